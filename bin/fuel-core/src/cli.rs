@@ -11,6 +11,7 @@ lazy_static::lazy_static! {
     pub static ref DEFAULT_DB_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("db");
 }
 
+pub mod backup;
 pub mod run;
 pub mod snapshot;
 
@@ -31,6 +32,7 @@ pub struct Opt {
 pub enum Fuel {
     Run(run::Command),
     Snapshot(snapshot::Command),
+    Backup(backup::Command),
 }
 
 pub const LOG_FILTER: &str = "RUST_LOG";
@@ -91,6 +93,7 @@ pub async fn run_cli() -> anyhow::Result<()> {
         Ok(opt) => match opt.command {
             Fuel::Run(command) => run::exec(command).await,
             Fuel::Snapshot(command) => snapshot::exec(command).await,
+            Fuel::Backup(command) => backup::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
@@ -0,0 +1,49 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Create a consistent backup of the database.
+///
+/// The database is locked by the running node, so the backup of the running node
+/// should be created via the `backupDatabase` GraphQL mutation instead.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    pub database_path: PathBuf,
+
+    /// The path to the new directory for the backup.
+    #[clap(name = "OUT", long = "out", value_parser)]
+    pub out: PathBuf,
+}
+
+#[cfg(not(feature = "rocksdb"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(feature = "rocksdb")]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::Database;
+
+    let path = command.database_path;
+    let db = Database::open(&path).context(format!(
+        "failed to open database at path {}",
+        path.display()
+    ))?;
+
+    db.backup(&command.out).context(format!(
+        "failed to create the backup at path {}",
+        command.out.display()
+    ))?;
+    tracing::info!("The backup is created at {}", command.out.display());
+    Ok(())
+}
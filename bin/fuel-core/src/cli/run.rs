@@ -15,12 +15,15 @@ use fuel_core::{
     chain_config::{
         default_consensus_dev_key,
         ChainConfig,
+        CheckpointConfig,
+        SnapshotReader,
     },
     producer::Config as ProducerConfig,
     service::{
         config::Trigger,
         Config,
         DbType,
+        PruningConfig,
         RelayerVerifierConfig,
         ServiceTrait,
         VMConfig,
@@ -29,6 +32,7 @@ use fuel_core::{
     types::{
         blockchain::primitives::SecretKeyWrapper,
         fuel_tx::Address,
+        fuel_types::Bytes32,
         fuel_vm::SecretKey,
         secrecy::{
             ExposeSecret,
@@ -55,8 +59,12 @@ pub const CONSENSUS_KEY_ENV: &str = "CONSENSUS_KEY_SECRET";
 mod p2p;
 
 mod consensus;
+mod graphql;
 #[cfg(feature = "relayer")]
 mod relayer;
+#[cfg(feature = "rocksdb")]
+mod rocksdb;
+mod txpool;
 
 /// Run the Fuel client node locally.
 #[derive(Debug, Clone, Parser)]
@@ -85,7 +93,11 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
-    /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+    #[cfg(feature = "rocksdb")]
+    #[clap(flatten)]
+    pub rocksdb_args: rocksdb::RocksDbArgs,
+
+    /// Specify either an alias to a built-in configuration or filepath to a JSON or binary file.
     #[arg(
         name = "CHAIN_CONFIG",
         long = "chain",
@@ -94,6 +106,30 @@ pub struct Command {
     )]
     pub chain_config: String,
 
+    /// The directory of the state snapshot created by the `fuel-core snapshot --out`.
+    /// The genesis imports the state chunk by chunk, and the chain config is loaded
+    /// from the snapshot instead of the `--chain`.
+    #[arg(long = "snapshot", value_parser, env, conflicts_with = "CHAIN_CONFIG")]
+    pub snapshot: Option<PathBuf>,
+
+    /// The height of the trusted checkpoint block. Overrides the checkpoint of the chain config.
+    #[arg(long = "checkpoint-height", requires = "checkpoint_block_id", env)]
+    pub checkpoint_height: Option<u32>,
+
+    /// The id of the trusted checkpoint block at the `--checkpoint-height`.
+    #[arg(long = "checkpoint-block-id", requires = "checkpoint_height", env)]
+    pub checkpoint_block_id: Option<Bytes32>,
+
+    /// The directory of the state snapshot taken at the checkpoint. The empty database
+    /// starts from this state, so only the headers before the checkpoint are synced.
+    #[arg(
+        long = "checkpoint-snapshot",
+        value_parser,
+        env,
+        conflicts_with = "snapshot"
+    )]
+    pub checkpoint_snapshot: Option<PathBuf>,
+
     /// Allows GraphQL Endpoints to arbitrarily advanced blocks. Should be used for local development only
     #[arg(long = "manual_blocks_enabled", env)]
     pub manual_blocks_enabled: bool,
@@ -102,6 +138,11 @@ pub struct Command {
     #[arg(long = "vm-backtrace", env)]
     pub vm_backtrace: bool,
 
+    /// Record the call traces of the executed transactions, available via the
+    /// `transactionTrace` GraphQL query
+    #[arg(long = "vm-trace", env)]
+    pub vm_trace: bool,
+
     /// Enable full utxo stateful validation
     /// disabled by default until downstream consumers stabilize
     #[arg(long = "utxo-validation", env)]
@@ -153,6 +194,41 @@ pub struct Command {
 
     #[clap(long = "tx-pool-ttl", default_value = "5m", env)]
     pub tx_pool_ttl: humantime::Duration,
+
+    /// The minimal increase of the gas price in percents required to replace
+    /// a transaction in the pool that spends the same coins or messages.
+    #[clap(long = "tx-replacement-price-bump", default_value = "10", env)]
+    pub tx_replacement_price_bump: u64,
+
+    #[clap(flatten)]
+    pub tx_selection_args: txpool::TxSelectionArgs,
+
+    /// Enables the pruning of the historical data. Transactions, receipts and statuses
+    /// are kept only for the specified number of the latest blocks.
+    /// The full history is kept if not set.
+    #[arg(
+        long = "pruning-retained-blocks",
+        value_parser = clap::value_parser!(u32).range(1..),
+        env
+    )]
+    pub pruning_retained_blocks: Option<u32>,
+
+    /// The directory for the backups of the database created via the `backupDatabase`
+    /// GraphQL mutation. The mutation is disabled if not set.
+    #[arg(long = "backup-dir", value_parser, env)]
+    pub backup_dir: Option<PathBuf>,
+
+    /// The bearer token required by the privileged GraphQL operations: the block
+    /// production, the debugger, dry runs and backups. They are open to everyone if
+    /// not set. Setting via the `ADMIN_TOKEN` env var is preferred.
+    #[arg(long = "admin-token", env)]
+    pub admin_token: Option<String>,
+
+    #[clap(flatten)]
+    pub websocket_args: graphql::WebSocketArgs,
+
+    #[clap(flatten)]
+    pub query_limits_args: graphql::QueryLimitsArgs,
 }
 
 impl Command {
@@ -162,8 +238,15 @@ impl Command {
             port,
             database_path,
             database_type,
+            #[cfg(feature = "rocksdb")]
+            rocksdb_args,
             chain_config,
+            snapshot,
+            checkpoint_height,
+            checkpoint_block_id,
+            checkpoint_snapshot,
             vm_backtrace,
+            vm_trace,
             manual_blocks_enabled,
             utxo_validation,
             min_gas_price,
@@ -181,11 +264,32 @@ impl Command {
             max_da_lag,
             max_wait_time,
             tx_pool_ttl,
+            tx_replacement_price_bump,
+            tx_selection_args,
+            pruning_retained_blocks,
+            backup_dir,
+            admin_token,
+            websocket_args,
+            query_limits_args,
         } = self;
 
         let addr = net::SocketAddr::new(ip, port);
 
-        let chain_conf: ChainConfig = chain_config.as_str().parse()?;
+        let genesis_snapshot =
+            snapshot.map(|dir| SnapshotReader::open(&dir)).transpose()?;
+        let mut chain_conf: ChainConfig = match &genesis_snapshot {
+            Some(snapshot) => snapshot.chain_config()?,
+            None => chain_config.as_str().parse()?,
+        };
+        if let (Some(height), Some(block_id)) = (checkpoint_height, checkpoint_block_id) {
+            chain_conf.checkpoint = Some(CheckpointConfig {
+                height: height.into(),
+                block_id,
+            });
+        }
+        let checkpoint_snapshot = checkpoint_snapshot
+            .map(|dir| SnapshotReader::open(&dir))
+            .transpose()?;
 
         #[cfg(feature = "p2p")]
         let p2p_cfg = p2p_args.into_config(metrics)?;
@@ -239,12 +343,15 @@ impl Command {
             addr,
             database_path,
             database_type,
+            #[cfg(feature = "rocksdb")]
+            rocksdb: rocksdb_args.into(),
             chain_conf: chain_conf.clone(),
             utxo_validation,
             manual_blocks_enabled,
             block_production: trigger,
             vm: VMConfig {
                 backtrace: vm_backtrace,
+                trace: vm_trace,
             },
             txpool: TxPoolConfig::new(
                 chain_conf,
@@ -252,6 +359,8 @@ impl Command {
                 utxo_validation,
                 metrics,
                 tx_pool_ttl.into(),
+                tx_selection_args.into(),
+                tx_replacement_price_bump,
             ),
             block_producer: ProducerConfig {
                 utxo_validation,
@@ -269,6 +378,14 @@ impl Command {
             consensus_key,
             name: String::default(),
             verifier,
+            pruning: pruning_retained_blocks
+                .map(|retained_blocks| PruningConfig { retained_blocks }),
+            backup_dir,
+            genesis_snapshot,
+            checkpoint_snapshot,
+            websocket: websocket_args.into(),
+            query_limits: query_limits_args.into(),
+            admin_token: admin_token.map(Secret::new),
         })
     }
 }
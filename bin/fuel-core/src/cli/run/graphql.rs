@@ -0,0 +1,97 @@
+//! Clap configuration related to the GraphQL service
+
+use clap::Args;
+use fuel_core::service::{
+    QueryLimits,
+    RateLimitConfig,
+    WebSocketConfig,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct QueryLimitsArgs {
+    /// The maximum nesting depth of the GraphQL query.
+    #[clap(long = "graphql-max-depth", default_value = "16", env)]
+    pub graphql_max_depth: usize,
+
+    /// The maximum complexity of the GraphQL query. Every field costs one, the
+    /// paginated connections multiply the cost of their elements by the page size,
+    /// and the lists loaded from the database by their estimated size.
+    #[clap(long = "graphql-max-complexity", default_value = "50000", env)]
+    pub graphql_max_complexity: usize,
+
+    /// The maximum `first` or `last` of the paginated connections.
+    #[clap(long = "graphql-max-page-size", default_value = "10000", env)]
+    pub graphql_max_page_size: usize,
+
+    /// The maximum number of the owner's transactions scanned by one page of the
+    /// `transactionHistory`. The page stops early with the `scanCursor` to continue.
+    #[clap(long = "graphql-max-scanned-entries", default_value = "100000", env)]
+    pub graphql_max_scanned_entries: usize,
+
+    /// The number of GraphQL requests per second allowed from one IP address.
+    /// The requests are not throttled if not set.
+    #[clap(long = "graphql-rate-limit", env)]
+    pub graphql_rate_limit: Option<u32>,
+
+    /// The number of GraphQL requests that one IP address can send at once.
+    /// Defaults to the `--graphql-rate-limit`.
+    #[clap(
+        long = "graphql-rate-limit-burst",
+        requires = "graphql_rate_limit",
+        env
+    )]
+    pub graphql_rate_limit_burst: Option<u32>,
+
+    /// The maximum number of transactions in one `dryRunBatch` request.
+    #[clap(long = "graphql-max-dry-run-txs", default_value = "100", env)]
+    pub graphql_max_dry_run_txs: usize,
+
+    /// The maximum total gas limit of the scripts in one `dryRunBatch` request.
+    #[clap(long = "graphql-max-dry-run-gas", default_value = "1000000000", env)]
+    pub graphql_max_dry_run_gas: u64,
+}
+
+impl From<QueryLimitsArgs> for QueryLimits {
+    fn from(args: QueryLimitsArgs) -> Self {
+        QueryLimits {
+            max_depth: args.graphql_max_depth,
+            max_complexity: args.graphql_max_complexity,
+            max_page_size: args.graphql_max_page_size,
+            max_scanned_entries: args.graphql_max_scanned_entries,
+            rate_limit: args.graphql_rate_limit.map(|requests_per_second| {
+                RateLimitConfig {
+                    requests_per_second,
+                    burst: args.graphql_rate_limit_burst.unwrap_or(requests_per_second),
+                }
+            }),
+            max_dry_run_txs: args.graphql_max_dry_run_txs,
+            max_dry_run_gas: args.graphql_max_dry_run_gas,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct WebSocketArgs {
+    /// The interval between keep-alive messages sent over the `/graphql-ws` connections.
+    #[clap(long = "ws-keep-alive-interval", default_value = "15s", env)]
+    pub ws_keep_alive_interval: humantime::Duration,
+
+    /// The time given to the `/graphql-ws` clients to initialize the connection
+    /// before it is closed.
+    #[clap(long = "ws-connection-init-timeout", default_value = "10s", env)]
+    pub ws_connection_init_timeout: humantime::Duration,
+
+    /// The maximum number of active subscriptions of one `/graphql-ws` connection.
+    #[clap(long = "ws-max-subscriptions", default_value = "100", env)]
+    pub ws_max_subscriptions: usize,
+}
+
+impl From<WebSocketArgs> for WebSocketConfig {
+    fn from(args: WebSocketArgs) -> Self {
+        WebSocketConfig {
+            keep_alive_interval: args.ws_keep_alive_interval.into(),
+            connection_init_timeout: args.ws_connection_init_timeout.into(),
+            max_subscriptions_per_connection: args.ws_max_subscriptions,
+        }
+    }
+}
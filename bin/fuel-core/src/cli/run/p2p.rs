@@ -11,6 +11,7 @@ use fuel_core::{
         gossipsub_config::default_gossipsub_builder,
         HeartbeatConfig,
         Multiaddr,
+        ReputationConfig,
     },
     types::{
         fuel_crypto,
@@ -164,6 +165,22 @@ pub struct P2PArgs {
     /// Cannot be zero.
     #[clap(long = "heartbeat_max_failures", default_value = "5", env)]
     pub heartbeat_max_failures: NonZeroU32,
+
+    /// The fraction of the peer's reputation score that remains after every 10 seconds
+    #[clap(long = "reputation_decay_factor", default_value = "0.98", env)]
+    pub reputation_decay_factor: f64,
+
+    /// Peers with a reputation score below the threshold are disconnected
+    #[clap(long = "reputation_disconnect_threshold", default_value = "-50", env)]
+    pub reputation_disconnect_threshold: f64,
+
+    /// Peers with a reputation score at or below the threshold are banned
+    #[clap(long = "reputation_ban_threshold", default_value = "-100", env)]
+    pub reputation_ban_threshold: f64,
+
+    /// How long the banned peer is not allowed to connect, in seconds
+    #[clap(long = "peer_ban_duration", default_value = "3600", env)]
+    pub peer_ban_duration: u64,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -174,6 +191,17 @@ pub struct SyncArgs {
     /// The maximum number of get transaction requests to make in a single batch.
     #[clap(long = "sync_max_get_txns", default_value = "10", env)]
     pub max_get_txns_requests: usize,
+    /// The maximum number of headers to request from a peer in a single request.
+    #[clap(long = "sync_header_batch_size", default_value = "10", env)]
+    pub header_batch_size: usize,
+    /// How long to wait for the response of a peer before asking another peer, in seconds.
+    #[clap(long = "sync_peer_request_timeout", default_value = "10", env)]
+    pub peer_request_timeout: u64,
+    /// Sync only the verified block headers without executing the blocks.
+    /// The node serves the header chain, but doesn't have the state or transactions.
+    /// The message proofs are not supported in this mode.
+    #[clap(long = "sync_headers_only", env)]
+    pub headers_only: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -206,6 +234,10 @@ impl From<SyncArgs> for fuel_core::sync::Config {
         Self {
             max_get_header_requests: value.max_get_header_requests,
             max_get_txns_requests: value.max_get_txns_requests,
+            header_batch_size: value.header_batch_size,
+            peer_request_timeout: Duration::from_secs(value.peer_request_timeout),
+            headers_only: value.headers_only,
+            checkpoint: None,
         }
     }
 }
@@ -262,6 +294,13 @@ impl P2PArgs {
             )
         };
 
+        let reputation_config = ReputationConfig {
+            decay_factor: self.reputation_decay_factor,
+            disconnect_threshold: self.reputation_disconnect_threshold,
+            ban_threshold: self.reputation_ban_threshold,
+            ban_duration: Duration::from_secs(self.peer_ban_duration),
+        };
+
         let config = || -> Option<Config<NotInitialized>> {
             Some(Config {
                 keypair: local_keypair?,
@@ -293,6 +332,7 @@ impl P2PArgs {
                 ),
                 info_interval: Some(Duration::from_secs(self.info_interval)),
                 identify_interval: Some(Duration::from_secs(self.identify_interval)),
+                reputation_config,
                 metrics,
                 state: NotInitialized,
             })
@@ -0,0 +1,54 @@
+use clap::Args;
+use fuel_core::service::{
+    CompactionStyle,
+    RocksDbConfig,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct RocksDbArgs {
+    /// The capacity in bytes of the block cache shared by all columns of the RocksDB.
+    #[clap(long = "rocksdb-cache-capacity", default_value_t = RocksDbConfig::DEFAULT_CACHE_CAPACITY, env)]
+    pub cache_capacity: usize,
+
+    /// The maximum number of files opened by the RocksDB. `-1` means no limit.
+    #[clap(long = "rocksdb-max-open-files", default_value_t = RocksDbConfig::DEFAULT_MAX_OPEN_FILES, allow_hyphen_values = true, env)]
+    pub max_open_files: i32,
+
+    /// The size in bytes of a single memtable of each column of the RocksDB.
+    #[clap(long = "rocksdb-write-buffer-size", default_value_t = RocksDbConfig::DEFAULT_WRITE_BUFFER_SIZE, env)]
+    pub write_buffer_size: usize,
+
+    /// The total size in bytes of the memtables across all columns of the RocksDB.
+    /// `0` means no limit.
+    #[clap(long = "rocksdb-db-write-buffer-size", default_value_t = RocksDbConfig::DEFAULT_DB_WRITE_BUFFER_SIZE, env)]
+    pub db_write_buffer_size: usize,
+
+    /// The number of bits per key of the bloom filters of the columns with point lookups,
+    /// like coins and messages. `0` disables the bloom filters.
+    #[clap(long = "rocksdb-bloom-filter-bits-per-key", default_value_t = RocksDbConfig::DEFAULT_BLOOM_FILTER_BITS_PER_KEY, env)]
+    pub bloom_filter_bits_per_key: f64,
+
+    /// The part of the memtable used by the prefix bloom filter of the columns indexed
+    /// by the owner, like the owned coins. `0` disables the filter.
+    #[clap(long = "rocksdb-memtable-prefix-bloom-ratio", default_value_t = RocksDbConfig::DEFAULT_MEMTABLE_PREFIX_BLOOM_RATIO, env)]
+    pub memtable_prefix_bloom_ratio: f64,
+
+    /// The compaction style of the history columns of the RocksDB, like transactions,
+    /// receipts and blocks.
+    #[clap(long = "rocksdb-history-compaction-style", value_enum, default_value_t = RocksDbConfig::DEFAULT_HISTORY_COMPACTION_STYLE, env)]
+    pub history_compaction_style: CompactionStyle,
+}
+
+impl From<RocksDbArgs> for RocksDbConfig {
+    fn from(args: RocksDbArgs) -> Self {
+        RocksDbConfig {
+            cache_capacity: args.cache_capacity,
+            max_open_files: args.max_open_files,
+            write_buffer_size: args.write_buffer_size,
+            db_write_buffer_size: args.db_write_buffer_size,
+            bloom_filter_bits_per_key: args.bloom_filter_bits_per_key,
+            memtable_prefix_bloom_ratio: args.memtable_prefix_bloom_ratio,
+            history_compaction_style: args.history_compaction_style,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Clap configuration related to the transaction selection of the block production
+
+use clap::{
+    Args,
+    ValueEnum,
+};
+use fuel_core::{
+    txpool::SelectionStrategy,
+    types::fuel_tx::Address,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct TxSelectionArgs {
+    /// The strategy of selecting transactions from the pool into the produced block.
+    #[clap(
+        long = "tx-selection-strategy",
+        default_value = "gas-price",
+        value_enum,
+        env
+    )]
+    pub tx_selection_strategy: Strategy,
+
+    /// The comma separated addresses whose transactions are included first by the
+    /// `priority-addresses` strategy.
+    #[clap(
+        long = "tx-priority-addresses",
+        value_delimiter = ',',
+        required_if_eq("tx_selection_strategy", "priority-addresses"),
+        env
+    )]
+    pub tx_priority_addresses: Vec<Address>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Strategy {
+    /// Prefers transactions with the highest gas price.
+    GasPrice,
+    /// Includes transactions in the order of their arrival.
+    ByTime,
+    /// Maximizes the total fee of the block.
+    MaxFee,
+    /// Includes transactions from the `--tx-priority-addresses` first.
+    PriorityAddresses,
+}
+
+impl From<TxSelectionArgs> for SelectionStrategy {
+    fn from(args: TxSelectionArgs) -> Self {
+        match args.tx_selection_strategy {
+            Strategy::GasPrice => SelectionStrategy::GasPrice,
+            Strategy::ByTime => SelectionStrategy::ByTime,
+            Strategy::MaxFee => SelectionStrategy::MaxFee,
+            Strategy::PriorityAddresses => {
+                SelectionStrategy::PriorityAddresses(args.tx_priority_addresses)
+            }
+        }
+    }
+}
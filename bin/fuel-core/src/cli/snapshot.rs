@@ -1,8 +1,13 @@
 use crate::cli::DEFAULT_DB_PATH;
-use clap::Parser;
+use clap::{
+    Parser,
+    ValueEnum,
+};
+use fuel_core::chain_config::SnapshotEncoding;
 use std::path::PathBuf;
 
-/// Print a snapshot of blockchain state to stdout.
+/// Print a snapshot of blockchain state to stdout, or write it into
+/// the `--out` directory chunk by chunk.
 #[derive(Debug, Clone, Parser)]
 pub struct Command {
     #[clap(
@@ -13,9 +18,43 @@ pub struct Command {
     )]
     pub database_path: PathBuf,
 
-    /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+    /// Specify either an alias to a built-in configuration or filepath to a JSON or binary file.
     #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
     pub chain_config: String,
+
+    /// The directory for the streaming snapshot. Large states should use it, because
+    /// the snapshot printed to stdout is built in memory.
+    #[clap(name = "OUT", long = "out", value_parser)]
+    pub out: Option<PathBuf>,
+
+    /// The maximum number of entries in one chunk of the streaming snapshot.
+    #[clap(
+        long = "chunk-size",
+        default_value_t = fuel_core::chain_config::DEFAULT_CHUNK_SIZE,
+        requires = "OUT"
+    )]
+    pub chunk_size: usize,
+
+    /// The encoding of the snapshot. The binary snapshot is smaller and faster to load.
+    /// The encoding is detected automatically when the snapshot is loaded.
+    #[clap(long = "format", value_enum, default_value_t = Format::Json)]
+    pub format: Format,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Json,
+    Binary,
+}
+
+impl From<Format> for SnapshotEncoding {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Json => SnapshotEncoding::Json,
+            Format::Binary => SnapshotEncoding::Binary,
+        }
+    }
 }
 
 #[cfg(not(feature = "rocksdb"))]
@@ -31,6 +70,8 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     use anyhow::Context;
     use fuel_core::{
         chain_config::{
+            to_binary,
+            write_snapshot,
             ChainConfig,
             StateConfig,
         },
@@ -44,6 +85,16 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
         path.display()
     ))?;
 
+    if let Some(out) = command.out {
+        write_snapshot(&db, config, &out, command.chunk_size, command.format.into())
+            .context(format!(
+                "failed to write the snapshot into {}",
+                out.display()
+            ))?;
+        tracing::info!("The snapshot is written into {}", out.display());
+        return Ok(())
+    }
+
     let state_conf = StateConfig::generate_state_config(db)?;
 
     let chain_conf = ChainConfig {
@@ -51,9 +102,17 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
         ..config
     };
 
-    let stdout = std::io::stdout().lock();
+    let mut stdout = std::io::stdout().lock();
 
-    serde_json::to_writer(stdout, &chain_conf)
-        .context("failed to dump snapshot to JSON")?;
+    match command.format {
+        Format::Json => serde_json::to_writer(stdout, &chain_conf)
+            .context("failed to dump snapshot to JSON")?,
+        Format::Binary => {
+            use std::io::Write;
+            stdout
+                .write_all(&to_binary(chain_conf)?)
+                .context("failed to dump snapshot in the binary form")?
+        }
+    }
     Ok(())
 }
@@ -1,15 +1,21 @@
+mod binary;
 mod chain;
+mod checkpoint;
 mod coin;
 mod consensus;
 mod contract;
 mod message;
+mod snapshot;
 mod state;
 
+pub use binary::*;
 pub use chain::*;
+pub use checkpoint::*;
 pub use coin::*;
 pub use consensus::*;
 pub use contract::*;
 pub use message::*;
+pub use snapshot::*;
 pub use state::*;
 
 #[cfg(test)]
@@ -40,7 +46,12 @@ mod tests {
     };
 
     use super::{
+        binary::{
+            from_binary,
+            to_binary,
+        },
         chain::ChainConfig,
+        checkpoint::CheckpointConfig,
         coin::CoinConfig,
         contract::ContractConfig,
         message::MessageConfig,
@@ -61,6 +72,17 @@ mod tests {
         assert_eq!(disk_config, load_config);
     }
 
+    #[test]
+    fn from_str_loads_from_binary_file() {
+        let tmp_file = tmp_path();
+        let disk_config = test_config_coin_state();
+        write(tmp_file.clone(), to_binary(disk_config.clone()).unwrap()).unwrap();
+
+        let load_config: ChainConfig =
+            tmp_file.to_string_lossy().into_owned().parse().unwrap();
+        assert_eq!(disk_config, load_config);
+    }
+
     #[test]
     fn snapshot_local_testnet_config() {
         let config = ChainConfig::local_testnet();
@@ -107,6 +129,38 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn snapshot_regenesis_config() {
+        let config = test_config_regenesis();
+        let json = serde_json::to_string_pretty(&config).unwrap();
+        insta::assert_snapshot!(json);
+    }
+
+    #[test]
+    fn can_roundtrip_regenesis_config() {
+        let config = test_config_regenesis();
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized_config: ChainConfig =
+            serde_json::from_str(json.as_str()).unwrap();
+        assert_eq!(config, deserialized_config);
+    }
+
+    #[test]
+    fn snapshot_checkpoint_config() {
+        let config = test_config_checkpoint();
+        let json = serde_json::to_string_pretty(&config).unwrap();
+        insta::assert_snapshot!(json);
+    }
+
+    #[test]
+    fn can_roundtrip_checkpoint_config() {
+        let config = test_config_checkpoint();
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized_config: ChainConfig =
+            serde_json::from_str(json.as_str()).unwrap();
+        assert_eq!(config, deserialized_config);
+    }
+
     #[test]
     fn snapshot_simple_contract() {
         let config = test_config_contract(false, false, false, false);
@@ -219,6 +273,29 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn binary_roundtrip_matches_json() {
+        let configs = [
+            ChainConfig::local_testnet(),
+            test_config_regenesis(),
+            test_config_checkpoint(),
+            test_config_contract(false, false, false, false),
+            test_config_contract(true, true, true, true),
+            test_config_coin_state(),
+            test_message_config(),
+        ];
+        for config in configs {
+            let json = serde_json::to_string(&config).unwrap();
+            let from_json: ChainConfig = serde_json::from_str(json.as_str()).unwrap();
+            let binary = to_binary(config.clone()).unwrap();
+            let from_binary: ChainConfig = from_binary(&binary).unwrap();
+
+            assert_eq!(from_binary, from_json);
+            assert_eq!(from_binary, config);
+            assert!(binary.len() < json.len());
+        }
+    }
+
     fn test_config_contract(
         state: bool,
         balances: bool,
@@ -271,6 +348,30 @@ mod tests {
         }
     }
 
+    fn test_config_regenesis() -> ChainConfig {
+        let mut rng = StdRng::seed_from_u64(3);
+        ChainConfig {
+            initial_state: Some(StateConfig {
+                height: Some(rng.next_u32().into()),
+                last_block_id: Some(rng.gen()),
+                spent_messages: Some(vec![rng.gen(), rng.gen()]),
+                ..Default::default()
+            }),
+            ..ChainConfig::local_testnet()
+        }
+    }
+
+    fn test_config_checkpoint() -> ChainConfig {
+        let mut rng = StdRng::seed_from_u64(4);
+        ChainConfig {
+            checkpoint: Some(CheckpointConfig {
+                height: rng.next_u32().into(),
+                block_id: rng.gen(),
+            }),
+            ..ChainConfig::local_testnet()
+        }
+    }
+
     fn test_config_coin_state() -> ChainConfig {
         let mut rng = StdRng::seed_from_u64(1);
         let tx_id: Option<Bytes32> = Some(rng.gen());
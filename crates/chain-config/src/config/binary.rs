@@ -0,0 +1,352 @@
+//! The compact binary encoding of the chain config and its state.
+//!
+//! Hex strings of the JSON form make multi-GB states slow to parse and twice as large
+//! as the data they contain. The binary form is the [`postcard`] encoding of the value
+//! prefixed with a header:
+//!
+//! ```text
+//! | magic (8 bytes) | version (u32 BE) | checksum (32 bytes) | payload |
+//! ```
+//!
+//! The checksum is the SHA-256 hash of the payload. The JSON form omits `None` fields,
+//! which is not supported by postcard, so types with optional fields are encoded via
+//! their [`BinaryRepr::Repr`] that always contains all fields.
+
+use crate::{
+    ChainConfig,
+    CheckpointConfig,
+    CoinConfig,
+    ConsensusConfig,
+    ContractBalanceConfig,
+    ContractConfig,
+    ContractStateConfig,
+    MessageConfig,
+    SpentMessageConfig,
+    StateConfig,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    fuel_crypto::Hasher,
+    fuel_tx::ConsensusParameters,
+    fuel_types::{
+        Address,
+        AssetId,
+        Bytes32,
+        Nonce,
+        Salt,
+    },
+    fuel_vm::GasCostsValues,
+};
+use serde::{
+    de::DeserializeOwned,
+    Deserialize,
+    Serialize,
+};
+
+/// The prefix of the binary encoded value.
+pub const BINARY_MAGIC: [u8; 8] = *b"FUELSTAT";
+/// The version of the binary encoding. It is increased on any change of the
+/// representation of the encoded types.
+pub const BINARY_VERSION: u32 = 2;
+
+const HEADER_SIZE: usize = BINARY_MAGIC.len() + 4 + Bytes32::LEN;
+
+/// The type that can be encoded into the binary form.
+pub trait BinaryRepr: Sized {
+    /// The representation of the type in the binary form.
+    type Repr: Serialize + DeserializeOwned;
+
+    fn into_repr(self) -> Self::Repr;
+
+    fn from_repr(repr: Self::Repr) -> Self;
+}
+
+/// Returns `true` if the `bytes` start with the header of the binary form.
+pub fn is_binary(bytes: &[u8]) -> bool {
+    bytes.starts_with(&BINARY_MAGIC)
+}
+
+/// Encodes the `value` into the binary form.
+pub fn to_binary<T: BinaryRepr>(value: T) -> anyhow::Result<Vec<u8>> {
+    let payload = postcard::to_stdvec(&value.into_repr())?;
+    let mut bytes = Vec::with_capacity(HEADER_SIZE + payload.len());
+    bytes.extend_from_slice(&BINARY_MAGIC);
+    bytes.extend_from_slice(&BINARY_VERSION.to_be_bytes());
+    bytes.extend_from_slice(Hasher::hash(&payload).as_ref());
+    bytes.extend_from_slice(&payload);
+    Ok(bytes)
+}
+
+/// Decodes the value from the binary form, verifying the header and the checksum.
+pub fn from_binary<T: BinaryRepr>(bytes: &[u8]) -> anyhow::Result<T> {
+    anyhow::ensure!(
+        is_binary(bytes) && bytes.len() >= HEADER_SIZE,
+        "The binary header is missing"
+    );
+    let (version, rest) = bytes[BINARY_MAGIC.len()..].split_at(4);
+    let version = u32::from_be_bytes(version.try_into().expect("The size is checked"));
+    anyhow::ensure!(
+        version == BINARY_VERSION,
+        "Unsupported version {version} of the binary encoding, expected {BINARY_VERSION}"
+    );
+    let (checksum, payload) = rest.split_at(Bytes32::LEN);
+    anyhow::ensure!(
+        Hasher::hash(payload).as_ref() == checksum,
+        "The checksum of the binary encoding doesn't match"
+    );
+    let repr = postcard::from_bytes(payload)?;
+    Ok(T::from_repr(repr))
+}
+
+impl<T: BinaryRepr> BinaryRepr for Vec<T> {
+    type Repr = Vec<T::Repr>;
+
+    fn into_repr(self) -> Self::Repr {
+        self.into_iter().map(BinaryRepr::into_repr).collect()
+    }
+
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr.into_iter().map(BinaryRepr::from_repr).collect()
+    }
+}
+
+/// The types without optional fields are encoded as they are.
+macro_rules! impl_binary_repr_as_is {
+    ($($t:ty),*) => {
+        $(
+            impl BinaryRepr for $t {
+                type Repr = Self;
+
+                fn into_repr(self) -> Self::Repr {
+                    self
+                }
+
+                fn from_repr(repr: Self::Repr) -> Self {
+                    repr
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_repr_as_is!(
+    MessageConfig,
+    ContractStateConfig,
+    ContractBalanceConfig,
+    SpentMessageConfig,
+    CheckpointConfig
+);
+
+#[derive(Serialize, Deserialize)]
+pub struct CoinConfigRepr {
+    tx_id: Option<Bytes32>,
+    output_index: Option<u8>,
+    tx_pointer_block_height: Option<BlockHeight>,
+    tx_pointer_tx_idx: Option<u16>,
+    maturity: Option<BlockHeight>,
+    owner: Address,
+    amount: u64,
+    asset_id: AssetId,
+}
+
+impl BinaryRepr for CoinConfig {
+    type Repr = CoinConfigRepr;
+
+    fn into_repr(self) -> Self::Repr {
+        CoinConfigRepr {
+            tx_id: self.tx_id,
+            output_index: self.output_index,
+            tx_pointer_block_height: self.tx_pointer_block_height,
+            tx_pointer_tx_idx: self.tx_pointer_tx_idx,
+            maturity: self.maturity,
+            owner: self.owner,
+            amount: self.amount,
+            asset_id: self.asset_id,
+        }
+    }
+
+    fn from_repr(repr: Self::Repr) -> Self {
+        CoinConfig {
+            tx_id: repr.tx_id,
+            output_index: repr.output_index,
+            tx_pointer_block_height: repr.tx_pointer_block_height,
+            tx_pointer_tx_idx: repr.tx_pointer_tx_idx,
+            maturity: repr.maturity,
+            owner: repr.owner,
+            amount: repr.amount,
+            asset_id: repr.asset_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ContractConfigRepr {
+    code: Vec<u8>,
+    salt: Salt,
+    state: Option<Vec<(Bytes32, Bytes32)>>,
+    balances: Option<Vec<(AssetId, u64)>>,
+    tx_id: Option<Bytes32>,
+    output_index: Option<u8>,
+    tx_pointer_block_height: Option<BlockHeight>,
+    tx_pointer_tx_idx: Option<u16>,
+}
+
+impl BinaryRepr for ContractConfig {
+    type Repr = ContractConfigRepr;
+
+    fn into_repr(self) -> Self::Repr {
+        ContractConfigRepr {
+            code: self.code,
+            salt: self.salt,
+            state: self.state,
+            balances: self.balances,
+            tx_id: self.tx_id,
+            output_index: self.output_index,
+            tx_pointer_block_height: self.tx_pointer_block_height,
+            tx_pointer_tx_idx: self.tx_pointer_tx_idx,
+        }
+    }
+
+    fn from_repr(repr: Self::Repr) -> Self {
+        ContractConfig {
+            code: repr.code,
+            salt: repr.salt,
+            state: repr.state,
+            balances: repr.balances,
+            tx_id: repr.tx_id,
+            output_index: repr.output_index,
+            tx_pointer_block_height: repr.tx_pointer_block_height,
+            tx_pointer_tx_idx: repr.tx_pointer_tx_idx,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StateConfigRepr {
+    coins: Option<Vec<CoinConfigRepr>>,
+    contracts: Option<Vec<ContractConfigRepr>>,
+    messages: Option<Vec<MessageConfig>>,
+    height: Option<BlockHeight>,
+    last_block_id: Option<Bytes32>,
+    spent_messages: Option<Vec<Nonce>>,
+}
+
+impl BinaryRepr for StateConfig {
+    type Repr = StateConfigRepr;
+
+    fn into_repr(self) -> Self::Repr {
+        StateConfigRepr {
+            coins: self.coins.map(BinaryRepr::into_repr),
+            contracts: self.contracts.map(BinaryRepr::into_repr),
+            messages: self.messages,
+            height: self.height,
+            last_block_id: self.last_block_id,
+            spent_messages: self.spent_messages,
+        }
+    }
+
+    fn from_repr(repr: Self::Repr) -> Self {
+        StateConfig {
+            coins: repr.coins.map(BinaryRepr::from_repr),
+            contracts: repr.contracts.map(BinaryRepr::from_repr),
+            messages: repr.messages,
+            height: repr.height,
+            last_block_id: repr.last_block_id,
+            spent_messages: repr.spent_messages,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ChainConfigRepr {
+    chain_name: String,
+    block_gas_limit: u64,
+    initial_state: Option<StateConfigRepr>,
+    transaction_parameters: ConsensusParameters,
+    gas_costs: GasCostsValues,
+    consensus: ConsensusConfig,
+    checkpoint: Option<CheckpointConfig>,
+}
+
+impl BinaryRepr for ChainConfig {
+    type Repr = ChainConfigRepr;
+
+    fn into_repr(self) -> Self::Repr {
+        ChainConfigRepr {
+            chain_name: self.chain_name,
+            block_gas_limit: self.block_gas_limit,
+            initial_state: self.initial_state.map(BinaryRepr::into_repr),
+            transaction_parameters: self.transaction_parameters,
+            gas_costs: self.gas_costs.into(),
+            consensus: self.consensus,
+            checkpoint: self.checkpoint,
+        }
+    }
+
+    fn from_repr(repr: Self::Repr) -> Self {
+        ChainConfig {
+            chain_name: repr.chain_name,
+            block_gas_limit: repr.block_gas_limit,
+            initial_state: repr.initial_state.map(BinaryRepr::from_repr),
+            transaction_parameters: repr.transaction_parameters,
+            gas_costs: repr.gas_costs.into(),
+            consensus: repr.consensus,
+            checkpoint: repr.checkpoint,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin() -> CoinConfig {
+        CoinConfig {
+            tx_id: None,
+            output_index: Some(1),
+            tx_pointer_block_height: Some(2u32.into()),
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner: Address::from([3; 32]),
+            amount: 4,
+            asset_id: AssetId::from([5; 32]),
+        }
+    }
+
+    #[test]
+    fn entries_roundtrip() {
+        let coins = vec![coin(), coin()];
+        let bytes = to_binary(coins.clone()).unwrap();
+
+        assert!(is_binary(&bytes));
+        assert_eq!(from_binary::<Vec<CoinConfig>>(&bytes).unwrap(), coins);
+    }
+
+    #[test]
+    fn corrupted_payload_is_rejected() {
+        let mut bytes = to_binary(vec![coin()]).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+
+        let err = from_binary::<Vec<CoinConfig>>(&bytes).unwrap_err();
+        assert!(err.to_string().contains("checksum"));
+    }
+
+    #[test]
+    fn unknown_version_is_rejected() {
+        let mut bytes = to_binary(vec![coin()]).unwrap();
+        bytes[BINARY_MAGIC.len()..BINARY_MAGIC.len() + 4]
+            .copy_from_slice(&(BINARY_VERSION + 1).to_be_bytes());
+
+        let err = from_binary::<Vec<CoinConfig>>(&bytes).unwrap_err();
+        assert!(err.to_string().contains("Unsupported version"));
+    }
+
+    #[test]
+    fn json_is_not_detected_as_binary() {
+        let json = serde_json::to_vec(&vec![coin()]).unwrap();
+
+        assert!(!is_binary(&json));
+        assert!(from_binary::<Vec<CoinConfig>>(&json).is_err());
+    }
+}
@@ -43,6 +43,11 @@ use std::{
 
 use crate::{
     config::{
+        binary::{
+            from_binary,
+            is_binary,
+        },
+        checkpoint::CheckpointConfig,
         coin::CoinConfig,
         state::StateConfig,
     },
@@ -71,6 +76,9 @@ pub struct ChainConfig {
     #[serde_as(as = "FromInto<GasCostsValues>")]
     pub gas_costs: GasCosts,
     pub consensus: ConsensusConfig,
+    /// The trusted block from which the new nodes may start instead of the genesis.
+    #[serde(default)]
+    pub checkpoint: Option<CheckpointConfig>,
 }
 
 impl Default for ChainConfig {
@@ -84,6 +92,7 @@ impl Default for ChainConfig {
             consensus: ConsensusConfig::PoA {
                 signing_key: Input::owner(&default_consensus_dev_key().public_key()),
             },
+            checkpoint: None,
         }
     }
 }
@@ -153,10 +162,15 @@ impl FromStr for ChainConfig {
                 // Attempt to load chain config from path
                 let path = PathBuf::from(s.to_string());
                 let contents = std::fs::read(path)?;
-                serde_json::from_slice(&contents).map_err(|e| {
+                let config = if is_binary(&contents) {
+                    from_binary(&contents)
+                } else {
+                    serde_json::from_slice(&contents).map_err(anyhow::Error::new)
+                };
+                config.map_err(|e| {
                     std::io::Error::new(
                         ErrorKind::InvalidData,
-                        anyhow::Error::new(e).context(format!(
+                        e.context(format!(
                             "an error occurred while loading the chain config file {s}"
                         )),
                     )
@@ -179,6 +193,9 @@ impl GenesisCommitment for ChainConfig {
             transaction_parameters,
             gas_costs,
             consensus,
+            // The checkpoint is a trust setting of the node, the nodes with different
+            // checkpoints still belong to the same network.
+            checkpoint: _,
         } = self;
 
         // TODO: Hash settlement configuration when it will be available.
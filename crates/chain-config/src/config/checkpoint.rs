@@ -0,0 +1,27 @@
+use crate::serialization::{
+    HexNumber,
+    HexType,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    fuel_types::Bytes32,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::serde_as;
+
+/// The trusted block of the chain. A new node can import the state snapshot taken
+/// at this block instead of executing all blocks since the genesis. The headers
+/// before the checkpoint are downloaded and verified against it.
+#[serde_as]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct CheckpointConfig {
+    /// The height of the trusted block.
+    #[serde_as(as = "HexNumber")]
+    pub height: BlockHeight,
+    /// The id of the trusted block.
+    #[serde_as(as = "HexType")]
+    pub block_id: Bytes32,
+}
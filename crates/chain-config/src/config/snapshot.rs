@@ -0,0 +1,510 @@
+//! The streaming snapshot of the chain state.
+//!
+//! Unlike the [`StateConfig`](crate::StateConfig) inside of the [`ChainConfig`], the
+//! snapshot is never loaded into memory at once. It is a directory with the chain
+//! config and a sub-directory per table of the state. Each sub-directory contains
+//! numbered files (chunks) with at most `chunk_size` entries. The files are JSON or
+//! the [binary form](crate::BinaryRepr) with the `.bin` extension:
+//!
+//! ```text
+//! snapshot/
+//! ├── chain_config.json
+//! ├── coins/00000000.json
+//! ├── contracts/00000000.json
+//! ├── contract_state/00000000.json
+//! ├── contract_balances/00000000.json
+//! ├── messages/00000000.json
+//! └── spent_messages/00000000.json
+//! ```
+//!
+//! The contracts are stored without the state and balances, because the state of one
+//! contract may be too large to fit into memory. The chain config is written
+//! last, so only complete snapshots can be opened.
+
+use crate::{
+    from_binary,
+    is_binary,
+    serialization::{
+        HexNumber,
+        HexType,
+    },
+    to_binary,
+    BinaryRepr,
+    ChainConfig,
+    ChainConfigDb,
+    CoinConfig,
+    ContractConfig,
+    MessageConfig,
+    StateConfig,
+};
+use anyhow::Context;
+use fuel_core_storage::Result as StorageResult;
+use fuel_core_types::fuel_types::{
+    AssetId,
+    Bytes32,
+    ContractId,
+    Nonce,
+};
+use serde::{
+    de::DeserializeOwned,
+    Deserialize,
+    Serialize,
+};
+use serde_with::serde_as;
+use std::{
+    fs::File,
+    io::{
+        BufWriter,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+pub const CHAIN_CONFIG_FILE_STEM: &str = "chain_config";
+pub const DEFAULT_CHUNK_SIZE: usize = 10_000;
+
+/// The encoding of the files of the snapshot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SnapshotEncoding {
+    #[default]
+    Json,
+    Binary,
+}
+
+impl SnapshotEncoding {
+    fn extension(&self) -> &'static str {
+        match self {
+            SnapshotEncoding::Json => "json",
+            SnapshotEncoding::Binary => "bin",
+        }
+    }
+
+    fn encode<T>(&self, value: T) -> anyhow::Result<Vec<u8>>
+    where
+        T: Serialize + BinaryRepr,
+    {
+        match self {
+            SnapshotEncoding::Json => Ok(serde_json::to_vec(&value)?),
+            SnapshotEncoding::Binary => to_binary(value),
+        }
+    }
+}
+
+/// The entry of the storage slot of the contract.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ContractStateConfig {
+    #[serde_as(as = "HexType")]
+    pub contract_id: ContractId,
+    #[serde_as(as = "HexType")]
+    pub key: Bytes32,
+    #[serde_as(as = "HexType")]
+    pub value: Bytes32,
+}
+
+/// The entry of the balance of the contract.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ContractBalanceConfig {
+    #[serde_as(as = "HexType")]
+    pub contract_id: ContractId,
+    #[serde_as(as = "HexType")]
+    pub asset_id: AssetId,
+    #[serde_as(as = "HexNumber")]
+    pub amount: u64,
+}
+
+/// The nonce of the message spent on the chain from which the snapshot was taken.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct SpentMessageConfig {
+    #[serde_as(as = "HexType")]
+    pub nonce: Nonce,
+}
+
+/// The type of the entries of one table of the snapshot.
+pub trait SnapshotEntry: Serialize + DeserializeOwned + BinaryRepr {
+    /// The name of the directory with chunks of the table.
+    const TABLE: &'static str;
+}
+
+impl SnapshotEntry for CoinConfig {
+    const TABLE: &'static str = "coins";
+}
+
+impl SnapshotEntry for ContractConfig {
+    const TABLE: &'static str = "contracts";
+}
+
+impl SnapshotEntry for ContractStateConfig {
+    const TABLE: &'static str = "contract_state";
+}
+
+impl SnapshotEntry for ContractBalanceConfig {
+    const TABLE: &'static str = "contract_balances";
+}
+
+impl SnapshotEntry for MessageConfig {
+    const TABLE: &'static str = "messages";
+}
+
+impl SnapshotEntry for SpentMessageConfig {
+    const TABLE: &'static str = "spent_messages";
+}
+
+/// Writes the snapshot of the state from the `db` into the `dir`. The `chain_config`
+/// is stored alongside with the height and the id of the latest block of the `db`.
+pub fn write_snapshot<T>(
+    db: &T,
+    chain_config: ChainConfig,
+    dir: &Path,
+    chunk_size: usize,
+    encoding: SnapshotEncoding,
+) -> anyhow::Result<()>
+where
+    T: ChainConfigDb,
+{
+    let height = db.get_block_height()?;
+    let block_id = db.get_block_id()?;
+    let writer = SnapshotWriter::new(dir, chunk_size, encoding)?;
+    writer.write_entries(db.iter_coin_configs())?;
+    writer.write_entries(db.iter_contract_configs())?;
+    writer.write_entries(db.iter_contract_state_configs())?;
+    writer.write_entries(db.iter_contract_balance_configs())?;
+    writer.write_entries(db.iter_message_configs())?;
+    writer.write_entries(
+        db.iter_spent_messages()
+            .map(|nonce| nonce.map(|nonce| SpentMessageConfig { nonce })),
+    )?;
+    writer.write_chain_config(&ChainConfig {
+        initial_state: Some(StateConfig {
+            height: Some(height),
+            last_block_id: Some(block_id),
+            ..Default::default()
+        }),
+        ..chain_config
+    })
+}
+
+pub struct SnapshotWriter {
+    dir: PathBuf,
+    chunk_size: usize,
+    encoding: SnapshotEncoding,
+}
+
+impl SnapshotWriter {
+    /// Creates the writer into the `dir`. The `dir` should be empty or not exist,
+    /// so chunks of another snapshot can't be mixed into the new one.
+    pub fn new(
+        dir: &Path,
+        chunk_size: usize,
+        encoding: SnapshotEncoding,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(chunk_size > 0, "The chunk size should be greater than zero");
+        if dir.exists() {
+            anyhow::ensure!(
+                dir.read_dir()?.next().is_none(),
+                "The directory {} is not empty",
+                dir.display()
+            );
+        }
+        std::fs::create_dir_all(dir)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            chunk_size,
+            encoding,
+        })
+    }
+
+    /// Writes the chain config. It should be the last step of the snapshot creation.
+    pub fn write_chain_config(&self, chain_config: &ChainConfig) -> anyhow::Result<()> {
+        let path = chain_config_path(&self.dir, self.encoding);
+        write_file(&path, &self.encoding.encode(chain_config.clone())?)
+    }
+
+    /// Writes the `entries` of the table chunk by chunk. Only one chunk is kept in memory.
+    ///
+    /// Returns the number of written entries.
+    pub fn write_entries<T, I>(&self, entries: I) -> anyhow::Result<usize>
+    where
+        T: SnapshotEntry,
+        I: Iterator<Item = StorageResult<T>>,
+    {
+        let table_dir = self.dir.join(T::TABLE);
+        std::fs::create_dir_all(&table_dir)?;
+
+        let mut written = 0;
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        let mut chunk_index = 0;
+        for entry in entries {
+            chunk.push(entry?);
+            if chunk.len() == self.chunk_size {
+                let full =
+                    std::mem::replace(&mut chunk, Vec::with_capacity(self.chunk_size));
+                written += full.len();
+                self.write_chunk(&table_dir, chunk_index, full)?;
+                chunk_index += 1;
+            }
+        }
+        if !chunk.is_empty() {
+            written += chunk.len();
+            self.write_chunk(&table_dir, chunk_index, chunk)?;
+        }
+
+        Ok(written)
+    }
+
+    fn write_chunk<T: SnapshotEntry>(
+        &self,
+        table_dir: &Path,
+        index: usize,
+        chunk: Vec<T>,
+    ) -> anyhow::Result<()> {
+        let path = chunk_path(table_dir, index, self.encoding);
+        write_file(&path, &self.encoding.encode(chunk)?)
+    }
+}
+
+fn chain_config_path(dir: &Path, encoding: SnapshotEncoding) -> PathBuf {
+    dir.join(CHAIN_CONFIG_FILE_STEM)
+        .with_extension(encoding.extension())
+}
+
+fn chunk_path(table_dir: &Path, index: usize, encoding: SnapshotEncoding) -> PathBuf {
+    table_dir.join(format!("{index:08}.{}", encoding.extension()))
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+    let file = File::create(path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    let mut writer = BufWriter::new(file);
+    writer.write_all(bytes)?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+pub struct SnapshotReader {
+    dir: PathBuf,
+    encoding: SnapshotEncoding,
+}
+
+impl SnapshotReader {
+    /// Opens the complete snapshot in the `dir`. The encoding of the snapshot is
+    /// detected by the extension of the chain config file.
+    pub fn open(dir: &Path) -> anyhow::Result<Self> {
+        let encoding = [SnapshotEncoding::Json, SnapshotEncoding::Binary]
+            .into_iter()
+            .find(|encoding| chain_config_path(dir, *encoding).is_file())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The directory {} doesn't contain a complete snapshot",
+                    dir.display()
+                )
+            })?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            encoding,
+        })
+    }
+
+    pub fn encoding(&self) -> SnapshotEncoding {
+        self.encoding
+    }
+
+    pub fn chain_config(&self) -> anyhow::Result<ChainConfig> {
+        read_file(&chain_config_path(&self.dir, self.encoding))
+    }
+
+    /// Returns the iterator over chunks of the table. The chunks are read lazily.
+    pub fn chunks<T>(&self) -> impl Iterator<Item = anyhow::Result<Vec<T>>>
+    where
+        T: SnapshotEntry,
+    {
+        let table_dir = self.dir.join(T::TABLE);
+        let encoding = self.encoding;
+        (0..)
+            .map(move |index| chunk_path(&table_dir, index, encoding))
+            .take_while(|path| path.is_file())
+            .map(|path| read_file(&path))
+    }
+}
+
+/// Reads the JSON or the binary file. The binary form is detected by its header.
+fn read_file<T>(path: &Path) -> anyhow::Result<T>
+where
+    T: DeserializeOwned + BinaryRepr,
+{
+    // Some types of the config borrow strings during deserialization,
+    // so the file is read at once. It is fine, because chunks are small.
+    let contents = std::fs::read(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let value = if is_binary(&contents) {
+        from_binary(&contents)
+    } else {
+        serde_json::from_slice(&contents).map_err(anyhow::Error::new)
+    };
+    value.with_context(|| format!("failed to parse {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::iter::{
+        BoxedIter,
+        IntoBoxedIter,
+    };
+    use fuel_core_types::blockchain::primitives::BlockHeight;
+
+    struct TestDb {
+        coins: Vec<CoinConfig>,
+        state: Vec<ContractStateConfig>,
+        spent_messages: Vec<Nonce>,
+    }
+
+    impl ChainConfigDb for TestDb {
+        fn get_coin_config(&self) -> StorageResult<Option<Vec<CoinConfig>>> {
+            Ok(Some(self.coins.clone()))
+        }
+
+        fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>> {
+            Ok(None)
+        }
+
+        fn get_message_config(&self) -> StorageResult<Option<Vec<MessageConfig>>> {
+            Ok(None)
+        }
+
+        fn get_block_height(&self) -> StorageResult<BlockHeight> {
+            Ok(10u32.into())
+        }
+
+        fn get_block_id(&self) -> StorageResult<Bytes32> {
+            Ok(Bytes32::from([10; 32]))
+        }
+
+        fn iter_coin_configs(&self) -> BoxedIter<StorageResult<CoinConfig>> {
+            self.coins.clone().into_iter().map(Ok).into_boxed()
+        }
+
+        fn iter_contract_configs(&self) -> BoxedIter<StorageResult<ContractConfig>> {
+            core::iter::empty().into_boxed()
+        }
+
+        fn iter_contract_state_configs(
+            &self,
+        ) -> BoxedIter<StorageResult<ContractStateConfig>> {
+            self.state.clone().into_iter().map(Ok).into_boxed()
+        }
+
+        fn iter_contract_balance_configs(
+            &self,
+        ) -> BoxedIter<StorageResult<ContractBalanceConfig>> {
+            core::iter::empty().into_boxed()
+        }
+
+        fn iter_message_configs(&self) -> BoxedIter<StorageResult<MessageConfig>> {
+            core::iter::empty().into_boxed()
+        }
+
+        fn iter_spent_messages(&self) -> BoxedIter<StorageResult<Nonce>> {
+            self.spent_messages.clone().into_iter().map(Ok).into_boxed()
+        }
+    }
+
+    fn coin(amount: u64) -> CoinConfig {
+        CoinConfig {
+            tx_id: Some(Bytes32::from([amount as u8; 32])),
+            output_index: Some(0),
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner: Default::default(),
+            amount,
+            asset_id: Default::default(),
+        }
+    }
+
+    fn snapshot_roundtrip_in_chunks(encoding: SnapshotEncoding) {
+        let dir = tempfile::tempdir().unwrap();
+        let db = TestDb {
+            coins: (0..5).map(coin).collect(),
+            state: vec![ContractStateConfig {
+                contract_id: ContractId::from([1; 32]),
+                key: Bytes32::from([2; 32]),
+                value: Bytes32::from([3; 32]),
+            }],
+            spent_messages: vec![Nonce::from([4; 32])],
+        };
+
+        write_snapshot(&db, ChainConfig::local_testnet(), dir.path(), 2, encoding)
+            .unwrap();
+
+        let reader = SnapshotReader::open(dir.path()).unwrap();
+        assert_eq!(reader.encoding(), encoding);
+        let chunks = reader
+            .chunks::<CoinConfig>()
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            chunks.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        assert_eq!(chunks.concat(), db.coins);
+        let state = reader
+            .chunks::<ContractStateConfig>()
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(state.concat(), db.state);
+        assert_eq!(reader.chunks::<MessageConfig>().count(), 0);
+        let spent_messages = reader
+            .chunks::<SpentMessageConfig>()
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            spent_messages.concat(),
+            vec![SpentMessageConfig {
+                nonce: Nonce::from([4; 32])
+            }]
+        );
+
+        let chain_config = reader.chain_config().unwrap();
+        let state = chain_config.initial_state.unwrap();
+        assert_eq!(state.height, Some(10u32.into()));
+        assert_eq!(state.last_block_id, Some(Bytes32::from([10; 32])));
+        assert_eq!(state.coins, None);
+    }
+
+    #[test]
+    fn json_snapshot_roundtrip_in_chunks() {
+        snapshot_roundtrip_in_chunks(SnapshotEncoding::Json)
+    }
+
+    #[test]
+    fn binary_snapshot_roundtrip_in_chunks() {
+        snapshot_roundtrip_in_chunks(SnapshotEncoding::Binary)
+    }
+
+    #[test]
+    fn incomplete_snapshot_can_not_be_opened() {
+        let dir = tempfile::tempdir().unwrap();
+        let writer = SnapshotWriter::new(dir.path(), 2, SnapshotEncoding::Json).unwrap();
+        writer.write_entries((0..3).map(coin).map(Ok)).unwrap();
+
+        assert!(SnapshotReader::open(dir.path()).is_err());
+    }
+
+    #[test]
+    fn snapshot_is_not_written_into_not_empty_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let writer = SnapshotWriter::new(dir.path(), 2, SnapshotEncoding::Json).unwrap();
+        writer
+            .write_chain_config(&ChainConfig::local_testnet())
+            .unwrap();
+
+        assert!(SnapshotWriter::new(dir.path(), 2, SnapshotEncoding::Json).is_err());
+    }
+}
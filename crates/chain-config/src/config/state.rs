@@ -1,7 +1,19 @@
-use crate::serialization::HexNumber;
+use crate::serialization::{
+    HexNumber,
+    HexType,
+};
 
-use fuel_core_storage::Result as StorageResult;
-use fuel_core_types::blockchain::primitives::BlockHeight;
+use fuel_core_storage::{
+    iter::BoxedIter,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    fuel_types::{
+        Bytes32,
+        Nonce,
+    },
+};
 
 use serde::{
     Deserialize,
@@ -16,9 +28,14 @@ use super::{
     coin::CoinConfig,
     contract::ContractConfig,
     message::MessageConfig,
+    snapshot::{
+        ContractBalanceConfig,
+        ContractStateConfig,
+    },
 };
 
-// TODO: do streaming deserialization to handle large state configs
+/// The state embedded into the chain config. Large states should use the streaming
+/// [`SnapshotReader`](crate::SnapshotReader) instead.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
@@ -33,6 +50,16 @@ pub struct StateConfig {
     #[serde_as(as = "Option<HexNumber>")]
     #[serde(default)]
     pub height: Option<BlockHeight>,
+    /// The id of the block at the `height` of the chain from which the state was taken.
+    /// If set, the genesis block is created at `height + 1` and refers to this block.
+    #[serde_as(as = "Option<HexType>")]
+    #[serde(default)]
+    pub last_block_id: Option<Bytes32>,
+    /// Nonces of messages already spent on the chain from which the state was taken.
+    /// They can't be spent again if the relayer imports them one more time.
+    #[serde_as(as = "Option<Vec<HexType>>")]
+    #[serde(default)]
+    pub spent_messages: Option<Vec<Nonce>>,
 }
 
 impl StateConfig {
@@ -45,6 +72,8 @@ impl StateConfig {
             contracts: db.get_contract_config()?,
             messages: db.get_message_config()?,
             height: Some(db.get_block_height()?),
+            last_block_id: Some(db.get_block_id()?),
+            spent_messages: Some(db.iter_spent_messages().collect::<StorageResult<_>>()?),
         })
     }
 }
@@ -58,4 +87,22 @@ pub trait ChainConfigDb {
     fn get_message_config(&self) -> StorageResult<Option<Vec<MessageConfig>>>;
     /// Returns the last available block height.
     fn get_block_height(&self) -> StorageResult<BlockHeight>;
+    /// Returns the id of the last available block.
+    fn get_block_id(&self) -> StorageResult<Bytes32>;
+    /// Returns an iterator over *all* unspent coin configs available in the database.
+    fn iter_coin_configs(&self) -> BoxedIter<StorageResult<CoinConfig>>;
+    /// Returns an iterator over *alive* contract configs without the state and balances.
+    fn iter_contract_configs(&self) -> BoxedIter<StorageResult<ContractConfig>>;
+    /// Returns an iterator over the state of *all* contracts.
+    fn iter_contract_state_configs(
+        &self,
+    ) -> BoxedIter<StorageResult<ContractStateConfig>>;
+    /// Returns an iterator over the balances of *all* contracts.
+    fn iter_contract_balance_configs(
+        &self,
+    ) -> BoxedIter<StorageResult<ContractBalanceConfig>>;
+    /// Returns an iterator over *all* unspent message configs available in the database.
+    fn iter_message_configs(&self) -> BoxedIter<StorageResult<MessageConfig>>;
+    /// Returns an iterator over nonces of *all* spent messages.
+    fn iter_spent_messages(&self) -> BoxedIter<StorageResult<Nonce>>;
 }
@@ -13,13 +13,16 @@ pub trait GenesisCommitment {
 
 impl GenesisCommitment for Genesis {
     fn root(&self) -> anyhow::Result<MerkleRoot> {
-        let genesis_hash = *Hasher::default()
+        let mut hasher = Hasher::default()
             .chain(self.chain_config_hash)
             .chain(self.coins_root)
             .chain(self.contracts_root)
-            .chain(self.messages_root)
-            .finalize();
+            .chain(self.messages_root);
+        // Keeps the root of the genesis of a fresh chain unchanged.
+        if let Some(block_id) = &self.previous_chain_block_id {
+            hasher = hasher.chain(block_id.as_slice());
+        }
 
-        Ok(genesis_hash)
+        Ok(*hasher.finalize())
     }
 }
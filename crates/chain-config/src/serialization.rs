@@ -7,7 +7,11 @@ use fuel_core_types::{
     fuel_types::bytes::WORD_SIZE,
 };
 use serde::{
-    de::Error,
+    de::{
+        Error,
+        SeqAccess,
+        Visitor,
+    },
     Deserializer,
     Serializer,
 };
@@ -35,6 +39,11 @@ impl<'de> DeserializeAs<'de, BlockHeight> for HexNumber {
     where
         D: Deserializer<'de>,
     {
+        // Binary formats store the height as it was serialized.
+        if !deserializer.is_human_readable() {
+            let number: u32 = HexNumber::deserialize_as(deserializer)?;
+            return Ok(number.into())
+        }
         let number: u64 = HexNumber::deserialize_as(deserializer)?;
         Ok(number.into())
     }
@@ -60,6 +69,7 @@ impl<'de> DeserializeAs<'de, DaBlockHeight> for HexNumber {
     }
 }
 
+/// Hex string in human-readable formats(JSON) and raw bytes in binary formats.
 pub(crate) struct HexType;
 
 impl<T: AsRef<[u8]>> SerializeAs<T> for HexType {
@@ -67,7 +77,11 @@ impl<T: AsRef<[u8]>> SerializeAs<T> for HexType {
     where
         S: Serializer,
     {
-        serde_hex::serialize(value, serializer)
+        if serializer.is_human_readable() {
+            serde_hex::serialize(value, serializer)
+        } else {
+            serializer.serialize_bytes(value.as_ref())
+        }
     }
 }
 
@@ -80,7 +94,47 @@ where
     where
         D: Deserializer<'de>,
     {
-        serde_hex::deserialize(deserializer)
+        if deserializer.is_human_readable() {
+            serde_hex::deserialize(deserializer)
+        } else {
+            let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+            T::try_from(bytes.as_slice()).map_err(D::Error::custom)
+        }
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a byte array")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
     }
 }
 
@@ -128,6 +182,9 @@ macro_rules! impl_hex_number {
             where
                 S: Serializer,
             {
+                if !serializer.is_human_readable() {
+                    return serde::Serialize::serialize(value, serializer)
+                }
                 let bytes = value.to_be_bytes();
                 serde_hex::serialize(bytes, serializer)
             }
@@ -138,6 +195,9 @@ macro_rules! impl_hex_number {
             where
                 D: Deserializer<'de>,
             {
+                if !deserializer.is_human_readable() {
+                    return serde::Deserialize::deserialize(deserializer)
+                }
                 const SIZE: usize = core::mem::size_of::<$i>();
                 let mut bytes: Vec<u8> = serde_hex::deserialize(deserializer)?;
                 match bytes.len() {
@@ -5,7 +5,11 @@ use crate::client::schema::{
         SpendQueryElementInput,
     },
     contract::ContractBalanceQueryArgs,
-    tx::DryRunArg,
+    tx::{
+        DryRunArg,
+        DryRunBatchArgs,
+        StateOverridesInput,
+    },
     Tai64Timestamp,
 };
 use anyhow::Context;
@@ -36,6 +40,7 @@ use fuel_core_types::{
 use futures::StreamExt;
 use itertools::Itertools;
 use schema::{
+    admin::BackupDatabaseArgs,
     balance::BalanceArgs,
     block::BlockByIdArgs,
     coins::{
@@ -47,6 +52,7 @@ use schema::{
         ContractByIdArgs,
     },
     tx::{
+        TransactionHistoryFilterInput,
         TxArg,
         TxIdArgs,
     },
@@ -90,6 +96,7 @@ use std::{
 use tai64::Tai64;
 use tracing as _;
 use types::{
+    OwnerTransactionResponse,
     TransactionResponse,
     TransactionStatus,
 };
@@ -105,6 +112,7 @@ pub mod types;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FuelClient {
     url: reqwest::Url,
+    admin_token: Option<String>,
 }
 
 impl FromStr for FuelClient {
@@ -119,7 +127,10 @@ impl FromStr for FuelClient {
         let mut url = reqwest::Url::parse(&raw_url)
             .with_context(|| format!("Invalid fuel-core URL: {str}"))?;
         url.set_path("/graphql");
-        Ok(Self { url })
+        Ok(Self {
+            url,
+            admin_token: None,
+        })
     }
 }
 
@@ -151,6 +162,13 @@ impl FuelClient {
         Self::from_str(url.as_ref())
     }
 
+    /// Sends the `token` in the `Authorization` header to be allowed to use the
+    /// privileged operations of the node, like the block production or the debugger.
+    pub fn with_admin_token(mut self, token: impl Into<String>) -> Self {
+        self.admin_token = Some(token.into());
+        self
+    }
+
     async fn query<ResponseData, Vars>(
         &self,
         q: Operation<ResponseData, Vars>,
@@ -159,8 +177,11 @@ impl FuelClient {
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
-        let response = reqwest::Client::new()
-            .post(self.url.clone())
+        let mut request = reqwest::Client::new().post(self.url.clone());
+        if let Some(token) = &self.admin_token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
             .run_graphql(q)
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
@@ -265,6 +286,145 @@ impl FuelClient {
         Ok(stream)
     }
 
+    /// Subscribes via the `/graphql-ws` endpoint using the `graphql-transport-ws`
+    /// protocol. The stream ends when the server completes the subscription.
+    #[tracing::instrument(skip_all)]
+    #[cfg(feature = "subscriptions")]
+    async fn subscribe_ws<ResponseData, Vars>(
+        &self,
+        q: StreamingOperation<ResponseData, Vars>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<ResponseData>>>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        use futures::SinkExt;
+        use tokio_tungstenite::tungstenite::{
+            client::IntoClientRequest,
+            http::HeaderValue,
+            Message,
+        };
+
+        #[derive(serde::Deserialize)]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        enum ServerMessage<ResponseData> {
+            ConnectionAck,
+            Ping,
+            Pong,
+            Next {
+                payload: GraphQlResponse<ResponseData>,
+            },
+            Error {
+                payload: serde_json::Value,
+            },
+            Complete,
+        }
+
+        fn other_error<E: std::fmt::Debug>(context: &str, e: E) -> io::Error {
+            io::Error::new(io::ErrorKind::Other, format!("{context}: {e:?}"))
+        }
+
+        let mut url = self.url.clone();
+        let scheme = if url.scheme() == "https" { "wss" } else { "ws" };
+        url.set_scheme(scheme)
+            .map_err(|e| other_error("Invalid WebSocket url", e))?;
+        url.set_path("/graphql-ws");
+        let mut request = url
+            .as_str()
+            .into_client_request()
+            .map_err(|e| other_error("Invalid WebSocket request", e))?;
+        request.headers_mut().insert(
+            "Sec-WebSocket-Protocol",
+            HeaderValue::from_static("graphql-transport-ws"),
+        );
+        let (mut socket, _) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|e| other_error("Failed to connect", e))?;
+
+        let init = serde_json::json!({ "type": "connection_init" });
+        socket
+            .send(Message::Text(init.to_string()))
+            .await
+            .map_err(|e| other_error("Failed to initialize connection", e))?;
+        loop {
+            let message = match socket.next().await {
+                Some(Ok(Message::Text(text))) => text,
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => return Err(other_error("WebSocket error", e)),
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        "The connection was closed before the acknowledgment",
+                    ))
+                }
+            };
+            match serde_json::from_str::<ServerMessage<ResponseData>>(&message)? {
+                ServerMessage::ConnectionAck => break,
+                ServerMessage::Ping => {
+                    let pong = serde_json::json!({ "type": "pong" });
+                    socket
+                        .send(Message::Text(pong.to_string()))
+                        .await
+                        .map_err(|e| other_error("WebSocket error", e))?;
+                }
+                _ => {}
+            }
+        }
+
+        let subscribe = serde_json::json!({
+            "id": "1",
+            "type": "subscribe",
+            "payload": q,
+        });
+        socket
+            .send(Message::Text(subscribe.to_string()))
+            .await
+            .map_err(|e| other_error("Failed to subscribe", e))?;
+
+        let stream = futures::stream::unfold(Some(socket), |socket| async move {
+            let mut socket = socket?;
+            loop {
+                let message = match socket.next().await {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None => return None,
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => {
+                        return Some((Err(other_error("WebSocket error", e)), None))
+                    }
+                };
+                tracing::debug!("Got message: {message}");
+                let message =
+                    match serde_json::from_str::<ServerMessage<ResponseData>>(&message) {
+                        Ok(message) => message,
+                        Err(e) => {
+                            return Some((Err(other_error("Json error", e)), Some(socket)))
+                        }
+                    };
+                match message {
+                    ServerMessage::Next { payload } => {
+                        let result = Self::decode_response(payload)
+                            .map_err(|e| other_error("Decode error", e));
+                        return Some((result, Some(socket)))
+                    }
+                    ServerMessage::Error { payload } => {
+                        return Some((Err(other_error("Graphql error", payload)), None))
+                    }
+                    ServerMessage::Complete => return None,
+                    ServerMessage::Ping => {
+                        let pong = serde_json::json!({ "type": "pong" });
+                        if let Err(e) = socket.send(Message::Text(pong.to_string())).await
+                        {
+                            return Some((Err(other_error("WebSocket error", e)), None))
+                        }
+                    }
+                    ServerMessage::ConnectionAck | ServerMessage::Pong => {}
+                }
+            }
+        });
+
+        Ok(Box::pin(stream))
+    }
+
     pub async fn health(&self) -> io::Result<bool> {
         let query = schema::Health::build(());
         self.query(query).await.map(|r| r.health)
@@ -304,6 +464,54 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run the sequence of transactions after the block at `height` with the state
+    /// `overrides`. Every transaction sees the changes of the previous ones.
+    pub async fn dry_run_batch(
+        &self,
+        txs: &[Transaction],
+        height: Option<u64>,
+        overrides: Option<StateOverridesInput>,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<schema::tx::DryRunResult>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+            .collect();
+        let query = schema::tx::DryRunBatch::build(DryRunBatchArgs {
+            txs,
+            height: height.map(Into::into),
+            overrides,
+            utxo_validation,
+        });
+        let results = self.query(query).await.map(|r| r.dry_run_batch)?;
+        Ok(results)
+    }
+
+    /// Estimate the gas limit and the fee of the transaction, including the gas used by
+    /// its predicates.
+    pub async fn estimate_gas(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<schema::gas::GasEstimation> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::gas::EstimateGas::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+        let estimation = self.query(query).await.map(|r| r.estimate_gas)?;
+        Ok(estimation)
+    }
+
+    /// Estimate the gas used by the predicates of the transaction.
+    pub async fn estimate_predicates(&self, tx: &Transaction) -> io::Result<u64> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::gas::EstimatePredicates::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+        let gas_used = self.query(query).await.map(|r| r.estimate_predicates)?;
+        Ok(gas_used.0)
+    }
+
     pub async fn submit(&self, tx: &Transaction) -> io::Result<TransactionId> {
         let tx = tx.clone().to_bytes();
         let query = schema::tx::Submit::build(TxArg {
@@ -444,6 +652,18 @@ impl FuelClient {
         Ok(transaction.map(|tx| tx.try_into()).transpose()?)
     }
 
+    /// Get the call trace of the committed transaction. The node records the traces
+    /// only if it is configured to do so.
+    pub async fn transaction_trace(
+        &self,
+        id: &str,
+    ) -> io::Result<Option<schema::tx::TransactionTrace>> {
+        let query =
+            schema::tx::TransactionTraceQuery::build(TxIdArgs { id: id.parse()? });
+        let trace = self.query(query).await?.transaction_trace;
+        Ok(trace)
+    }
+
     /// Get the status of a transaction
     pub async fn transaction_status(&self, id: &str) -> io::Result<TransactionStatus> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: id.parse()? });
@@ -478,7 +698,7 @@ impl FuelClient {
         let s = schema::tx::StatusChangeSubscription::build(TxIdArgs { id: id.parse()? });
 
         tracing::debug!("subscribing");
-        let stream = self.subscribe(s).await?.map(|tx| {
+        let stream = self.subscribe_ws(s).await?.map(|tx| {
             tracing::debug!("received {tx:?}");
             let tx = tx?;
             let status = tx.status_change.try_into()?;
@@ -488,6 +708,47 @@ impl FuelClient {
         Ok(stream)
     }
 
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the new blocks with their transactions. If `start_height` is set,
+    /// the stream begins with the existing blocks starting at this height.
+    pub async fn subscribe_new_blocks(
+        &self,
+        start_height: Option<u64>,
+    ) -> io::Result<
+        impl futures::Stream<Item = io::Result<schema::block::BlockWithTransactions>>,
+    > {
+        use cynic::SubscriptionBuilder;
+        let s =
+            schema::block::NewBlocksSubscription::build(schema::block::NewBlocksArgs {
+                start_height: start_height.map(Into::into),
+            });
+
+        let stream = self.subscribe(s).await?.map(|block| Ok(block?.new_blocks));
+        Ok(stream)
+    }
+
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the headers of the new blocks. If `start_height` is set,
+    /// the stream begins with the existing headers starting at this height.
+    pub async fn subscribe_new_headers(
+        &self,
+        start_height: Option<u64>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<schema::block::Header>>> {
+        use cynic::SubscriptionBuilder;
+        let s =
+            schema::block::NewHeadersSubscription::build(schema::block::NewBlocksArgs {
+                start_height: start_height.map(Into::into),
+            });
+
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|header| Ok(header?.new_headers));
+        Ok(stream)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Awaits for the transaction to be committed into a block
     ///
@@ -498,7 +759,6 @@ impl FuelClient {
         id: &str,
     ) -> io::Result<TransactionStatus> {
         // skip until we've reached a final status and then stop consuming the stream
-        // to avoid an EOF which the eventsource client considers as an error.
         let status_result = self
             .subscribe_transaction_status(id)
             .await?
@@ -541,6 +801,19 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions of the owner from the `filter`
+    /// with the changes of the owner's balances.
+    pub async fn transaction_history(
+        &self,
+        filter: TransactionHistoryFilterInput,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<OwnerTransactionResponse, String>> {
+        let query = schema::tx::TransactionHistoryQuery::build((filter, request).into());
+
+        let transactions = self.query(query).await?.transaction_history.try_into()?;
+        Ok(transactions)
+    }
+
     pub async fn receipts(&self, id: &str) -> io::Result<Vec<Receipt>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: id.parse()? });
 
@@ -574,6 +847,18 @@ impl FuelClient {
         Ok(new_height.into())
     }
 
+    /// Creates a backup of the node's database in the `name` directory inside of
+    /// the backup directory of the node. Returns the path to the backup.
+    pub async fn backup_database(&self, name: &str) -> io::Result<String> {
+        let query = schema::admin::BackupDatabaseMutation::build(BackupDatabaseArgs {
+            name: name.to_string(),
+        });
+
+        let path = self.query(query).await?.backup_database;
+
+        Ok(path)
+    }
+
     pub async fn block(&self, id: &str) -> io::Result<Option<schema::block::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some(id.parse()?),
@@ -19,11 +19,13 @@ use thiserror::Error;
 
 pub use primitives::*;
 
+pub mod admin;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
+pub mod gas;
 pub mod message;
 pub mod node_info;
 pub mod primitives;
@@ -0,0 +1,31 @@
+use crate::client::schema::schema;
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BackupDatabaseArgs {
+    pub name: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "BackupDatabaseArgs",
+    graphql_type = "Mutation"
+)]
+pub struct BackupDatabaseMutation {
+    #[arguments(name: $name)]
+    pub backup_database: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backup_database_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = BackupDatabaseMutation::build(BackupDatabaseArgs {
+            name: "backup".to_string(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -13,7 +13,10 @@ use crate::client::{
 use fuel_core_types::fuel_crypto;
 
 use super::{
-    tx::TransactionIdFragment,
+    tx::{
+        OpaqueTransaction,
+        TransactionIdFragment,
+    },
     Bytes32,
 };
 
@@ -100,6 +103,43 @@ pub struct BlockIdFragment {
     pub id: BlockId,
 }
 
+/// The block with the full transactions and their statuses.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
+pub struct BlockWithTransactions {
+    pub id: BlockId,
+    pub header: Header,
+    pub consensus: Consensus,
+    pub transactions: Vec<OpaqueTransaction>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NewBlocksArgs {
+    pub start_height: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "NewBlocksArgs"
+)]
+pub struct NewBlocksSubscription {
+    #[arguments(startHeight: $start_height)]
+    pub new_blocks: BlockWithTransactions,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "NewBlocksArgs"
+)]
+pub struct NewHeadersSubscription {
+    #[arguments(startHeight: $start_height)]
+    pub new_headers: Header,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct ProduceBlockArgs {
     pub start_timestamp: Option<Tai64Timestamp>,
@@ -148,6 +188,7 @@ pub struct Genesis {
     pub coins_root: Bytes32,
     pub contracts_root: Bytes32,
     pub messages_root: Bytes32,
+    pub previous_chain_block_id: Option<BlockId>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
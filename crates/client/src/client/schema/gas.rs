@@ -0,0 +1,70 @@
+use crate::client::schema::{
+    schema,
+    tx::TxArg,
+    U64,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct GasEstimation {
+    pub script_gas_used: U64,
+    pub predicate_gas_used: U64,
+    pub gas_limit: U64,
+    pub gas_price: U64,
+    pub total_fee: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct EstimateGas {
+    #[arguments(tx: $tx)]
+    pub estimate_gas: GasEstimation,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct EstimatePredicates {
+    #[arguments(tx: $tx)]
+    pub estimate_predicates: U64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::schema::{
+        Bytes,
+        HexString,
+    };
+    use fuel_core_types::{
+        fuel_tx,
+        fuel_types::bytes::SerializableVec,
+    };
+
+    #[test]
+    fn estimate_gas_query_gql_output() {
+        use cynic::QueryBuilder;
+        let mut tx = fuel_tx::Transaction::default();
+        let operation = EstimateGas::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn estimate_predicates_query_gql_output() {
+        use cynic::QueryBuilder;
+        let mut tx = fuel_tx::Transaction::default();
+        let operation = EstimatePredicates::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
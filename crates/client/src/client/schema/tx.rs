@@ -1,16 +1,25 @@
 use super::block::BlockIdFragment;
 use crate::client::{
     schema::{
+        coins::Coin,
+        contract::ContractBalance,
         schema,
         Address,
+        AssetId,
         ConnectionArgs,
+        ContractId,
         ConversionError,
         HexString,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        UtxoId,
+        U64,
+    },
+    types::{
+        OwnerTransactionResponse,
+        TransactionResponse,
     },
-    types::TransactionResponse,
     PageDirection,
     PaginatedResult,
     PaginationRequest,
@@ -22,6 +31,7 @@ use fuel_core_types::{
         Bytes32,
     },
     fuel_vm,
+    services::graphql_api,
 };
 use std::convert::{
     TryFrom,
@@ -48,6 +58,33 @@ pub struct TransactionQuery {
     pub transaction: Option<OpaqueTransaction>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionTraceQuery {
+    #[arguments(id: $id)]
+    pub transaction_trace: Option<TransactionTrace>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionTrace {
+    pub gas_used: U64,
+    pub frames: Vec<CallFrameTrace>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CallFrameTrace {
+    pub parent: Option<i32>,
+    pub contract: Option<ContractId>,
+    pub gas_forwarded: U64,
+    pub receipts: Vec<transparent_receipt::Receipt>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -256,6 +293,147 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransferDirection {
+    In,
+    Out,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransactionHistoryStatus {
+    Success,
+    Reverted,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionHistoryFilterInput {
+    /// Select transactions based on related `owner`s
+    pub owner: Address,
+    /// Select transactions changing the balance of the asset
+    pub asset_id: Option<AssetId>,
+    /// Select transactions increasing or decreasing the balance of the owner
+    pub direction: Option<TransferDirection>,
+    /// Select transactions with the status
+    pub status: Option<TransactionHistoryStatus>,
+    /// Select transactions from blocks starting at this height
+    pub from_height: Option<U64>,
+    /// Select transactions from blocks up to this height
+    pub to_height: Option<U64>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionHistoryConnectionArgs {
+    /// Select transactions based on a filter
+    pub filter: TransactionHistoryFilterInput,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(TransactionHistoryFilterInput, PaginationRequest<String>)>
+    for TransactionHistoryConnectionArgs
+{
+    fn from(r: (TransactionHistoryFilterInput, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionHistoryConnectionArgs {
+                filter: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => TransactionHistoryConnectionArgs {
+                filter: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionHistoryConnectionArgs"
+)]
+pub struct TransactionHistoryQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub transaction_history: OwnerTransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OwnerTransactionConnection {
+    pub edges: Vec<OwnerTransactionEdge>,
+    pub page_info: PageInfo,
+    pub scan_cursor: Option<String>,
+}
+
+impl TryFrom<OwnerTransactionConnection>
+    for PaginatedResult<OwnerTransactionResponse, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: OwnerTransactionConnection) -> Result<Self, Self::Error> {
+        let results: Result<Vec<OwnerTransactionResponse>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            // The page stopped at the limit of the scanned transactions continues
+            // from the last scanned one.
+            cursor: conn.scan_cursor.or(conn.page_info.end_cursor),
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OwnerTransactionEdge {
+    pub cursor: String,
+    pub node: OwnerTransaction,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OwnerTransaction {
+    pub transaction: OpaqueTransaction,
+    pub reverted: bool,
+    pub deltas: Vec<BalanceDelta>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BalanceDelta {
+    pub asset_id: AssetId,
+    pub received: U64,
+    pub spent: U64,
+}
+
+impl From<BalanceDelta> for graphql_api::BalanceDelta {
+    fn from(delta: BalanceDelta) -> Self {
+        graphql_api::BalanceDelta {
+            asset_id: delta.asset_id.into(),
+            received: delta.received.into(),
+            spent: delta.spent.into(),
+        }
+    }
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -291,6 +469,89 @@ pub struct DryRun {
     pub dry_run: Vec<transparent_receipt::Receipt>,
 }
 
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateOverridesInput {
+    /// The coins added to the state
+    pub coins: Vec<CoinOverrideInput>,
+    /// The values of the contracts storage slots
+    pub contract_state: Vec<ContractStateOverrideInput>,
+    /// The bytecode of the contracts
+    pub contract_code: Vec<ContractCodeOverrideInput>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinOverrideInput {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub amount: U64,
+    pub asset_id: AssetId,
+    pub maturity: Option<U64>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStateOverrideInput {
+    pub contract: ContractId,
+    pub key: super::Bytes32,
+    /// `None` clears the slot
+    pub value: Option<super::Bytes32>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractCodeOverrideInput {
+    pub contract: ContractId,
+    pub bytecode: HexString,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DryRunBatchArgs {
+    pub txs: Vec<HexString>,
+    pub height: Option<U64>,
+    pub overrides: Option<StateOverridesInput>,
+    pub utxo_validation: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunBatchArgs"
+)]
+pub struct DryRunBatch {
+    #[arguments(txs: $txs, height: $height, overrides: $overrides, utxoValidation: $utxo_validation)]
+    pub dry_run_batch: Vec<DryRunResult>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunResult {
+    pub transaction_id: TransactionId,
+    pub receipts: Vec<transparent_receipt::Receipt>,
+    pub gas_used: U64,
+    pub state_diff: StateDiff,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateDiff {
+    pub created_coins: Vec<Coin>,
+    pub spent_coins: Vec<UtxoId>,
+    pub created_contracts: Vec<ContractId>,
+    pub contract_state: Vec<ContractStateSlot>,
+    pub contract_balances: Vec<ContractBalance>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStateSlot {
+    pub contract: ContractId,
+    pub key: super::Bytes32,
+    pub value: Option<super::Bytes32>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -317,6 +578,15 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn transaction_trace_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionTraceQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn opaque_transaction_by_id_query_gql_output() {
         use cynic::QueryBuilder;
@@ -352,6 +622,27 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn transaction_history_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TransactionHistoryQuery::build(TransactionHistoryConnectionArgs {
+                filter: TransactionHistoryFilterInput {
+                    owner: Default::default(),
+                    asset_id: None,
+                    direction: None,
+                    status: None,
+                    from_height: None,
+                    to_height: None,
+                },
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -363,6 +654,19 @@ pub mod tests {
         insta::assert_snapshot!(query.query)
     }
 
+    #[test]
+    fn dry_run_batch_gql_output() {
+        use cynic::MutationBuilder;
+        let mut tx = fuel_tx::Transaction::default();
+        let query = DryRunBatch::build(DryRunBatchArgs {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            height: None,
+            overrides: None,
+            utxo_validation: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
     #[test]
     fn submit_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -1,6 +1,7 @@
 use crate::client::schema::{
     tx::{
         OpaqueTransaction,
+        OwnerTransaction,
         TransactionStatus as SchemaTxStatus,
     },
     ConversionError,
@@ -9,6 +10,7 @@ use fuel_core_types::{
     fuel_tx::Transaction,
     fuel_types::bytes::Deserializable,
     fuel_vm::ProgramState,
+    services::graphql_api::BalanceDelta,
 };
 use serde::{
     Deserialize,
@@ -22,6 +24,14 @@ pub struct TransactionResponse {
     pub status: TransactionStatus,
 }
 
+/// The transaction of the owner with the changes of the owner's balances.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnerTransactionResponse {
+    pub transaction: TransactionResponse,
+    pub reverted: bool,
+    pub deltas: Vec<BalanceDelta>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransactionStatus {
     Submitted {
@@ -90,3 +100,15 @@ impl TryFrom<OpaqueTransaction> for TransactionResponse {
         })
     }
 }
+
+impl TryFrom<OwnerTransaction> for OwnerTransactionResponse {
+    type Error = ConversionError;
+
+    fn try_from(value: OwnerTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction: value.transaction.try_into()?,
+            reverted: value.reverted,
+            deltas: value.deltas.into_iter().map(Into::into).collect(),
+        })
+    }
+}
@@ -8,18 +8,30 @@ use crate::{
 use fuel_core_chain_config::{
     ChainConfigDb,
     CoinConfig,
+    ContractBalanceConfig,
     ContractConfig,
+    ContractStateConfig,
     MessageConfig,
 };
 use fuel_core_storage::{
-    iter::IterDirection,
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+    },
     transactional::{
         StorageTransaction,
         Transactional,
     },
     Result as StorageResult,
 };
-use fuel_core_types::blockchain::primitives::BlockHeight;
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    fuel_types::{
+        Bytes32,
+        Nonce,
+    },
+};
 use serde::{
     de::DeserializeOwned,
     Serialize,
@@ -31,6 +43,7 @@ use std::{
         Formatter,
     },
     marker::Send,
+    path::Path,
     sync::Arc,
 };
 
@@ -42,9 +55,10 @@ type DatabaseResult<T> = Result<T>;
 
 // TODO: Extract `Database` and all belongs into `fuel-core-database`.
 #[cfg(feature = "rocksdb")]
-use crate::state::rocks_db::RocksDb;
-#[cfg(feature = "rocksdb")]
-use std::path::Path;
+use crate::state::rocks_db::{
+    RocksDb,
+    RocksDbConfig,
+};
 #[cfg(feature = "rocksdb")]
 use tempfile::TempDir;
 
@@ -61,9 +75,14 @@ mod sealed_block;
 mod state;
 
 pub(crate) mod coin;
+pub(crate) mod owned_balances;
 
 pub mod balances;
+pub mod banned_peers;
 pub mod metadata;
+pub mod migration;
+pub mod pending_transactions;
+pub mod pruning;
 pub mod storage;
 pub mod transaction;
 pub mod transactions;
@@ -101,8 +120,8 @@ pub enum Column {
     Receipts = 11,
     /// See [`FuelBlocks`](fuel_core_storage::tables::FuelBlocks)
     FuelBlocks = 12,
-    /// See [`FuelBlockSecondaryKeyBlockHeights`](storage::FuelBlockSecondaryKeyBlockHeights)
-    FuelBlockSecondaryKeyBlockHeights = 13,
+    /// See [`FuelBlockIdsToHeights`](storage::FuelBlockIdsToHeights)
+    FuelBlockIdsToHeights = 13,
     /// See [`Messages`](fuel_core_storage::tables::Messages)
     Messages = 14,
     /// The column of the table that stores `true` if `owner` owns `Message` with `message_id`
@@ -128,6 +147,18 @@ pub enum Column {
     ContractsStateMerkleData = 23,
     /// See [`ContractsStateMerkleMetadata`](storage::ContractsStateMerkleMetadata)
     ContractsStateMerkleMetadata = 24,
+    /// The transactions of the TxPool saved on the shutdown to restore them on the next start.
+    PendingTransactions = 25,
+    /// The column of the table of `owner`'s balances per `asset_id`
+    OwnedBalances = 26,
+    /// Transaction id to the call trace of its execution.
+    /// Only populated when the tracing is enabled in the [`crate::service::config::VMConfig`].
+    TransactionTraces = 27,
+    /// The peers banned by the p2p reputation system, restored on the next start.
+    BannedPeers = 28,
+    /// The heights of the blocks imported without their transactions by the headers-only
+    /// sync or below the checkpoint.
+    HeaderOnlyBlocks = 29,
 }
 
 #[derive(Clone, Debug)]
@@ -172,8 +203,15 @@ impl Drop for DropResources {
 impl Database {
     #[cfg(feature = "rocksdb")]
     pub fn open(path: &Path) -> DatabaseResult<Self> {
+        Self::open_with_config(path, &RocksDbConfig::default())
+    }
+
+    #[cfg(feature = "rocksdb")]
+    pub fn open_with_config(path: &Path, config: &RocksDbConfig) -> DatabaseResult<Self> {
         use anyhow::Context;
-        let db = RocksDb::default_open(path).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
+        let columns = enum_iterator::all::<Column>().collect::<Vec<_>>();
+        let db =
+            RocksDb::open(path, columns, config).context("Failed to open rocksdb")?;
 
         Ok(Database {
             data: Arc::new(db),
@@ -181,6 +219,12 @@ impl Database {
         })
     }
 
+    /// Creates a consistent backup of the database in the new directory at the `path`.
+    /// The backup can be opened with [`Database::open`] like a regular database.
+    pub fn backup(&self, path: &Path) -> DatabaseResult<()> {
+        self.data.checkpoint(path)
+    }
+
     pub fn in_memory() -> Self {
         Self {
             data: Arc::new(MemoryStore::default()),
@@ -413,6 +457,44 @@ impl ChainConfigDb for Database {
     fn get_block_height(&self) -> StorageResult<BlockHeight> {
         Self::latest_height(self)
     }
+
+    fn get_block_id(&self) -> StorageResult<Bytes32> {
+        Self::latest_block_id(self).map(Into::into)
+    }
+
+    fn iter_coin_configs(&self) -> BoxedIter<StorageResult<CoinConfig>> {
+        Self::iter_coin_configs(self)
+            .map(|result| result.map_err(Into::into))
+            .into_boxed()
+    }
+
+    fn iter_contract_configs(&self) -> BoxedIter<StorageResult<ContractConfig>> {
+        Self::iter_contract_configs(self).into_boxed()
+    }
+
+    fn iter_contract_state_configs(
+        &self,
+    ) -> BoxedIter<StorageResult<ContractStateConfig>> {
+        Self::iter_contract_state_configs(self)
+            .map(|result| result.map_err(Into::into))
+            .into_boxed()
+    }
+
+    fn iter_contract_balance_configs(
+        &self,
+    ) -> BoxedIter<StorageResult<ContractBalanceConfig>> {
+        Self::iter_contract_balance_configs(self)
+            .map(|result| result.map_err(Into::into))
+            .into_boxed()
+    }
+
+    fn iter_message_configs(&self) -> BoxedIter<StorageResult<MessageConfig>> {
+        Self::iter_message_configs(self).into_boxed()
+    }
+
+    fn iter_spent_messages(&self) -> BoxedIter<StorageResult<Nonce>> {
+        Self::iter_spent_messages(self).into_boxed()
+    }
 }
 
 #[cfg(feature = "rocksdb")]
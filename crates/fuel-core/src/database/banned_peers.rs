@@ -0,0 +1,61 @@
+//! The peers banned by the p2p reputation system, saved across the restarts of the node.
+
+use crate::database::{
+    Column,
+    Database,
+    Error as DatabaseError,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::transactional::Transaction as _;
+use fuel_core_types::services::p2p::BannedPeer;
+
+impl Database {
+    /// Returns the saved banned peers.
+    pub fn get_banned_peers(&self) -> DatabaseResult<Vec<BannedPeer>> {
+        self.iter_all::<Vec<u8>, BannedPeer>(Column::BannedPeers, None)
+            .map(|entry| entry.map(|(_, peer)| peer))
+            .collect()
+    }
+
+    /// Saves the banned peers, replacing the previously saved ones.
+    pub fn set_banned_peers(&self, peers: Vec<BannedPeer>) -> DatabaseResult<()> {
+        let mut db_tx = self.transaction();
+        let db: &Database = db_tx.as_ref();
+        let keys = db
+            .iter_all::<Vec<u8>, BannedPeer>(Column::BannedPeers, None)
+            .map(|entry| entry.map(|(key, _)| key))
+            .collect::<DatabaseResult<Vec<_>>>()?;
+        for key in keys {
+            db.data.delete(&key, Column::BannedPeers)?;
+        }
+        for peer in peers.iter() {
+            let _: Option<BannedPeer> =
+                db.insert(peer.peer_id.as_ref(), Column::BannedPeers, peer)?;
+        }
+        db_tx.commit().map_err(|e| DatabaseError::Other(e.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::tai64::Tai64;
+
+    fn banned_peer(peer_id: u8, banned_until: u64) -> BannedPeer {
+        BannedPeer {
+            peer_id: vec![peer_id; 32].into(),
+            banned_until: Tai64(banned_until),
+        }
+    }
+
+    #[test]
+    fn saving_replaces_previous_banned_peers() {
+        let db = Database::default();
+        db.set_banned_peers(vec![banned_peer(1, 10), banned_peer(2, 20)])
+            .unwrap();
+
+        db.set_banned_peers(vec![banned_peer(3, 30)]).unwrap();
+
+        assert_eq!(db.get_banned_peers().unwrap(), vec![banned_peer(3, 30)]);
+    }
+}
@@ -1,9 +1,9 @@
 use crate::database::{
     storage::{
         DenseMerkleMetadata,
+        FuelBlockIdsToHeights,
         FuelBlockMerkleData,
         FuelBlockMerkleMetadata,
-        FuelBlockSecondaryKeyBlockHeights,
         ToDatabaseKey,
     },
     Column,
@@ -41,26 +41,24 @@ use fuel_core_types::{
     tai64::Tai64,
 };
 use itertools::Itertools;
-use std::{
-    borrow::{
-        BorrowMut,
-        Cow,
-    },
-    convert::{
-        TryFrom,
-        TryInto,
-    },
+use std::borrow::{
+    BorrowMut,
+    Cow,
 };
 
 impl StorageInspect<FuelBlocks> for Database {
     type Error = StorageError;
 
-    fn get(&self, key: &BlockId) -> Result<Option<Cow<CompressedBlock>>, Self::Error> {
-        Database::get(self, key.as_slice(), Column::FuelBlocks).map_err(Into::into)
+    fn get(
+        &self,
+        key: &BlockHeight,
+    ) -> Result<Option<Cow<CompressedBlock>>, Self::Error> {
+        Database::get(self, key.database_key().as_ref(), Column::FuelBlocks)
+            .map_err(Into::into)
     }
 
-    fn contains_key(&self, key: &BlockId) -> Result<bool, Self::Error> {
-        Database::contains_key(self, key.as_slice(), Column::FuelBlocks)
+    fn contains_key(&self, key: &BlockHeight) -> Result<bool, Self::Error> {
+        Database::contains_key(self, key.database_key().as_ref(), Column::FuelBlocks)
             .map_err(Into::into)
     }
 }
@@ -68,14 +66,19 @@ impl StorageInspect<FuelBlocks> for Database {
 impl StorageMutate<FuelBlocks> for Database {
     fn insert(
         &mut self,
-        key: &BlockId,
+        key: &BlockHeight,
         value: &CompressedBlock,
     ) -> Result<Option<CompressedBlock>, Self::Error> {
-        let prev = Database::insert(self, key.as_slice(), Column::FuelBlocks, value)?;
+        let prev = Database::insert(
+            self,
+            key.database_key().as_ref(),
+            Column::FuelBlocks,
+            value,
+        )?;
 
-        let height = value.header().height();
-        self.storage::<FuelBlockSecondaryKeyBlockHeights>()
-            .insert(height, key)?;
+        let block_id = value.id();
+        self.storage::<FuelBlockIdsToHeights>()
+            .insert(&block_id, key)?;
 
         // Get latest metadata entry
         let prev_metadata = self
@@ -92,7 +95,7 @@ impl StorageMutate<FuelBlocks> for Database {
         let mut tree: MerkleTree<FuelBlockMerkleData, _> =
             MerkleTree::load(storage, prev_metadata.version)
                 .map_err(|err| StorageError::Other(err.into()))?;
-        let data = key.as_slice();
+        let data = block_id.as_slice();
         tree.push(data)?;
 
         // Generate new metadata for the updated tree
@@ -100,24 +103,24 @@ impl StorageMutate<FuelBlocks> for Database {
         let root = tree.root();
         let metadata = DenseMerkleMetadata { version, root };
         self.storage::<FuelBlockMerkleMetadata>()
-            .insert(height, &metadata)?;
+            .insert(key, &metadata)?;
 
         Ok(prev)
     }
 
-    fn remove(&mut self, key: &BlockId) -> Result<Option<CompressedBlock>, Self::Error> {
+    fn remove(
+        &mut self,
+        key: &BlockHeight,
+    ) -> Result<Option<CompressedBlock>, Self::Error> {
         let prev: Option<CompressedBlock> =
-            Database::remove(self, key.as_slice(), Column::FuelBlocks)?;
+            Database::remove(self, key.database_key().as_ref(), Column::FuelBlocks)?;
 
         if let Some(block) = &prev {
-            let height = block.header().height();
-            let _ = self
-                .storage::<FuelBlockSecondaryKeyBlockHeights>()
-                .remove(height);
+            let _ = self.storage::<FuelBlockIdsToHeights>().remove(&block.id());
             // We can't clean up `MerkleTree<FuelBlockMerkleData>`.
             // But if we plan to insert a new block, it will override old values in the
             // `FuelBlockMerkleData` table.
-            let _ = self.storage::<FuelBlockMerkleMetadata>().remove(height);
+            let _ = self.storage::<FuelBlockMerkleMetadata>().remove(key);
         }
 
         Ok(prev)
@@ -131,93 +134,126 @@ impl Database {
             .ok_or(not_found!("BlockHeight"))
     }
 
+    pub fn latest_block_id(&self) -> StorageResult<BlockId> {
+        self.ids_of_latest_block()?
+            .map(|(_, id)| id)
+            .ok_or(not_found!("BlockId"))
+    }
+
     /// Get the current block at the head of the chain.
     pub fn get_current_block(&self) -> StorageResult<Option<Cow<CompressedBlock>>> {
-        let block_ids = self.ids_of_latest_block()?;
-        match block_ids {
-            Some((_, id)) => Ok(StorageAsRef::storage::<FuelBlocks>(self).get(&id)?),
-            None => Ok(None),
-        }
+        let block = self
+            .iter_all::<Vec<u8>, CompressedBlock>(
+                Column::FuelBlocks,
+                Some(IterDirection::Reverse),
+            )
+            .next()
+            .transpose()?
+            .map(|(_, block)| Cow::Owned(block));
+
+        Ok(block)
     }
 
     pub fn block_time(&self, height: &BlockHeight) -> StorageResult<Tai64> {
-        let id = self.get_block_id(height)?.unwrap_or_default();
         let block = self
             .storage::<FuelBlocks>()
-            .get(&id)?
+            .get(height)?
             .ok_or(not_found!(FuelBlocks))?;
         Ok(block.header().time().to_owned())
     }
 
     pub fn get_block_id(&self, height: &BlockHeight) -> StorageResult<Option<BlockId>> {
-        Database::get(
+        Ok(self
+            .storage::<FuelBlocks>()
+            .get(height)?
+            .map(|block| block.id()))
+    }
+
+    /// Marks the block at the `height` as imported without its transactions.
+    pub(crate) fn mark_header_only(&mut self, height: &BlockHeight) -> StorageResult<()> {
+        let _: Option<()> = Database::insert(
+            self,
+            height.database_key().as_ref(),
+            Column::HeaderOnlyBlocks,
+            &(),
+        )?;
+        Ok(())
+    }
+
+    /// Returns `true` if the block at the `height` was imported without its transactions.
+    pub fn is_header_only(&self, height: &BlockHeight) -> StorageResult<bool> {
+        Database::contains_key(
             self,
             height.database_key().as_ref(),
-            Column::FuelBlockSecondaryKeyBlockHeights,
+            Column::HeaderOnlyBlocks,
         )
         .map_err(Into::into)
     }
 
-    pub fn all_block_ids(
+    /// Returns `true` if any block was imported without its transactions.
+    pub fn has_header_only_blocks(&self) -> StorageResult<bool> {
+        Ok(self
+            .iter_all::<Vec<u8>, ()>(Column::HeaderOnlyBlocks, None)
+            .next()
+            .transpose()?
+            .is_some())
+    }
+
+    pub fn get_block_height(&self, id: &BlockId) -> StorageResult<Option<BlockHeight>> {
+        Ok(self
+            .storage::<FuelBlockIdsToHeights>()
+            .get(id)?
+            .map(Cow::into_owned))
+    }
+
+    /// Returns the blocks sorted by the height starting from the `start`.
+    pub fn all_blocks(
         &self,
         start: Option<BlockHeight>,
         direction: IterDirection,
-    ) -> impl Iterator<Item = DatabaseResult<(BlockHeight, BlockId)>> + '_ {
+    ) -> impl Iterator<Item = DatabaseResult<CompressedBlock>> + '_ {
         let start = start.map(|b| b.to_bytes());
-        self.iter_all_by_start::<Vec<u8>, BlockId, _>(
-            Column::FuelBlockSecondaryKeyBlockHeights,
+        self.iter_all_by_start::<Vec<u8>, CompressedBlock, _>(
+            Column::FuelBlocks,
             start,
             Some(direction),
         )
-        .map(|res| {
-            let (height, id) = res?;
-            Ok((
-                height
-                    .try_into()
-                    .expect("block height always has correct number of bytes"),
-                id,
-            ))
-        })
+        .map(|res| res.map(|(_, block)| block))
     }
 
     pub fn ids_of_genesis_block(&self) -> DatabaseResult<(BlockHeight, BlockId)> {
-        self.iter_all(
-            Column::FuelBlockSecondaryKeyBlockHeights,
+        self.iter_all::<Vec<u8>, CompressedBlock>(
+            Column::FuelBlocks,
             Some(IterDirection::Forward),
         )
         .next()
         .ok_or(DatabaseError::ChainUninitialized)?
-        .map(|(height, id): (Vec<u8>, BlockId)| {
-            let bytes = <[u8; 4]>::try_from(height.as_slice())
-                .expect("all block heights are stored with the correct amount of bytes");
-            (u32::from_be_bytes(bytes).into(), id)
-        })
+        .map(|(_, block)| (*block.header().height(), block.id()))
     }
 
     pub fn ids_of_latest_block(&self) -> DatabaseResult<Option<(BlockHeight, BlockId)>> {
         let ids = self
-            .iter_all::<Vec<u8>, BlockId>(
-                Column::FuelBlockSecondaryKeyBlockHeights,
-                Some(IterDirection::Reverse),
-            )
-            .next()
-            .transpose()?
-            .map(|(height, block)| {
-                // safety: we know that all block heights are stored with the correct amount of bytes
-                let bytes = <[u8; 4]>::try_from(height.as_slice()).unwrap();
-                (u32::from_be_bytes(bytes).into(), block)
-            });
+            .get_current_block()
+            .map_err(|e| DatabaseError::Other(e.into()))?
+            .map(|block| (*block.header().height(), block.id()));
 
         Ok(ids)
     }
 
-    /// Retrieve the full block and all associated transactions
+    /// Retrieve the full block and all associated transactions.
+    /// Fails if the transactions of the block are pruned.
     pub(crate) fn get_full_block(
         &self,
-        block_id: &BlockId,
+        height: &BlockHeight,
     ) -> StorageResult<Option<Block>> {
-        let db_block = self.storage::<FuelBlocks>().get(block_id)?;
+        let db_block = self.storage::<FuelBlocks>().get(height)?;
         if let Some(block) = db_block {
+            if self.is_pruned(height)? {
+                return Err(StorageError::Other(anyhow::anyhow!(
+                    "The transactions of the block {} are pruned",
+                    u32::from(*height)
+                )))
+            }
             // fetch all the transactions
             // TODO: optimize with multi-key get
             let txs = block
@@ -295,7 +331,7 @@ mod tests {
         for block in &blocks {
             StorageMutate::<FuelBlocks>::insert(
                 &mut database,
-                &block.id(),
+                block.header().height(),
                 &block.compress(),
             )
             .unwrap();
@@ -356,7 +392,7 @@ mod tests {
         for block in &blocks {
             StorageMutate::<FuelBlocks>::insert(
                 &mut database,
-                &block.id(),
+                block.header().height(),
                 &block.compress(),
             )
             .unwrap();
@@ -370,4 +406,35 @@ mod tests {
 
         assert!(matches!(err, fuel_core_storage::Error::NotFound(_, _)));
     }
+
+    #[test]
+    fn inserted_block_is_indexed_by_id() {
+        let mut database = Database::default();
+        let block = PartialFuelBlock::new(
+            PartialBlockHeader {
+                application: Default::default(),
+                consensus: ConsensusHeader::<Empty> {
+                    height: 10u32.into(),
+                    ..Default::default()
+                },
+            },
+            vec![],
+        )
+        .generate(&[]);
+        let height = *block.header().height();
+
+        StorageMutate::<FuelBlocks>::insert(&mut database, &height, &block.compress())
+            .unwrap();
+
+        assert_eq!(
+            database.get_block_height(&block.id()).unwrap(),
+            Some(height)
+        );
+        assert_eq!(database.get_block_id(&height).unwrap(), Some(block.id()));
+
+        StorageMutate::<FuelBlocks>::remove(&mut database, &height).unwrap();
+
+        assert_eq!(database.get_block_height(&block.id()).unwrap(), None);
+        assert_eq!(database.get_block_id(&height).unwrap(), None);
+    }
 }
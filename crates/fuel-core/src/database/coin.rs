@@ -83,10 +83,16 @@ impl StorageMutate<Coins> for Database {
     ) -> Result<Option<CompressedCoin>, Self::Error> {
         let coin_by_owner = owner_coin_id_key(&value.owner, key);
         // insert primary record
-        let insert = Database::insert(self, utxo_id_to_bytes(key), Column::Coins, value)?;
+        let insert: Option<CompressedCoin> =
+            Database::insert(self, utxo_id_to_bytes(key), Column::Coins, value)?;
         // insert secondary index by owner
         self.storage_as_mut::<OwnedCoins>()
             .insert(&coin_by_owner, &true)?;
+        // update the balances index, the replaced coin is not owned anymore
+        if let Some(previous) = &insert {
+            self.decrease_coin_balance(previous)?;
+        }
+        self.increase_coin_balance(value)?;
         Ok(insert)
     }
 
@@ -98,6 +104,7 @@ impl StorageMutate<Coins> for Database {
         if let Some(coin) = &coin {
             let key = owner_coin_id_key(&coin.owner, key);
             self.storage_as_mut::<OwnedCoins>().remove(&key)?;
+            self.decrease_coin_balance(coin)?;
         }
 
         Ok(coin)
@@ -140,7 +147,16 @@ impl Database {
 
     pub fn get_coin_config(&self) -> DatabaseResult<Option<Vec<CoinConfig>>> {
         let configs = self
-            .iter_all::<Vec<u8>, CompressedCoin>(Column::Coins, None)
+            .iter_coin_configs()
+            .collect::<DatabaseResult<Vec<CoinConfig>>>()?;
+
+        Ok(Some(configs))
+    }
+
+    pub fn iter_coin_configs(
+        &self,
+    ) -> impl Iterator<Item = DatabaseResult<CoinConfig>> + '_ {
+        self.iter_all::<Vec<u8>, CompressedCoin>(Column::Coins, None)
             .map(|raw_coin| -> DatabaseResult<CoinConfig> {
                 let coin = raw_coin?;
 
@@ -161,8 +177,5 @@ impl Database {
                     asset_id: coin.1.asset_id,
                 })
             })
-            .collect::<DatabaseResult<Vec<CoinConfig>>>()?;
-
-        Ok(Some(configs))
     }
 }
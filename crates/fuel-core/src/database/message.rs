@@ -51,7 +51,7 @@ impl StorageMutate<Messages> for Database {
         value: &Message,
     ) -> Result<Option<Message>, Self::Error> {
         // insert primary record
-        let result =
+        let result: Option<Message> =
             Database::insert(self, key.database_key().as_ref(), Column::Messages, value)?;
 
         // insert secondary record by owner
@@ -62,6 +62,12 @@ impl StorageMutate<Messages> for Database {
             &true,
         )?;
 
+        // update the balances index, the replaced message is not owned anymore
+        if let Some(previous) = &result {
+            self.decrease_message_balance(previous)?;
+        }
+        self.increase_message_balance(value)?;
+
         Ok(result)
     }
 
@@ -75,6 +81,7 @@ impl StorageMutate<Messages> for Database {
                 &owner_msg_id_key(&message.recipient, key),
                 Column::OwnedMessageIds,
             )?;
+            self.decrease_message_balance(message)?;
         }
 
         Ok(result)
@@ -120,7 +127,16 @@ impl Database {
 
     pub fn get_message_config(&self) -> StorageResult<Option<Vec<MessageConfig>>> {
         let configs = self
-            .all_messages(None, None)
+            .iter_message_configs()
+            .collect::<StorageResult<Vec<MessageConfig>>>()?;
+
+        Ok(Some(configs))
+    }
+
+    pub fn iter_message_configs(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<MessageConfig>> + '_ {
+        self.all_messages(None, None)
             .filter_map(|msg| {
                 // Return only unspent messages
                 if let Ok(msg) = msg {
@@ -145,9 +161,16 @@ impl Database {
                     da_height: msg.da_height,
                 })
             })
-            .collect::<StorageResult<Vec<MessageConfig>>>()?;
+    }
 
-        Ok(Some(configs))
+    /// Returns nonces of all spent messages.
+    pub fn iter_spent_messages(&self) -> impl Iterator<Item = StorageResult<Nonce>> + '_ {
+        self.iter_all::<Vec<u8>, ()>(Column::SpentMessages, None)
+            .map(|res| -> StorageResult<Nonce> {
+                let (key, _) = res?;
+                Nonce::try_from(key.as_slice())
+                    .map_err(|err| StorageError::Other(err.into()))
+            })
     }
 
     pub fn is_message_spent(&self, id: &Nonce) -> StorageResult<bool> {
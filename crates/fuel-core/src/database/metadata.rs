@@ -4,13 +4,19 @@ use crate::database::{
     Error as DatabaseError,
     Result as DatabaseResult,
 };
-use fuel_core_chain_config::ChainConfig;
+use fuel_core_chain_config::{
+    ChainConfig,
+    CheckpointConfig,
+};
 
 pub(crate) const DB_VERSION_KEY: &[u8] = b"version";
 pub(crate) const CHAIN_NAME_KEY: &[u8] = b"chain_name";
+pub(crate) const CHECKPOINT_KEY: &[u8] = b"checkpoint";
 
-/// Can be used to perform migrations in the future.
-pub(crate) const DB_VERSION: u32 = 0x00;
+/// The version of the database expected by this build of fuel-core.
+/// Databases of previous versions are upgraded by the
+/// [`MIGRATIONS`](crate::database::migration::MIGRATIONS).
+pub(crate) const DB_VERSION: u32 = 0x04;
 
 impl Database {
     /// Ensures the database is initialized and that the database version is correct
@@ -27,22 +33,24 @@ impl Database {
                 })?;
         }
 
-        // Ensure the database version is correct
-        if let Some(version) = self.get::<u32>(DB_VERSION_KEY, Column::Metadata)? {
-            if version != DB_VERSION {
-                return Err(DatabaseError::InvalidDatabaseVersion {
-                    found: version,
-                    expected: DB_VERSION,
-                })?
-            }
-        } else {
-            let _: Option<u32> =
-                self.insert(DB_VERSION_KEY, Column::Metadata, &DB_VERSION)?;
-        }
-        Ok(())
+        // Ensure the database version is correct, migrating the data if required
+        self.migrate()
     }
 
     pub fn get_chain_name(&self) -> DatabaseResult<Option<String>> {
         self.get(CHAIN_NAME_KEY, Column::Metadata)
     }
+
+    /// Returns the checkpoint if the state of the database was imported at it
+    /// instead of being created by the execution of the blocks since the genesis.
+    pub fn get_checkpoint(&self) -> DatabaseResult<Option<CheckpointConfig>> {
+        self.get(CHECKPOINT_KEY, Column::Metadata)
+    }
+
+    /// Records the checkpoint at which the state of the database was imported.
+    pub fn set_checkpoint(&self, checkpoint: &CheckpointConfig) -> DatabaseResult<()> {
+        let _: Option<CheckpointConfig> =
+            self.insert(CHECKPOINT_KEY, Column::Metadata, checkpoint)?;
+        Ok(())
+    }
 }
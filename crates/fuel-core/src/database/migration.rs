@@ -0,0 +1,894 @@
+//! The migration of the database between versions.
+//!
+//! Each [`Migration`] moves the database from its `version` to the `version + 1`.
+//! The migration is split into batches. Each batch is committed atomically
+//! together with a [`MigrationCheckpoint`] in the [`Column::Metadata`], so if the node
+//! crashes in the middle of the migration, it continues from the last committed batch
+//! on the next start.
+
+use crate::database::{
+    metadata::{
+        DB_VERSION,
+        DB_VERSION_KEY,
+    },
+    transaction::DatabaseTransaction,
+    transactions::balance_deltas,
+    Column,
+    Database,
+    Error as DatabaseError,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    transactional::Transaction as _,
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::{
+            Consensus,
+            Genesis,
+        },
+        primitives::{
+            BlockHeight,
+            BlockId,
+        },
+    },
+    entities::{
+        coins::coin::CompressedCoin,
+        message::Message,
+    },
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        Transaction,
+    },
+    fuel_types::{
+        Address,
+        Bytes32,
+    },
+    services::{
+        graphql_api::OwnedTransaction,
+        txpool::TransactionStatus,
+    },
+};
+use serde::de::DeserializeOwned;
+
+pub const MIGRATION_CHECKPOINT_KEY: &[u8] = b"migration_checkpoint";
+
+/// The ordered list of all known migrations. The migration with `version` `N`
+/// upgrades the database from the version `N` to the version `N + 1`.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 0,
+        name: "fuel blocks keyed by height",
+        migrate: migrate_fuel_blocks_to_heights,
+    },
+    Migration {
+        version: 1,
+        name: "genesis with the previous chain block id",
+        migrate: migrate_genesis_consensus,
+    },
+    Migration {
+        version: 2,
+        name: "index of the owners' balances",
+        migrate: migrate_owned_balances,
+    },
+    Migration {
+        version: 3,
+        name: "balance changes in the owners' transactions index",
+        migrate: migrate_owned_transactions,
+    },
+];
+
+/// The number of blocks re-keyed in one batch of [`migrate_fuel_blocks_to_heights`].
+const FUEL_BLOCKS_BATCH_SIZE: u32 = 10_000;
+
+/// The number of coins or messages indexed in one batch of [`migrate_owned_balances`].
+const OWNED_BALANCES_BATCH_SIZE: usize = 10_000;
+
+/// The number of entries re-encoded in one batch of [`migrate_owned_transactions`].
+const OWNED_TRANSACTIONS_BATCH_SIZE: usize = 10_000;
+
+/// The function that migrates one batch of the data.
+///
+/// It receives the database transaction to write changes into and the cursor returned by
+/// the previous batch(`None` for the first batch). It returns the result of the batch.
+pub type MigrationFn =
+    fn(&mut DatabaseTransaction, Option<Vec<u8>>) -> DatabaseResult<MigrationBatch>;
+
+/// The migration of the database from the `version` to the `version + 1`.
+#[derive(Clone, Copy)]
+pub struct Migration {
+    /// The version of the database to which the migration is applicable.
+    pub version: u32,
+    /// The human-readable description of the migration used for logging.
+    pub name: &'static str,
+    /// The function that migrates one batch of the data.
+    pub migrate: MigrationFn,
+}
+
+/// The result of the migrated batch.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MigrationBatch {
+    /// The number of entries migrated in the batch.
+    pub migrated: usize,
+    /// The cursor to start the next batch. `None` means that the migration is finished.
+    pub next: Option<Vec<u8>>,
+}
+
+/// The progress of the unfinished migration stored in the [`Column::Metadata`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MigrationCheckpoint {
+    /// The version of the database that is migrated.
+    pub version: u32,
+    /// The cursor of the next batch.
+    pub cursor: Vec<u8>,
+    /// The number of entries migrated so far.
+    pub migrated: u64,
+}
+
+/// Re-keys the `FuelBlocks` from the block id to the block height and replaces
+/// the index of the block height to the block id with the index of the block id
+/// to the block height.
+///
+/// The cursor contains the next height to migrate and the last height to migrate,
+/// both encoded as big-endian `u32`.
+fn migrate_fuel_blocks_to_heights(
+    tx: &mut DatabaseTransaction,
+    cursor: Option<Vec<u8>>,
+) -> DatabaseResult<MigrationBatch> {
+    let (next, last) = match cursor {
+        Some(cursor) => decode_heights_cursor(&cursor)?,
+        None => {
+            // Before the first batch all keys of the index are heights.
+            let first = first_height_key(tx, IterDirection::Forward)?;
+            let last = first_height_key(tx, IterDirection::Reverse)?;
+            match (first, last) {
+                (Some(first), Some(last)) => (first, last),
+                _ => return Ok(MigrationBatch::default()),
+            }
+        }
+    };
+
+    let end = last.min(next.saturating_add(FUEL_BLOCKS_BATCH_SIZE - 1));
+    let mut migrated = 0;
+    for height in next..=end {
+        let height_key = height.to_be_bytes();
+        let block_id: BlockId =
+            match tx.remove(&height_key, Column::FuelBlockIdsToHeights)? {
+                Some(block_id) => block_id,
+                None => continue,
+            };
+        let block = tx
+            .data
+            .delete(block_id.as_slice(), Column::FuelBlocks)?
+            .ok_or_else(|| {
+                DatabaseError::Other(anyhow::anyhow!(
+                    "The block {block_id} at height {height} is missing"
+                ))
+            })?;
+        tx.data.put(&height_key, Column::FuelBlocks, block)?;
+        let _: Option<BlockHeight> = tx.insert(
+            block_id.as_slice(),
+            Column::FuelBlockIdsToHeights,
+            &BlockHeight::from(height),
+        )?;
+        migrated += 1;
+    }
+
+    let next = if end < last {
+        let mut cursor = (end + 1).to_be_bytes().to_vec();
+        cursor.extend_from_slice(&last.to_be_bytes());
+        Some(cursor)
+    } else {
+        None
+    };
+    Ok(MigrationBatch { migrated, next })
+}
+
+/// The `Genesis` consensus stored by the version 1 of the database.
+#[derive(serde::Deserialize)]
+#[cfg_attr(test, derive(serde::Serialize))]
+struct GenesisV1 {
+    chain_config_hash: Bytes32,
+    coins_root: Bytes32,
+    contracts_root: Bytes32,
+    messages_root: Bytes32,
+}
+
+/// Only the genesis block is migrated, so the other variants are not needed.
+#[derive(serde::Deserialize)]
+#[cfg_attr(test, derive(serde::Serialize))]
+enum ConsensusV1 {
+    Genesis(GenesisV1),
+}
+
+/// Re-encodes the consensus of the genesis block with the new
+/// `Genesis::previous_chain_block_id` field. The migration is done in one batch.
+fn migrate_genesis_consensus(
+    tx: &mut DatabaseTransaction,
+    _: Option<Vec<u8>>,
+) -> DatabaseResult<MigrationBatch> {
+    let genesis_block_id = match tx.ids_of_genesis_block() {
+        Ok((_, block_id)) => block_id,
+        Err(DatabaseError::ChainUninitialized) => return Ok(MigrationBatch::default()),
+        Err(err) => return Err(err),
+    };
+    let genesis: Option<ConsensusV1> =
+        tx.get(genesis_block_id.as_slice(), Column::FuelBlockConsensus)?;
+    let migrated = match genesis {
+        Some(ConsensusV1::Genesis(genesis)) => {
+            let consensus = Consensus::Genesis(Genesis {
+                chain_config_hash: genesis.chain_config_hash,
+                coins_root: genesis.coins_root,
+                contracts_root: genesis.contracts_root,
+                messages_root: genesis.messages_root,
+                previous_chain_block_id: None,
+            });
+            let _: Option<ConsensusV1> = tx.insert(
+                genesis_block_id.as_slice(),
+                Column::FuelBlockConsensus,
+                &consensus,
+            )?;
+            1
+        }
+        None => 0,
+    };
+    Ok(MigrationBatch {
+        migrated,
+        next: None,
+    })
+}
+
+/// Builds the index of the owners' balances from the existing coins and messages.
+///
+/// The first byte of the cursor is the column to index next: [`Column::Coins`] or
+/// [`Column::Messages`]. The rest is the key of the next entry of this column.
+fn migrate_owned_balances(
+    tx: &mut DatabaseTransaction,
+    cursor: Option<Vec<u8>>,
+) -> DatabaseResult<MigrationBatch> {
+    let (column, start) = match cursor.as_deref() {
+        None => (Column::Coins, None),
+        Some([column, start @ ..]) if *column == Column::Coins as u8 => {
+            (Column::Coins, Some(start.to_vec()))
+        }
+        Some([column, start @ ..]) if *column == Column::Messages as u8 => {
+            (Column::Messages, Some(start.to_vec()))
+        }
+        Some(cursor) => {
+            return Err(DatabaseError::Other(anyhow::anyhow!(
+                "Invalid migration cursor {cursor:?}"
+            )))
+        }
+    };
+    // The empty key means the start of the column.
+    let start = start.filter(|start| !start.is_empty());
+
+    let (migrated, next) = if column == Column::Coins {
+        let (entries, next) = owned_balances_batch::<CompressedCoin>(tx, column, start)?;
+        for coin in entries.iter() {
+            tx.increase_coin_balance(coin)?;
+        }
+        let next = match next {
+            Some(key) => (Column::Coins, key),
+            // Messages are indexed after all coins.
+            None => (Column::Messages, Vec::new()),
+        };
+        (entries.len(), Some(next))
+    } else {
+        let (entries, next) = owned_balances_batch::<Message>(tx, column, start)?;
+        for message in entries.iter() {
+            tx.increase_message_balance(message)?;
+        }
+        (entries.len(), next.map(|key| (Column::Messages, key)))
+    };
+
+    let next = next.map(|(column, key)| {
+        let mut cursor = vec![column as u8];
+        cursor.extend_from_slice(&key);
+        cursor
+    });
+    Ok(MigrationBatch { migrated, next })
+}
+
+/// Returns the batch of values of the `column` starting from the `start` key and
+/// the key of the first entry of the next batch.
+fn owned_balances_batch<V: DeserializeOwned>(
+    tx: &DatabaseTransaction,
+    column: Column,
+    start: Option<Vec<u8>>,
+) -> DatabaseResult<(Vec<V>, Option<Vec<u8>>)> {
+    let mut entries = tx
+        .iter_all_by_start::<Vec<u8>, V, _>(column, start, Some(IterDirection::Forward))
+        .take(OWNED_BALANCES_BATCH_SIZE + 1)
+        .collect::<DatabaseResult<Vec<_>>>()?;
+    let next = if entries.len() > OWNED_BALANCES_BATCH_SIZE {
+        entries.pop().map(|(key, _)| key)
+    } else {
+        None
+    };
+    Ok((entries.into_iter().map(|(_, value)| value).collect(), next))
+}
+
+/// Replaces the transaction ids in the index of the owners' transactions with the
+/// [`OwnedTransaction`] entries. The changes of the balances are calculated from the
+/// stored transactions, so the entries of the transactions missing in the database
+/// don't have them.
+///
+/// The cursor is the key of the next entry of the index.
+fn migrate_owned_transactions(
+    tx: &mut DatabaseTransaction,
+    cursor: Option<Vec<u8>>,
+) -> DatabaseResult<MigrationBatch> {
+    let mut entries = tx
+        .iter_all_by_start::<Vec<u8>, Bytes32, _>(
+            Column::TransactionsByOwnerBlockIdx,
+            cursor,
+            Some(IterDirection::Forward),
+        )
+        .take(OWNED_TRANSACTIONS_BATCH_SIZE + 1)
+        .collect::<DatabaseResult<Vec<_>>>()?;
+    let next = if entries.len() > OWNED_TRANSACTIONS_BATCH_SIZE {
+        entries.pop().map(|(key, _)| key)
+    } else {
+        None
+    };
+
+    let migrated = entries.len();
+    for (key, tx_id) in entries {
+        let owner = Address::try_from(&key[..Address::LEN]).map_err(|_| {
+            DatabaseError::Other(anyhow::anyhow!("Invalid owners index key {key:?}"))
+        })?;
+        let transaction: Option<Transaction> =
+            tx.get(tx_id.as_ref(), Column::Transactions)?;
+        let deltas = match &transaction {
+            Some(Transaction::Script(script)) => {
+                balance_deltas(&owner, script.inputs(), script.outputs())
+            }
+            Some(Transaction::Create(create)) => {
+                balance_deltas(&owner, create.inputs(), create.outputs())
+            }
+            Some(Transaction::Mint(mint)) => balance_deltas(&owner, &[], mint.outputs()),
+            None => vec![],
+        };
+        let reverted = matches!(
+            tx.get_tx_status(&tx_id)?,
+            Some(TransactionStatus::Failed { .. })
+        );
+        let owned = OwnedTransaction {
+            tx_id,
+            reverted,
+            deltas,
+        };
+        let _: Option<Bytes32> =
+            tx.insert(&key, Column::TransactionsByOwnerBlockIdx, &owned)?;
+    }
+    Ok(MigrationBatch { migrated, next })
+}
+
+fn first_height_key(
+    tx: &DatabaseTransaction,
+    direction: IterDirection,
+) -> DatabaseResult<Option<u32>> {
+    tx.data
+        .iter_all(Column::FuelBlockIdsToHeights, None, None, direction)
+        .next()
+        .transpose()?
+        .map(|(key, _)| decode_height(&key))
+        .transpose()
+}
+
+fn decode_heights_cursor(cursor: &[u8]) -> DatabaseResult<(u32, u32)> {
+    if cursor.len() != 8 {
+        return Err(DatabaseError::Other(anyhow::anyhow!(
+            "Invalid migration cursor of length {}",
+            cursor.len()
+        )))
+    }
+    let (next, last) = cursor.split_at(4);
+    Ok((decode_height(next)?, decode_height(last)?))
+}
+
+fn decode_height(bytes: &[u8]) -> DatabaseResult<u32> {
+    let bytes: [u8; 4] = bytes.try_into().map_err(|_| {
+        DatabaseError::Other(anyhow::anyhow!("Invalid block height key {bytes:?}"))
+    })?;
+    Ok(u32::from_be_bytes(bytes))
+}
+
+impl Database {
+    /// Returns the version of the database, if it is set.
+    pub fn get_version(&self) -> DatabaseResult<Option<u32>> {
+        self.get(DB_VERSION_KEY, Column::Metadata)
+    }
+
+    /// Migrates the database to the [`DB_VERSION`] using the registered [`MIGRATIONS`].
+    pub fn migrate(&self) -> DatabaseResult<()> {
+        self.migrate_with(MIGRATIONS, DB_VERSION)
+    }
+
+    pub(crate) fn migrate_with(
+        &self,
+        migrations: &[Migration],
+        target: u32,
+    ) -> DatabaseResult<()> {
+        let mut version = match self.get_version()? {
+            Some(version) => version,
+            None => {
+                // The new database doesn't require migration.
+                let _: Option<u32> =
+                    self.insert(DB_VERSION_KEY, Column::Metadata, &target)?;
+                return Ok(())
+            }
+        };
+
+        if version > target {
+            return Err(DatabaseError::InvalidDatabaseVersion {
+                found: version,
+                expected: target,
+            })
+        }
+
+        while version < target {
+            let migration = migrations
+                .iter()
+                .find(|migration| migration.version == version)
+                .ok_or(DatabaseError::InvalidDatabaseVersion {
+                    found: version,
+                    expected: target,
+                })?;
+            self.apply_migration(migration)?;
+            version += 1;
+        }
+        Ok(())
+    }
+
+    fn apply_migration(&self, migration: &Migration) -> DatabaseResult<()> {
+        let checkpoint = self
+            .get::<MigrationCheckpoint>(MIGRATION_CHECKPOINT_KEY, Column::Metadata)?
+            .filter(|checkpoint| checkpoint.version == migration.version);
+
+        let (mut cursor, mut migrated) = match checkpoint {
+            Some(checkpoint) => {
+                tracing::info!(
+                    "Resuming the migration `{}` of the database from version {} after {} entries",
+                    migration.name,
+                    migration.version,
+                    checkpoint.migrated,
+                );
+                (Some(checkpoint.cursor), checkpoint.migrated)
+            }
+            None => {
+                tracing::info!(
+                    "Starting the migration `{}` of the database from version {}",
+                    migration.name,
+                    migration.version,
+                );
+                (None, 0)
+            }
+        };
+
+        loop {
+            let mut tx = self.transaction();
+            let batch = (migration.migrate)(&mut tx, cursor)?;
+            migrated += batch.migrated as u64;
+
+            match &batch.next {
+                Some(next) => {
+                    let checkpoint = MigrationCheckpoint {
+                        version: migration.version,
+                        cursor: next.clone(),
+                        migrated,
+                    };
+                    let _: Option<MigrationCheckpoint> = tx.insert(
+                        MIGRATION_CHECKPOINT_KEY,
+                        Column::Metadata,
+                        &checkpoint,
+                    )?;
+                }
+                None => {
+                    let _: Option<MigrationCheckpoint> =
+                        tx.remove(MIGRATION_CHECKPOINT_KEY, Column::Metadata)?;
+                    let _: Option<u32> = tx.insert(
+                        DB_VERSION_KEY,
+                        Column::Metadata,
+                        &(migration.version + 1),
+                    )?;
+                }
+            }
+            tx.commit().map_err(|e| DatabaseError::Other(e.into()))?;
+
+            match batch.next {
+                Some(next) => {
+                    tracing::info!(
+                        "Migration `{}`: migrated {} entries",
+                        migration.name,
+                        migrated
+                    );
+                    cursor = Some(next);
+                }
+                None => {
+                    tracing::info!(
+                        "Migration `{}` finished: migrated {} entries, the database version is {}",
+                        migration.name,
+                        migrated,
+                        migration.version + 1,
+                    );
+                    return Ok(())
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::storage::ToDatabaseKey;
+    use fuel_core_storage::{
+        tables::{
+            FuelBlocks,
+            Transactions,
+        },
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        fuel_tx::{
+            Output,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::AssetId,
+        services::graphql_api::BalanceDelta,
+        tai64::Tai64,
+    };
+
+    const TEST_BATCH: usize = 2;
+
+    /// Doubles every value of the `Column::Coins` column.
+    fn double_values(
+        tx: &mut DatabaseTransaction,
+        cursor: Option<Vec<u8>>,
+    ) -> DatabaseResult<MigrationBatch> {
+        let mut entries = tx
+            .data
+            .iter_all(Column::Coins, None, cursor.as_deref(), Default::default())
+            .take(TEST_BATCH + 1)
+            .collect::<DatabaseResult<Vec<_>>>()?;
+        let next = if entries.len() > TEST_BATCH {
+            entries.pop().map(|(key, _)| key)
+        } else {
+            None
+        };
+        let migrated = entries.len();
+        for (key, value) in entries {
+            let value = value.into_iter().map(|v| v * 2).collect();
+            tx.data.put(&key, Column::Coins, value)?;
+        }
+        Ok(MigrationBatch { migrated, next })
+    }
+
+    fn failing(
+        _: &mut DatabaseTransaction,
+        _: Option<Vec<u8>>,
+    ) -> DatabaseResult<MigrationBatch> {
+        Err(DatabaseError::Other(anyhow::anyhow!("Migration failed")))
+    }
+
+    fn database_with_version(version: u32) -> Database {
+        let db = Database::default();
+        let _: Option<u32> = db
+            .insert(DB_VERSION_KEY, Column::Metadata, &version)
+            .unwrap();
+        for i in 0u8..5 {
+            db.data.put(&[i], Column::Coins, vec![i]).unwrap();
+        }
+        db
+    }
+
+    fn coins(db: &Database) -> Vec<Vec<u8>> {
+        db.data
+            .iter_all(Column::Coins, None, None, Default::default())
+            .map(|entry| entry.unwrap().1)
+            .collect()
+    }
+
+    #[test]
+    fn new_database_is_initialized_with_target_version() {
+        let db = Database::default();
+
+        db.migrate_with(&[], 5).unwrap();
+
+        assert_eq!(db.get_version().unwrap(), Some(5));
+    }
+
+    #[test]
+    fn database_with_newer_version_is_rejected() {
+        let db = database_with_version(2);
+
+        let result = db.migrate_with(&[], 1);
+
+        assert!(matches!(
+            result,
+            Err(DatabaseError::InvalidDatabaseVersion {
+                found: 2,
+                expected: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn missing_migration_is_rejected() {
+        let db = database_with_version(0);
+
+        let result = db.migrate_with(&[], 1);
+
+        assert!(matches!(
+            result,
+            Err(DatabaseError::InvalidDatabaseVersion {
+                found: 0,
+                expected: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn migrations_are_applied_in_order() {
+        let db = database_with_version(0);
+        let migrations = [
+            Migration {
+                version: 1,
+                name: "second",
+                migrate: double_values,
+            },
+            Migration {
+                version: 0,
+                name: "first",
+                migrate: double_values,
+            },
+        ];
+
+        db.migrate_with(&migrations, 2).unwrap();
+
+        assert_eq!(db.get_version().unwrap(), Some(2));
+        assert_eq!(
+            coins(&db),
+            vec![vec![0], vec![4], vec![8], vec![12], vec![16]]
+        );
+        assert_eq!(
+            db.get::<MigrationCheckpoint>(MIGRATION_CHECKPOINT_KEY, Column::Metadata)
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn migration_resumes_from_checkpoint() {
+        let db = database_with_version(0);
+        let checkpoint = MigrationCheckpoint {
+            version: 0,
+            cursor: vec![2],
+            migrated: 2,
+        };
+        let _: Option<MigrationCheckpoint> = db
+            .insert(MIGRATION_CHECKPOINT_KEY, Column::Metadata, &checkpoint)
+            .unwrap();
+        let migrations = [Migration {
+            version: 0,
+            name: "double",
+            migrate: double_values,
+        }];
+
+        db.migrate_with(&migrations, 1).unwrap();
+
+        assert_eq!(db.get_version().unwrap(), Some(1));
+        // Entries before the checkpoint are not migrated again.
+        assert_eq!(
+            coins(&db),
+            vec![vec![0], vec![1], vec![4], vec![6], vec![8]]
+        );
+    }
+
+    #[test]
+    fn failed_migration_keeps_version_and_checkpoint() {
+        let db = database_with_version(0);
+        let migrations = [
+            Migration {
+                version: 0,
+                name: "double",
+                migrate: double_values,
+            },
+            Migration {
+                version: 1,
+                name: "failing",
+                migrate: failing,
+            },
+        ];
+
+        db.migrate_with(&migrations, 2)
+            .expect_err("Migration should fail");
+
+        assert_eq!(db.get_version().unwrap(), Some(1));
+        assert_eq!(
+            coins(&db),
+            vec![vec![0], vec![2], vec![4], vec![6], vec![8]]
+        );
+    }
+
+    #[test]
+    fn fuel_blocks_are_migrated_to_heights() {
+        let db = Database::default();
+        let _: Option<u32> = db.insert(DB_VERSION_KEY, Column::Metadata, &0u32).unwrap();
+        // Blocks stored in the format of the version 0 with a gap at the height 2.
+        let blocks = [0u32, 1, 3]
+            .into_iter()
+            .map(|height| {
+                let mut block = CompressedBlock::default();
+                block.header_mut().consensus.height = height.into();
+                block.header_mut().recalculate_metadata();
+                block
+            })
+            .collect::<Vec<_>>();
+        for block in &blocks {
+            let height = block.header().height();
+            let _: Option<CompressedBlock> = db
+                .insert(block.id().as_slice(), Column::FuelBlocks, block)
+                .unwrap();
+            let _: Option<BlockId> = db
+                .insert(
+                    height.database_key().as_ref(),
+                    Column::FuelBlockIdsToHeights,
+                    &block.id(),
+                )
+                .unwrap();
+        }
+
+        db.migrate_with(MIGRATIONS, 1).unwrap();
+
+        assert_eq!(db.get_version().unwrap(), Some(1));
+        for block in &blocks {
+            let height = block.header().height();
+            let migrated = db.storage::<FuelBlocks>().get(height).unwrap().unwrap();
+            assert_eq!(migrated.id(), block.id());
+            assert_eq!(db.get_block_height(&block.id()).unwrap(), Some(*height));
+        }
+        assert_eq!(db.storage::<FuelBlocks>().get(&2u32.into()).unwrap(), None);
+    }
+
+    #[test]
+    fn genesis_consensus_is_migrated() {
+        let mut db = Database::default();
+        let _: Option<u32> = db.insert(DB_VERSION_KEY, Column::Metadata, &1u32).unwrap();
+        let block = CompressedBlock::default();
+        let _ = db
+            .storage_as_mut::<FuelBlocks>()
+            .insert(block.header().height(), &block)
+            .unwrap();
+        let genesis = GenesisV1 {
+            chain_config_hash: Bytes32::from([1; 32]),
+            coins_root: Bytes32::from([2; 32]),
+            contracts_root: Bytes32::from([3; 32]),
+            messages_root: Bytes32::from([4; 32]),
+        };
+        let _: Option<ConsensusV1> = db
+            .insert(
+                block.id().as_slice(),
+                Column::FuelBlockConsensus,
+                &ConsensusV1::Genesis(genesis),
+            )
+            .unwrap();
+
+        db.migrate_with(MIGRATIONS, 2).unwrap();
+
+        assert_eq!(db.get_version().unwrap(), Some(2));
+        assert_eq!(
+            db.get_genesis().unwrap(),
+            Genesis {
+                chain_config_hash: Bytes32::from([1; 32]),
+                coins_root: Bytes32::from([2; 32]),
+                contracts_root: Bytes32::from([3; 32]),
+                messages_root: Bytes32::from([4; 32]),
+                previous_chain_block_id: None,
+            }
+        );
+    }
+
+    #[test]
+    fn owned_balances_are_indexed() {
+        let db = Database::default();
+        let _: Option<u32> = db.insert(DB_VERSION_KEY, Column::Metadata, &2u32).unwrap();
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        // Coins and messages stored by the version 2 without the balances index.
+        for i in 0u8..3 {
+            let coin = CompressedCoin {
+                owner,
+                amount: 10,
+                asset_id,
+                maturity: Default::default(),
+                tx_pointer: Default::default(),
+            };
+            let _: Option<CompressedCoin> =
+                db.insert([i; 33], Column::Coins, &coin).unwrap();
+        }
+        for (i, data) in [vec![], vec![1]].into_iter().enumerate() {
+            let message = Message {
+                recipient: owner,
+                amount: 5,
+                data,
+                ..Default::default()
+            };
+            let _: Option<Message> = db
+                .insert([i as u8; 32], Column::Messages, &message)
+                .unwrap();
+        }
+
+        db.migrate_with(MIGRATIONS, 3).unwrap();
+
+        assert_eq!(db.get_version().unwrap(), Some(3));
+        assert_eq!(
+            db.owned_balances(&owner, None)
+                .collect::<DatabaseResult<Vec<_>>>()
+                .unwrap(),
+            vec![(AssetId::BASE, 5), (asset_id, 30)]
+        );
+    }
+
+    #[test]
+    fn owned_transactions_are_migrated() {
+        let mut db = Database::default();
+        let _: Option<u32> = db.insert(DB_VERSION_KEY, Column::Metadata, &3u32).unwrap();
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let transaction: Transaction = TransactionBuilder::script(vec![], vec![])
+            .add_output(Output::coin(owner, 10, asset_id))
+            .finalize_as_transaction();
+        let tx_id = transaction.id();
+        let _ = db
+            .storage_as_mut::<Transactions>()
+            .insert(&tx_id, &transaction)
+            .unwrap();
+        db.update_tx_status(
+            &tx_id,
+            TransactionStatus::Failed {
+                block_id: Default::default(),
+                time: Tai64::UNIX_EPOCH,
+                result: None,
+                reason: "Reverted".to_string(),
+            },
+        )
+        .unwrap();
+        // The entries of the version 3 contain only the transaction id.
+        let mut key = owner.to_vec();
+        key.extend_from_slice(&[0, 0, 0, 1, 0, 0]);
+        let _: Option<Bytes32> = db
+            .insert(&key, Column::TransactionsByOwnerBlockIdx, &tx_id)
+            .unwrap();
+
+        db.migrate_with(MIGRATIONS, 4).unwrap();
+
+        assert_eq!(db.get_version().unwrap(), Some(4));
+        let (_, owned) = db
+            .owned_transactions(owner, None, None)
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            owned,
+            OwnedTransaction {
+                tx_id,
+                reverted: true,
+                deltas: vec![BalanceDelta {
+                    asset_id,
+                    received: 10,
+                    spent: 0,
+                }],
+            }
+        );
+    }
+}
@@ -0,0 +1,305 @@
+//! The index of the owners' balances.
+//!
+//! The index is updated together with the `Coins` and `Messages` tables, so the balance
+//! of the owner is a single read instead of the fold over all its coins and messages.
+//! Only messages without data are spendable as coins, so only they are counted in the
+//! balance of the base asset.
+
+use crate::database::{
+    Column,
+    Database,
+    Error as DatabaseError,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::{
+    entities::{
+        coins::coin::CompressedCoin,
+        message::Message,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+    },
+};
+
+// TODO: Reuse `fuel_vm::storage::double_key` macro.
+pub fn owned_balance_key(owner: &Address, asset_id: &AssetId) -> OwnedBalanceKey {
+    let mut default = [0u8; Address::LEN + AssetId::LEN];
+    default[0..Address::LEN].copy_from_slice(owner.as_ref());
+    default[Address::LEN..].copy_from_slice(asset_id.as_ref());
+    default
+}
+
+/// The storage key for owned balances: `Address ++ AssetId`.
+///
+/// The value is the total amount of the owner's coins of the asset. It is `u128`
+/// because the sum of the coins of one owner may overflow `u64`. The entry is
+/// removed when the balance drops to zero.
+pub type OwnedBalanceKey = [u8; Address::LEN + AssetId::LEN];
+
+impl Database {
+    /// Returns the balance of the `owner` for the `asset_id`.
+    pub fn owned_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> DatabaseResult<u128> {
+        let balance = self
+            .get(&owned_balance_key(owner, asset_id), Column::OwnedBalances)?
+            .unwrap_or_default();
+        Ok(balance)
+    }
+
+    /// Returns all non-zero balances of the `owner` ordered by the asset id.
+    pub fn owned_balances(
+        &self,
+        owner: &Address,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(AssetId, u128)>> + '_ {
+        // The reverse iteration by the prefix starts from its last possible key.
+        let start = match direction {
+            Some(IterDirection::Reverse) => {
+                Some(owned_balance_key(owner, &AssetId::from([u8::MAX; 32])))
+            }
+            _ => None,
+        };
+        self.iter_all_filtered::<Vec<u8>, u128, _, _>(
+            Column::OwnedBalances,
+            Some(*owner),
+            start,
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, balance)| {
+                let asset_id = AssetId::try_from(&key[Address::LEN..])
+                    .expect("The key is always `Address ++ AssetId`");
+                (asset_id, balance)
+            })
+        })
+    }
+
+    pub(crate) fn increase_coin_balance(
+        &self,
+        coin: &CompressedCoin,
+    ) -> DatabaseResult<()> {
+        self.increase_balance(&coin.owner, &coin.asset_id, coin.amount)
+    }
+
+    pub(crate) fn decrease_coin_balance(
+        &self,
+        coin: &CompressedCoin,
+    ) -> DatabaseResult<()> {
+        self.decrease_balance(&coin.owner, &coin.asset_id, coin.amount)
+    }
+
+    pub(crate) fn increase_message_balance(
+        &self,
+        message: &Message,
+    ) -> DatabaseResult<()> {
+        if message.data.is_empty() {
+            self.increase_balance(&message.recipient, &AssetId::BASE, message.amount)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn decrease_message_balance(
+        &self,
+        message: &Message,
+    ) -> DatabaseResult<()> {
+        if message.data.is_empty() {
+            self.decrease_balance(&message.recipient, &AssetId::BASE, message.amount)?;
+        }
+        Ok(())
+    }
+
+    fn increase_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: u64,
+    ) -> DatabaseResult<()> {
+        if amount == 0 {
+            return Ok(())
+        }
+        let balance = self.owned_balance(owner, asset_id)?;
+        let balance = balance.checked_add(amount as u128).ok_or_else(|| {
+            DatabaseError::Other(anyhow::anyhow!(
+                "The balance of {owner} for {asset_id} overflows"
+            ))
+        })?;
+        let _: Option<u128> = self.insert(
+            owned_balance_key(owner, asset_id),
+            Column::OwnedBalances,
+            &balance,
+        )?;
+        Ok(())
+    }
+
+    fn decrease_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: u64,
+    ) -> DatabaseResult<()> {
+        if amount == 0 {
+            return Ok(())
+        }
+        let key = owned_balance_key(owner, asset_id);
+        let balance = self.owned_balance(owner, asset_id)?;
+        let balance = balance.checked_sub(amount as u128).ok_or_else(|| {
+            DatabaseError::Other(anyhow::anyhow!(
+                "The balance of {owner} for {asset_id} is less than the removed {amount}"
+            ))
+        })?;
+        if balance == 0 {
+            let _: Option<u128> = self.remove(&key, Column::OwnedBalances)?;
+        } else {
+            let _: Option<u128> = self.insert(key, Column::OwnedBalances, &balance)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::{
+            Coins,
+            Messages,
+        },
+        StorageAsMut,
+    };
+    use fuel_core_types::fuel_tx::UtxoId;
+
+    fn coin(owner: Address, asset_id: AssetId, amount: u64) -> CompressedCoin {
+        CompressedCoin {
+            owner,
+            amount,
+            asset_id,
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        }
+    }
+
+    fn balances(db: &Database, owner: &Address) -> Vec<(AssetId, u128)> {
+        db.owned_balances(owner, None)
+            .collect::<DatabaseResult<Vec<_>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn balances_follow_inserted_and_removed_coins() {
+        let mut db = Database::default();
+        let owner = Address::from([1; 32]);
+        let other_owner = Address::from([2; 32]);
+        let first_asset = AssetId::from([3; 32]);
+        let second_asset = AssetId::from([4; 32]);
+        let coins = [
+            (UtxoId::new([1; 32].into(), 0), coin(owner, first_asset, 10)),
+            (UtxoId::new([1; 32].into(), 1), coin(owner, first_asset, 20)),
+            (
+                UtxoId::new([1; 32].into(), 2),
+                coin(owner, second_asset, 30),
+            ),
+            (
+                UtxoId::new([1; 32].into(), 3),
+                coin(other_owner, first_asset, 40),
+            ),
+        ];
+        for (utxo_id, coin) in coins.iter() {
+            db.storage::<Coins>().insert(utxo_id, coin).unwrap();
+        }
+
+        assert_eq!(db.owned_balance(&owner, &first_asset).unwrap(), 30);
+        assert_eq!(
+            balances(&db, &owner),
+            vec![(first_asset, 30), (second_asset, 30)]
+        );
+        assert_eq!(balances(&db, &other_owner), vec![(first_asset, 40)]);
+
+        db.storage::<Coins>().remove(&coins[0].0).unwrap();
+        db.storage::<Coins>().remove(&coins[2].0).unwrap();
+        // Removing of the missing coin doesn't change the balance.
+        db.storage::<Coins>().remove(&coins[2].0).unwrap();
+
+        assert_eq!(db.owned_balance(&owner, &first_asset).unwrap(), 20);
+        assert_eq!(db.owned_balance(&owner, &second_asset).unwrap(), 0);
+        assert_eq!(
+            db.owned_balances(&owner, Some(IterDirection::Reverse))
+                .collect::<DatabaseResult<Vec<_>>>()
+                .unwrap(),
+            vec![(first_asset, 20)]
+        );
+    }
+
+    #[test]
+    fn replaced_coin_updates_balances() {
+        let mut db = Database::default();
+        let owner = Address::from([1; 32]);
+        let new_owner = Address::from([2; 32]);
+        let asset_id = AssetId::from([3; 32]);
+        let utxo_id = UtxoId::default();
+
+        db.storage::<Coins>()
+            .insert(&utxo_id, &coin(owner, asset_id, 10))
+            .unwrap();
+        db.storage::<Coins>()
+            .insert(&utxo_id, &coin(new_owner, asset_id, 15))
+            .unwrap();
+
+        assert_eq!(balances(&db, &owner), vec![]);
+        assert_eq!(balances(&db, &new_owner), vec![(asset_id, 15)]);
+    }
+
+    #[test]
+    fn only_messages_without_data_are_counted() {
+        let mut db = Database::default();
+        let owner = Address::from([1; 32]);
+        let coin_message = Message {
+            recipient: owner,
+            amount: 10,
+            ..Default::default()
+        };
+        let data_message = Message {
+            recipient: owner,
+            amount: 20,
+            data: vec![1],
+            ..Default::default()
+        };
+        db.storage::<Messages>()
+            .insert(&1.into(), &coin_message)
+            .unwrap();
+        db.storage::<Messages>()
+            .insert(&2.into(), &data_message)
+            .unwrap();
+
+        assert_eq!(balances(&db, &owner), vec![(AssetId::BASE, 10)]);
+
+        db.storage::<Messages>().remove(&1.into()).unwrap();
+        db.storage::<Messages>().remove(&2.into()).unwrap();
+
+        assert_eq!(balances(&db, &owner), vec![]);
+    }
+
+    #[test]
+    fn balance_may_exceed_u64() {
+        let mut db = Database::default();
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([3; 32]);
+        for output_index in 0..3 {
+            db.storage::<Coins>()
+                .insert(
+                    &UtxoId::new(Default::default(), output_index),
+                    &coin(owner, asset_id, u64::MAX),
+                )
+                .unwrap();
+        }
+
+        assert_eq!(
+            db.owned_balance(&owner, &asset_id).unwrap(),
+            u64::MAX as u128 * 3
+        );
+    }
+}
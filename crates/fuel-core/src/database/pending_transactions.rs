@@ -0,0 +1,90 @@
+//! The transactions of the TxPool saved across the restarts of the node.
+//!
+//! The transactions are keyed by their index in the order of saving, so they
+//! are returned in the same order as they were saved.
+
+use crate::database::{
+    Column,
+    Database,
+    Error as DatabaseError,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::transactional::Transaction as _;
+use fuel_core_types::fuel_tx::Transaction;
+
+impl Database {
+    /// Saves the pending transactions of the TxPool, replacing the previously saved ones.
+    pub fn save_pending_transactions(&self, txs: Vec<Transaction>) -> DatabaseResult<()> {
+        let mut db_tx = self.transaction();
+        clear(&db_tx)?;
+        for (index, tx) in txs.iter().enumerate() {
+            let _: Option<Transaction> = db_tx.insert(
+                (index as u64).to_be_bytes(),
+                Column::PendingTransactions,
+                tx,
+            )?;
+        }
+        db_tx.commit().map_err(|e| DatabaseError::Other(e.into()))
+    }
+
+    /// Returns the saved pending transactions in the order of saving.
+    pub fn pending_transactions(&self) -> DatabaseResult<Vec<Transaction>> {
+        self.iter_all::<Vec<u8>, Transaction>(Column::PendingTransactions, None)
+            .map(|result| result.map(|(_, tx)| tx))
+            .collect()
+    }
+
+    /// Removes the saved pending transactions.
+    pub fn clear_pending_transactions(&self) -> DatabaseResult<()> {
+        let mut db_tx = self.transaction();
+        clear(&db_tx)?;
+        db_tx.commit().map_err(|e| DatabaseError::Other(e.into()))
+    }
+}
+
+fn clear(db: &Database) -> DatabaseResult<()> {
+    let keys = db
+        .iter_all::<Vec<u8>, Transaction>(Column::PendingTransactions, None)
+        .map(|result| result.map(|(key, _)| key))
+        .collect::<DatabaseResult<Vec<_>>>()?;
+    for key in keys.iter() {
+        db.data.delete(key, Column::PendingTransactions)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::TransactionBuilder;
+
+    fn script(gas_price: u64) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .finalize_as_transaction()
+    }
+
+    #[test]
+    fn pending_transactions_are_returned_in_the_order_of_saving() {
+        let db = Database::default();
+        let txs: Vec<_> = (0..300).rev().map(script).collect();
+
+        db.save_pending_transactions(txs.clone()).unwrap();
+
+        assert_eq!(db.pending_transactions().unwrap(), txs);
+        assert_eq!(db.pending_transactions().unwrap(), txs);
+        db.clear_pending_transactions().unwrap();
+        assert!(db.pending_transactions().unwrap().is_empty());
+    }
+
+    #[test]
+    fn saving_replaces_previous_pending_transactions() {
+        let db = Database::default();
+        db.save_pending_transactions(vec![script(1), script(2)])
+            .unwrap();
+
+        db.save_pending_transactions(vec![script(3)]).unwrap();
+
+        assert_eq!(db.pending_transactions().unwrap(), vec![script(3)]);
+    }
+}
@@ -0,0 +1,284 @@
+//! The pruning of the historical data of the blocks.
+//!
+//! The blocks and their headers are kept forever, while the transactions, receipts,
+//! statuses and the owners index of the old blocks can be removed. Only the height of
+//! the last pruned block is stored in the [`Column::Metadata`]; nothing is kept per
+//! removed transaction, so after the pruning a missing transaction can't be told
+//! apart from the pruned one.
+
+use crate::database::{
+    storage::ToDatabaseKey,
+    transaction::DatabaseTransaction,
+    transactions::{
+        owners_of_coins,
+        TransactionIndex,
+    },
+    Column,
+    Database,
+    Error as DatabaseError,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::transactional::Transaction as _;
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        primitives::BlockHeight,
+    },
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        Transaction,
+    },
+};
+
+pub(crate) const PRUNED_HEIGHT_KEY: &[u8] = b"pruned_height";
+
+impl Database {
+    /// Returns the height of the last block with pruned transactions, receipts and statuses.
+    /// `None` means that the database contains the full history.
+    pub fn pruned_height(&self) -> DatabaseResult<Option<BlockHeight>> {
+        self.get(PRUNED_HEIGHT_KEY, Column::Metadata)
+    }
+
+    /// Returns the height of the last pruned block if any transaction could be pruned.
+    /// The genesis block has no transactions, so its pruning alone is ignored.
+    pub fn pruned_transactions_height(&self) -> DatabaseResult<Option<BlockHeight>> {
+        let pruned = match self.pruned_height()? {
+            Some(pruned) => pruned,
+            None => return Ok(None),
+        };
+        let (genesis_height, _) = self.ids_of_genesis_block()?;
+        Ok(Some(pruned).filter(|pruned| *pruned > genesis_height))
+    }
+
+    /// Returns `true` if the history of the block at the `height` is pruned.
+    pub fn is_pruned(&self, height: &BlockHeight) -> DatabaseResult<bool> {
+        Ok(self
+            .pruned_height()?
+            .map_or(false, |pruned| *height <= pruned))
+    }
+
+    /// Prunes the history of all not yet pruned blocks up to the `height` inclusive.
+    /// Each block is pruned in a separate database transaction.
+    ///
+    /// Returns the number of pruned blocks.
+    pub fn prune_up_to(&self, height: &BlockHeight) -> DatabaseResult<u32> {
+        let start = match self.pruned_height()? {
+            Some(pruned) => u32::from(pruned) + 1,
+            None => u32::from(self.ids_of_genesis_block()?.0),
+        };
+        let end = u32::from(*height);
+
+        for next in start..=end {
+            let mut tx = self.transaction();
+            prune_block(&mut tx, next.into())?;
+            tx.commit().map_err(|e| DatabaseError::Other(e.into()))?;
+        }
+
+        Ok(end.saturating_add(1).saturating_sub(start))
+    }
+}
+
+fn prune_block(tx: &mut DatabaseTransaction, height: BlockHeight) -> DatabaseResult<()> {
+    let block: Option<CompressedBlock> =
+        tx.get(height.database_key().as_ref(), Column::FuelBlocks)?;
+
+    if let Some(block) = block {
+        for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+            let transaction: Option<Transaction> =
+                tx.remove(tx_id.as_ref(), Column::Transactions)?;
+            if let Some(transaction) = transaction {
+                let owners = match &transaction {
+                    Transaction::Script(script) => {
+                        owners_of_coins(script.inputs(), script.outputs())
+                    }
+                    Transaction::Create(create) => {
+                        owners_of_coins(create.inputs(), create.outputs())
+                    }
+                    Transaction::Mint(mint) => owners_of_coins(&[], mint.outputs()),
+                };
+                for owner in owners {
+                    tx.remove_tx_id_owner(&owner, height, tx_idx as TransactionIndex)?;
+                }
+            }
+            tx.data.delete(tx_id.as_ref(), Column::Receipts)?;
+            tx.data.delete(tx_id.as_ref(), Column::TransactionTraces)?;
+            tx.data.delete(tx_id.as_ref(), Column::TransactionStatus)?;
+        }
+    }
+
+    let _: Option<BlockHeight> =
+        tx.insert(PRUNED_HEIGHT_KEY, Column::Metadata, &height)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::{
+            FuelBlocks,
+            Receipts,
+            Transactions,
+        },
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_tx::{
+            Address,
+            Output,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        services::{
+            graphql_api::OwnedTransaction,
+            txpool::TransactionStatus,
+        },
+        tai64::Tai64,
+    };
+
+    fn insert_block(database: &mut Database, height: u32, owner: Address) -> Transaction {
+        let transaction: Transaction = TransactionBuilder::script(vec![], vec![])
+            .add_output(Output::coin(owner, height as u64, Default::default()))
+            .finalize_as_transaction();
+        let block = PartialFuelBlock::new(
+            PartialBlockHeader {
+                application: Default::default(),
+                consensus: ConsensusHeader::<Empty> {
+                    height: height.into(),
+                    ..Default::default()
+                },
+            },
+            vec![transaction.clone()],
+        )
+        .generate(&[]);
+        let tx_id = transaction.id();
+
+        database
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height.into(), &block.compress())
+            .unwrap();
+        database
+            .storage_as_mut::<Transactions>()
+            .insert(&tx_id, &transaction)
+            .unwrap();
+        database
+            .storage_as_mut::<Receipts>()
+            .insert(&tx_id, &[])
+            .unwrap();
+        database
+            .update_tx_status(
+                &tx_id,
+                TransactionStatus::Success {
+                    block_id: block.id(),
+                    time: Tai64::UNIX_EPOCH,
+                    result: None,
+                },
+            )
+            .unwrap();
+        database
+            .record_tx_id_owner(
+                &owner,
+                height.into(),
+                0,
+                &OwnedTransaction {
+                    tx_id,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        transaction
+    }
+
+    #[test]
+    fn prune_up_to_removes_only_history_of_old_blocks() {
+        let mut database = Database::default();
+        let owner = Address::from([1; 32]);
+        let transactions = (0..4)
+            .map(|height| insert_block(&mut database, height, owner))
+            .collect::<Vec<_>>();
+
+        let pruned = database.prune_up_to(&1u32.into()).unwrap();
+
+        assert_eq!(pruned, 2);
+        assert_eq!(database.pruned_height().unwrap(), Some(1u32.into()));
+        for (height, transaction) in transactions.iter().enumerate() {
+            let tx_id = transaction.id();
+            let is_kept = height > 1;
+            assert_eq!(
+                database
+                    .storage::<Transactions>()
+                    .contains_key(&tx_id)
+                    .unwrap(),
+                is_kept
+            );
+            assert_eq!(
+                database.storage::<Receipts>().contains_key(&tx_id).unwrap(),
+                is_kept
+            );
+            assert_eq!(database.get_tx_status(&tx_id).unwrap().is_some(), is_kept);
+            assert_eq!(
+                database.is_pruned(&(height as u32).into()).unwrap(),
+                !is_kept
+            );
+            // Blocks are never pruned.
+            assert!(database
+                .storage::<FuelBlocks>()
+                .contains_key(&(height as u32).into())
+                .unwrap());
+        }
+        let owned = database
+            .owned_transactions(owner, None, None)
+            .map(|result| result.unwrap().1.tx_id)
+            .collect::<Vec<_>>();
+        assert_eq!(owned, vec![transactions[2].id(), transactions[3].id()]);
+
+        // The pruned blocks can't be loaded with their transactions.
+        assert!(database.get_full_block(&1u32.into()).is_err());
+        assert!(database.get_full_block(&2u32.into()).unwrap().is_some());
+    }
+
+    #[test]
+    fn pruning_of_genesis_block_does_not_prune_transactions() {
+        let mut database = Database::default();
+        let owner = Address::from([1; 32]);
+        for height in 0..3 {
+            insert_block(&mut database, height, owner);
+        }
+        assert_eq!(database.pruned_transactions_height().unwrap(), None);
+
+        database.prune_up_to(&0u32.into()).unwrap();
+        assert_eq!(database.pruned_transactions_height().unwrap(), None);
+
+        database.prune_up_to(&1u32.into()).unwrap();
+        assert_eq!(
+            database.pruned_transactions_height().unwrap(),
+            Some(1u32.into())
+        );
+    }
+
+    #[test]
+    fn prune_up_to_continues_from_pruned_height() {
+        let mut database = Database::default();
+        let owner = Address::from([1; 32]);
+        for height in 0..4 {
+            insert_block(&mut database, height, owner);
+        }
+
+        assert_eq!(database.prune_up_to(&1u32.into()).unwrap(), 2);
+        assert_eq!(database.prune_up_to(&1u32.into()).unwrap(), 0);
+        assert_eq!(database.prune_up_to(&3u32.into()).unwrap(), 2);
+        assert_eq!(database.pruned_height().unwrap(), Some(3u32.into()));
+    }
+}
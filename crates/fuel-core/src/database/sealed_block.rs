@@ -40,12 +40,32 @@ impl Database {
         &self,
         block_id: &BlockId,
     ) -> StorageResult<Option<SealedBlock>> {
-        // combine the block and consensus metadata into a sealed fuel block type
+        let height = match self.get_block_height(block_id)? {
+            Some(height) => height,
+            None => return Ok(None),
+        };
+        self.get_sealed_block_by_height(&height)
+    }
 
-        let block = self.get_full_block(block_id)?;
-        let consensus = self.storage::<SealedBlockConsensus>().get(block_id)?;
+    /// Returns `SealedBlock` by `height`.
+    /// Reusable across different trait implementations
+    ///
+    /// Returns `None` for the blocks imported without their transactions.
+    pub fn get_sealed_block_by_height(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<SealedBlock>> {
+        if self.is_header_only(height)? {
+            return Ok(None)
+        }
+        // combine the block and consensus metadata into a sealed fuel block type
+        let block = match self.get_full_block(height)? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        let consensus = self.storage::<SealedBlockConsensus>().get(&block.id())?;
 
-        if let (Some(block), Some(consensus)) = (block, consensus) {
+        if let Some(consensus) = consensus {
             let sealed_block = SealedBlock {
                 entity: block,
                 consensus: consensus.into_owned(),
@@ -57,19 +77,6 @@ impl Database {
         }
     }
 
-    /// Returns `SealedBlock` by `height`.
-    /// Reusable across different trait implementations
-    pub fn get_sealed_block_by_height(
-        &self,
-        height: &BlockHeight,
-    ) -> StorageResult<Option<SealedBlock>> {
-        let block_id = match self.get_block_id(height)? {
-            Some(i) => i,
-            None => return Ok(None),
-        };
-        self.get_sealed_block_by_id(&block_id)
-    }
-
     pub fn get_genesis(&self) -> StorageResult<Genesis> {
         let (_, genesis_block_id) = self.ids_of_genesis_block()?;
         let consensus = self
@@ -88,23 +95,15 @@ impl Database {
         &self,
         height: &BlockHeight,
     ) -> StorageResult<Option<SealedBlockHeader>> {
-        let block_id = match self.get_block_id(height)? {
-            Some(i) => i,
+        let header = match self.storage::<FuelBlocks>().get(height)? {
+            Some(block) => block.header().clone(),
             None => return Ok(None),
         };
-        self.get_sealed_block_header(&block_id)
-    }
+        let consensus = self.storage::<SealedBlockConsensus>().get(&header.id())?;
 
-    pub fn get_sealed_block_header(
-        &self,
-        block_id: &BlockId,
-    ) -> StorageResult<Option<SealedBlockHeader>> {
-        let header = self.storage::<FuelBlocks>().get(block_id)?;
-        let consensus = self.storage::<SealedBlockConsensus>().get(block_id)?;
-
-        if let (Some(header), Some(consensus)) = (header, consensus) {
+        if let Some(consensus) = consensus {
             let sealed_block = SealedBlockHeader {
-                entity: header.into_owned().header().clone(),
+                entity: header,
                 consensus: consensus.into_owned(),
             };
 
@@ -114,6 +113,17 @@ impl Database {
         }
     }
 
+    pub fn get_sealed_block_header(
+        &self,
+        block_id: &BlockId,
+    ) -> StorageResult<Option<SealedBlockHeader>> {
+        let height = match self.get_block_height(block_id)? {
+            Some(height) => height,
+            None => return Ok(None),
+        };
+        self.get_sealed_block_header_by_height(&height)
+    }
+
     pub fn get_transactions_on_block(
         &self,
         block_id: &BlockId,
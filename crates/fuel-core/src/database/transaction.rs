@@ -1,5 +1,8 @@
 use crate::{
-    database::Database,
+    database::{
+        Column,
+        Database,
+    },
     state::in_memory::transaction::MemoryTransactionView,
 };
 use fuel_core_storage::{
@@ -23,6 +26,13 @@ pub struct DatabaseTransaction {
     database: Database,
 }
 
+impl DatabaseTransaction {
+    /// Returns the keys modified by the transaction with their columns.
+    pub fn changed_keys(&self) -> Vec<(Vec<u8>, Column)> {
+        self.changes.changed_keys()
+    }
+}
+
 impl AsRef<Database> for DatabaseTransaction {
     fn as_ref(&self) -> &Database {
         &self.database
@@ -12,14 +12,37 @@ use fuel_core_types::{
     self,
     blockchain::primitives::BlockHeight,
     fuel_tx::{
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+            },
+        },
         Bytes32,
+        Input,
+        Output,
         Transaction,
         TxPointer,
     },
-    fuel_types::Address,
-    services::txpool::TransactionStatus,
+    fuel_types::{
+        Address,
+        AssetId,
+    },
+    services::{
+        executor::TransactionTrace,
+        graphql_api::{
+            BalanceDelta,
+            OwnedTransaction,
+        },
+        txpool::TransactionStatus,
+    },
 };
 use std::{
+    collections::BTreeMap,
     mem::size_of,
     ops::Deref,
 };
@@ -45,8 +68,8 @@ impl Database {
         .map(|res| res.map(|(_, tx)| tx))
     }
 
-    /// Iterates over a KV mapping of `[address + block height + tx idx] => transaction id`. This
-    /// allows for efficient lookup of transaction ids associated with an address, sorted by
+    /// Iterates over a KV mapping of `[address + block height + tx idx] => owned transaction`.
+    /// This allows for efficient lookup of transactions associated with an address, sorted by
     /// block age and ordering within a block. The cursor tracks the `[block height + tx idx]` for
     /// pagination purposes.
     pub fn owned_transactions(
@@ -54,18 +77,18 @@ impl Database {
         owner: Address,
         start: Option<OwnedTransactionIndexCursor>,
         direction: Option<IterDirection>,
-    ) -> impl Iterator<Item = DatabaseResult<(TxPointer, Bytes32)>> + '_ {
+    ) -> impl Iterator<Item = DatabaseResult<(TxPointer, OwnedTransaction)>> + '_ {
         let start = start
             .map(|cursor| owned_tx_index_key(&owner, cursor.block_height, cursor.tx_idx));
-        self.iter_all_filtered::<OwnedTransactionIndexKey, Bytes32, _, _>(
+        self.iter_all_filtered::<OwnedTransactionIndexKey, OwnedTransaction, _, _>(
             Column::TransactionsByOwnerBlockIdx,
             Some(owner),
             start,
             direction,
         )
         .map(|res| {
-            res.map(|(key, tx_id)| {
-                (TxPointer::new(key.block_height.into(), key.tx_idx), tx_id)
+            res.map(|(key, owned)| {
+                (TxPointer::new(key.block_height.into(), key.tx_idx), owned)
             })
         })
     }
@@ -75,12 +98,24 @@ impl Database {
         owner: &Address,
         block_height: BlockHeight,
         tx_idx: TransactionIndex,
-        tx_id: &Bytes32,
-    ) -> DatabaseResult<Option<Bytes32>> {
+        owned: &OwnedTransaction,
+    ) -> DatabaseResult<Option<OwnedTransaction>> {
         self.insert(
             owned_tx_index_key(owner, block_height, tx_idx),
             Column::TransactionsByOwnerBlockIdx,
-            tx_id,
+            owned,
+        )
+    }
+
+    pub fn remove_tx_id_owner(
+        &self,
+        owner: &Address,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+    ) -> DatabaseResult<Option<OwnedTransaction>> {
+        self.remove(
+            &owned_tx_index_key(owner, block_height, tx_idx),
+            Column::TransactionsByOwnerBlockIdx,
         )
     }
 
@@ -98,6 +133,126 @@ impl Database {
     ) -> DatabaseResult<Option<TransactionStatus>> {
         self.get(&id.deref()[..], Column::TransactionStatus)
     }
+
+    pub fn insert_tx_trace(
+        &self,
+        id: &Bytes32,
+        trace: &TransactionTrace,
+    ) -> DatabaseResult<Option<TransactionTrace>> {
+        self.insert(id, Column::TransactionTraces, trace)
+    }
+
+    pub fn get_tx_trace(&self, id: &Bytes32) -> DatabaseResult<Option<TransactionTrace>> {
+        self.get(&id.deref()[..], Column::TransactionTraces)
+    }
+}
+
+/// Returns the deduplicated owners of the coins spent and created by the transaction.
+/// The transaction is indexed for each of them in the
+/// [`Column::TransactionsByOwnerBlockIdx`].
+pub fn owners_of_coins(inputs: &[Input], outputs: &[Output]) -> Vec<Address> {
+    let mut owners = vec![];
+    for input in inputs {
+        if let Input::CoinSigned(CoinSigned { owner, .. })
+        | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
+        {
+            owners.push(*owner);
+        }
+    }
+
+    for output in outputs {
+        match output {
+            Output::Coin { to, .. }
+            | Output::Change { to, .. }
+            | Output::Variable { to, .. } => {
+                owners.push(*to);
+            }
+            Output::Contract { .. } | Output::ContractCreated { .. } => {}
+        }
+    }
+
+    // dedupe owners from inputs and outputs prior to indexing
+    owners.sort();
+    owners.dedup();
+    owners
+}
+
+/// Returns the changes of the `owner`'s balances made by the transaction, sorted by
+/// the asset id. Spent messages without data are counted as coins of the base asset.
+pub fn balance_deltas(
+    owner: &Address,
+    inputs: &[Input],
+    outputs: &[Output],
+) -> Vec<BalanceDelta> {
+    let mut deltas = BTreeMap::<AssetId, BalanceDelta>::new();
+
+    for input in inputs {
+        match input {
+            Input::CoinSigned(CoinSigned {
+                owner: input_owner,
+                amount,
+                asset_id,
+                ..
+            })
+            | Input::CoinPredicate(CoinPredicate {
+                owner: input_owner,
+                amount,
+                asset_id,
+                ..
+            }) if input_owner == owner => {
+                let delta = delta_of(&mut deltas, asset_id);
+                delta.spent = delta.spent.saturating_add(*amount);
+            }
+            Input::MessageCoinSigned(MessageCoinSigned {
+                recipient, amount, ..
+            })
+            | Input::MessageCoinPredicate(MessageCoinPredicate {
+                recipient,
+                amount,
+                ..
+            }) if recipient == owner => {
+                let delta = delta_of(&mut deltas, &AssetId::BASE);
+                delta.spent = delta.spent.saturating_add(*amount);
+            }
+            _ => {}
+        }
+    }
+
+    for output in outputs {
+        match output {
+            Output::Coin {
+                to,
+                amount,
+                asset_id,
+            }
+            | Output::Change {
+                to,
+                amount,
+                asset_id,
+            }
+            | Output::Variable {
+                to,
+                amount,
+                asset_id,
+            } if to == owner => {
+                let delta = delta_of(&mut deltas, asset_id);
+                delta.received = delta.received.saturating_add(*amount);
+            }
+            _ => {}
+        }
+    }
+
+    deltas.into_values().collect()
+}
+
+fn delta_of<'a>(
+    deltas: &'a mut BTreeMap<AssetId, BalanceDelta>,
+    asset_id: &AssetId,
+) -> &'a mut BalanceDelta {
+    deltas.entry(*asset_id).or_insert_with(|| BalanceDelta {
+        asset_id: *asset_id,
+        ..Default::default()
+    })
 }
 
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
@@ -184,3 +339,61 @@ impl From<OwnedTransactionIndexCursor> for Vec<u8> {
         bytes
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::{
+        TxPointer,
+        UtxoId,
+    };
+
+    #[test]
+    fn balance_deltas_count_only_coins_of_the_owner() {
+        let owner = Address::from([1; 32]);
+        let other = Address::from([2; 32]);
+        let asset_id = AssetId::from([3; 32]);
+        let coin = |owner: Address, amount: u64, asset_id: AssetId| {
+            Input::coin_signed(
+                UtxoId::default(),
+                owner,
+                amount,
+                asset_id,
+                TxPointer::default(),
+                0,
+                Default::default(),
+            )
+        };
+        let inputs = [
+            coin(owner, 100, asset_id),
+            coin(owner, 10, AssetId::BASE),
+            coin(other, 50, asset_id),
+            Input::message_coin_signed(owner, owner, 5, Default::default(), 0),
+        ];
+        let outputs = [
+            Output::coin(other, 70, asset_id),
+            Output::change(owner, 80, asset_id),
+            Output::change(owner, 15, AssetId::BASE),
+        ];
+
+        let deltas = balance_deltas(&owner, &inputs, &outputs);
+
+        assert_eq!(
+            deltas,
+            vec![
+                BalanceDelta {
+                    asset_id: AssetId::BASE,
+                    received: 15,
+                    spent: 15,
+                },
+                BalanceDelta {
+                    asset_id,
+                    received: 80,
+                    spent: 100,
+                },
+            ]
+        );
+        assert_eq!(deltas[0].net(), 0);
+        assert_eq!(deltas[1].net(), -20);
+    }
+}
@@ -1,8 +1,13 @@
 use crate::{
     database::{
         transaction::DatabaseTransaction,
-        transactions::TransactionIndex,
+        transactions::{
+            balance_deltas,
+            owners_of_coins,
+            TransactionIndex,
+        },
         vm_database::VmDatabase,
+        Column,
         Database,
     },
     service::Config,
@@ -11,7 +16,11 @@ use fuel_core_executor::refs::ContractRef;
 use fuel_core_storage::{
     tables::{
         Coins,
+        ContractsAssets,
+        ContractsInfo,
         ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         Receipts,
@@ -28,10 +37,7 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
-        block::{
-            Block,
-            PartialFuelBlock,
-        },
+        block::PartialFuelBlock,
         header::PartialBlockHeader,
         primitives::{
             BlockHeight,
@@ -68,17 +74,22 @@ use fuel_core_types::{
         Address,
         AssetId,
         Bytes32,
+        Contract as ContractCode,
         Input,
         Mint,
         Output,
         Receipt,
         Transaction,
         TransactionFee,
+        TxId,
         TxPointer,
         UniqueIdentifier,
         UtxoId,
     },
-    fuel_types::MessageId,
+    fuel_types::{
+        ContractId,
+        MessageId,
+    },
     fuel_vm::{
         checked_transaction::{
             Checked,
@@ -97,6 +108,10 @@ use fuel_core_types::{
     },
     services::{
         executor::{
+            CallFrameTrace,
+            ContractBalance,
+            ContractStateSlot,
+            DryRunResult,
             Error as ExecutorError,
             ExecutionBlock,
             ExecutionKind,
@@ -104,11 +119,15 @@ use fuel_core_types::{
             ExecutionType,
             ExecutionTypes,
             Result as ExecutorResult,
+            StateDiff,
+            StateOverrides,
             TransactionExecutionResult,
             TransactionExecutionStatus,
+            TransactionTrace,
             TransactionValidityError,
             UncommittedResult,
         },
+        graphql_api::OwnedTransaction,
         txpool::TransactionStatus,
     },
 };
@@ -116,6 +135,7 @@ use itertools::Itertools;
 pub use ports::RelayerPort;
 use std::{
     borrow::Cow,
+    collections::HashSet,
     ops::{
         Deref,
         DerefMut,
@@ -235,6 +255,91 @@ where
             .map_err(Into::into)
         // drop `temporary_db` without committing to avoid altering state.
     }
+
+    /// Executes the transactions of the `block` one after another on top of the state
+    /// `overrides`. The transaction with index `i` is executed in its own block with the
+    /// height `block.header.height + i`, so it sees the changes of the previous ones.
+    /// None of the changes are committed into the inner `Database`.
+    pub fn dry_run_batch(
+        &self,
+        block: PartialFuelBlock,
+        overrides: StateOverrides,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<DryRunResult>> {
+        let utxo_validation = utxo_validation.unwrap_or(self.config.utxo_validation);
+        let executor = Self {
+            relayer: self.relayer.clone(),
+            config: Config {
+                utxo_validation,
+                ..self.config.clone()
+            },
+            database: self.database.clone(),
+        };
+
+        let mut overlay = self.database.transaction();
+        apply_state_overrides(overlay.as_mut(), overrides)?;
+
+        let PartialFuelBlock {
+            header,
+            transactions,
+        } = block;
+        let base_height = header.consensus.height;
+        transactions
+            .into_iter()
+            .enumerate()
+            .map(|(index, transaction)| {
+                let mut header = header.clone();
+                header.consensus.height = base_height + (index as u64).into();
+
+                // The changes of every transaction are collected in a separate layer
+                // to build the diff of the transaction.
+                let mut tx_db = overlay.as_ref().transaction();
+                remove_coinbase(tx_db.as_mut(), &header.consensus.height)?;
+                let block = PartialFuelBlock::new(header, vec![transaction]);
+                let (
+                    ExecutionResult {
+                        block,
+                        skipped_transactions,
+                        ..
+                    },
+                    block_db,
+                ) = executor
+                    .execute_inner(ExecutionBlock::Production(block), tx_db.as_ref())?
+                    .into();
+                if let Some((_, err)) = skipped_transactions.into_iter().next() {
+                    return Err(err)
+                }
+                block_db.commit()?;
+
+                // The coinbase is the first transaction of the block.
+                let id = block
+                    .transactions()
+                    .last()
+                    .expect("The block contains the dry run transaction")
+                    .id();
+                let receipts = StorageInspect::<Receipts>::get(tx_db.as_ref(), &id)?
+                    .map(Cow::into_owned)
+                    .unwrap_or_default();
+                let gas_used = receipts
+                    .iter()
+                    .find_map(|receipt| match receipt {
+                        Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+                let state_diff = state_diff(&tx_db, &id)?;
+                tx_db.commit()?;
+
+                Ok(DryRunResult {
+                    id,
+                    receipts,
+                    gas_used,
+                    state_diff,
+                })
+            })
+            .collect()
+        // drop `overlay` without committing to avoid altering state.
+    }
 }
 
 impl<R> Executor<R>
@@ -301,7 +406,7 @@ where
         self.persist_transaction_status(&result, block_db_transaction.deref_mut())?;
 
         // save the associated owner for each transaction in the block
-        self.index_tx_owners_for_block(&result.block, &mut block_db_transaction)?;
+        self.index_tx_owners_for_block(&result, &mut block_db_transaction)?;
 
         // ------------ GraphQL API Functionality   END ------------
 
@@ -309,7 +414,7 @@ where
         block_db_transaction
             .deref_mut()
             .storage::<FuelBlocks>()
-            .insert(&finalized_block_id, &result.block.compress())?;
+            .insert(result.block.header().height(), &result.block.compress())?;
 
         // Get the complete fuel block.
         Ok(UncommittedResult::new(
@@ -697,6 +802,13 @@ where
             tx_db_transaction.deref_mut(),
         )?;
 
+        if self.config.vm.trace {
+            let trace = transaction_trace(original_tx.limit(), vm_result.receipts());
+            tx_db_transaction
+                .deref_mut()
+                .insert_tx_trace(&tx_id, &trace)?;
+        }
+
         let status = if vm_result.should_revert() {
             self.log_backtrace(&vm, vm_result.receipts());
             // get reason for revert
@@ -1368,9 +1480,18 @@ where
     /// Associate all transactions within a block to their respective UTXO owners
     fn index_tx_owners_for_block(
         &self,
-        block: &Block,
+        result: &ExecutionResult,
         block_db_transaction: &mut DatabaseTransaction,
     ) -> ExecutorResult<()> {
+        let block = &result.block;
+        let reverted = result
+            .tx_status
+            .iter()
+            .filter(|status| {
+                matches!(status.result, TransactionExecutionResult::Failed { .. })
+            })
+            .map(|status| status.id)
+            .collect::<HashSet<_>>();
         for (tx_idx, tx) in block.transactions().iter().enumerate() {
             let block_height = *block.header().height();
             let mut inputs = &[][..];
@@ -1389,11 +1510,12 @@ where
                     outputs = tx.outputs().as_slice();
                 }
             }
-            self.persist_owners_index(
+            Self::persist_owners_index(
                 block_height,
                 inputs,
                 outputs,
                 &tx_id,
+                reverted.contains(&tx_id),
                 tx_idx as u16,
                 block_db_transaction.deref_mut(),
             )?;
@@ -1401,46 +1523,29 @@ where
         Ok(())
     }
 
-    /// Index the tx id by owner for all of the inputs and outputs
+    /// Index the tx id with the balance changes by owner for all of the inputs and outputs
     fn persist_owners_index(
-        &self,
         block_height: BlockHeight,
         inputs: &[Input],
         outputs: &[Output],
         tx_id: &Bytes32,
+        reverted: bool,
         tx_idx: u16,
         db: &mut Database,
     ) -> ExecutorResult<()> {
-        let mut owners = vec![];
-        for input in inputs {
-            if let Input::CoinSigned(CoinSigned { owner, .. })
-            | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
-            {
-                owners.push(owner);
-            }
-        }
-
-        for output in outputs {
-            match output {
-                Output::Coin { to, .. }
-                | Output::Change { to, .. }
-                | Output::Variable { to, .. } => {
-                    owners.push(to);
-                }
-                Output::Contract { .. } | Output::ContractCreated { .. } => {}
-            }
-        }
-
-        // dedupe owners from inputs and outputs prior to indexing
-        owners.sort();
-        owners.dedup();
+        let owners = owners_of_coins(inputs, outputs);
 
         for owner in owners {
+            let owned = OwnedTransaction {
+                tx_id: *tx_id,
+                reverted,
+                deltas: balance_deltas(&owner, inputs, outputs),
+            };
             db.record_tx_id_owner(
-                owner,
+                &owner,
                 block_height,
                 tx_idx as TransactionIndex,
-                tx_id,
+                &owned,
             )?;
         }
 
@@ -1509,13 +1614,233 @@ impl Fee for CreateCheckedMetadata {
     }
 }
 
+/// Writes the state `overrides` into the `db`.
+fn apply_state_overrides(
+    db: &mut Database,
+    overrides: StateOverrides,
+) -> ExecutorResult<()> {
+    let StateOverrides {
+        coins,
+        contract_state,
+        contract_code,
+    } = overrides;
+
+    for coin in coins {
+        let utxo_id = coin.utxo_id;
+        db.storage::<Coins>().insert(&utxo_id, &coin.compress())?;
+    }
+
+    for (contract_id, code) in contract_code {
+        let code = ContractCode::from(code);
+        let salt = db
+            .storage::<ContractsInfo>()
+            .get(&contract_id)?
+            .map(|info| info.0)
+            .unwrap_or_default();
+        db.storage::<ContractsInfo>()
+            .insert(&contract_id, &(salt, code.root()))?;
+        db.storage::<ContractsRawCode>()
+            .insert(&contract_id, code.as_ref())?;
+        // The contract inputs of the transactions refer to the latest UTXO of the contract.
+        if !db
+            .storage::<ContractsLatestUtxo>()
+            .contains_key(&contract_id)?
+        {
+            db.storage::<ContractsLatestUtxo>()
+                .insert(&contract_id, &ContractUtxoInfo::default())?;
+        }
+    }
+
+    for ContractStateSlot {
+        contract_id,
+        key,
+        value,
+    } in contract_state
+    {
+        let key = (&contract_id, &key).into();
+        match value {
+            Some(value) => {
+                db.storage::<ContractsState>().insert(&key, &value)?;
+            }
+            None => {
+                db.storage::<ContractsState>().remove(&key)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Removes the coinbase of the existing block at the `height` and its coins from the `db`.
+/// The dry run on top of a historical block produces the coinbase with the same id.
+fn remove_coinbase(db: &mut Database, height: &BlockHeight) -> ExecutorResult<()> {
+    let coinbase_id = match db.storage::<FuelBlocks>().get(height)? {
+        Some(block) => match block.transactions().first() {
+            Some(id) => *id,
+            None => return Ok(()),
+        },
+        None => return Ok(()),
+    };
+    if let Some(Transaction::Mint(coinbase)) =
+        db.storage::<Transactions>().remove(&coinbase_id)?
+    {
+        for index in 0..coinbase.outputs().len() {
+            db.storage::<Coins>()
+                .remove(&UtxoId::new(coinbase_id, index as u8))?;
+        }
+    }
+    Ok(())
+}
+
+/// Builds the diff of the transaction `tx_id` from the changes of its block.
+fn state_diff(db: &DatabaseTransaction, tx_id: &TxId) -> ExecutorResult<StateDiff> {
+    let invalid_key = |column: Column| {
+        ExecutorError::StorageError(Box::new(fuel_core_storage::Error::Other(
+            anyhow::anyhow!("Invalid key in the column {:?}", column),
+        )))
+    };
+    let split_key = |key: &[u8], column: Column| -> ExecutorResult<(Bytes32, Vec<u8>)> {
+        if key.len() < Bytes32::LEN {
+            return Err(invalid_key(column))
+        }
+        let (first, rest) = key.split_at(Bytes32::LEN);
+        let first = Bytes32::try_from(first).map_err(|_| invalid_key(column))?;
+        Ok((first, rest.to_vec()))
+    };
+
+    let changed_keys = db.changed_keys();
+    let db = db.as_ref();
+    let mut diff = StateDiff::default();
+    for (key, column) in changed_keys {
+        match column {
+            Column::Coins => {
+                let (coin_tx_id, output_index) = split_key(&key, column)?;
+                let output_index = match output_index.as_slice() {
+                    [output_index] => *output_index,
+                    _ => return Err(invalid_key(column)),
+                };
+                let utxo_id = UtxoId::new(coin_tx_id, output_index);
+                match db.storage::<Coins>().get(&utxo_id)? {
+                    // Skips the coins created by the coinbase.
+                    Some(coin) if &coin_tx_id == tx_id => diff
+                        .created_coins
+                        .push(coin.into_owned().uncompress(utxo_id)),
+                    Some(_) => {}
+                    None => diff.spent_coins.push(utxo_id),
+                }
+            }
+            Column::ContractsRawCode => {
+                let (contract_id, _) = split_key(&key, column)?;
+                diff.created_contracts.push(ContractId::from(*contract_id));
+            }
+            Column::ContractsState => {
+                let (contract_id, slot) = split_key(&key, column)?;
+                let contract_id = ContractId::from(*contract_id);
+                let slot = Bytes32::try_from(slot.as_slice())
+                    .map_err(|_| invalid_key(column))?;
+                let value = db
+                    .storage::<ContractsState>()
+                    .get(&(&contract_id, &slot).into())?
+                    .map(Cow::into_owned);
+                diff.contract_state.push(ContractStateSlot {
+                    contract_id,
+                    key: slot,
+                    value,
+                });
+            }
+            Column::ContractsAssets => {
+                let (contract_id, asset_id) = split_key(&key, column)?;
+                let contract_id = ContractId::from(*contract_id);
+                let asset_id = AssetId::try_from(asset_id.as_slice())
+                    .map_err(|_| invalid_key(column))?;
+                let amount = db
+                    .storage::<ContractsAssets>()
+                    .get(&(&contract_id, &asset_id).into())?
+                    .map(Cow::into_owned)
+                    .unwrap_or_default();
+                diff.contract_balances.push(ContractBalance {
+                    contract_id,
+                    asset_id,
+                    amount,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    // The changes are stored in the hash map, so sort them to have a stable order.
+    diff.created_coins.sort_by_key(|coin| coin.utxo_id);
+    diff.spent_coins.sort();
+    diff.created_contracts.sort();
+    diff.contract_state
+        .sort_by_key(|slot| (slot.contract_id, slot.key));
+    diff.contract_balances
+        .sort_by_key(|balance| (balance.contract_id, balance.asset_id));
+    Ok(diff)
+}
+
+/// Builds the call tree of the transaction from its `receipts`.
+///
+/// Every `Call` receipt opens the frame of the called contract, and the `Return` or
+/// `ReturnData` receipt closes it. `Panic` and `Revert` stop the whole execution, so
+/// they are the last receipts of the frame before the `ScriptResult`.
+///
+/// The receipts don't contain the remaining gas at the end of the frame, so only the
+/// gas used by the whole script is known, not the gas used by each frame.
+fn transaction_trace(gas_limit: Word, receipts: &[Receipt]) -> TransactionTrace {
+    let mut frames = vec![CallFrameTrace {
+        parent: None,
+        contract_id: None,
+        gas_forwarded: gas_limit,
+        receipts: vec![],
+    }];
+    let mut gas_used = 0;
+    // The indexes of the frames of the current call stack.
+    let mut stack = vec![0];
+
+    for (index, receipt) in receipts.iter().enumerate() {
+        let index = index as u32;
+        let current = *stack.last().expect("The script frame is never popped");
+        match receipt {
+            Receipt::Call { to, gas, .. } => {
+                frames[current].receipts.push(index);
+                frames.push(CallFrameTrace {
+                    parent: Some(current as u32),
+                    contract_id: Some(*to),
+                    gas_forwarded: *gas,
+                    receipts: vec![],
+                });
+                stack.push(frames.len() - 1);
+            }
+            Receipt::Return { .. } | Receipt::ReturnData { .. } => {
+                frames[current].receipts.push(index);
+                if stack.len() > 1 {
+                    stack.pop();
+                }
+            }
+            Receipt::ScriptResult { gas_used: used, .. } => {
+                gas_used = *used;
+                frames[0].receipts.push(index);
+            }
+            _ => frames[current].receipts.push(index),
+        }
+    }
+
+    TransactionTrace { gas_used, frames }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use fuel_core_storage::tables::Messages;
     use fuel_core_types::{
-        blockchain::header::ConsensusHeader,
-        entities::message::Message,
+        blockchain::{
+            block::Block,
+            header::ConsensusHeader,
+        },
+        entities::{
+            coins::coin::Coin,
+            message::Message,
+        },
         fuel_asm::op,
         fuel_crypto::SecretKey,
         fuel_tx,
@@ -1859,14 +2184,14 @@ mod tests {
                 .execute_and_commit(ExecutionBlock::Validation(produced_block))
                 .unwrap();
             assert_eq!(validated_block.transactions(), produced_txs);
-            let (_, owned_transactions_td_id) = validator
+            let (_, owned_transaction) = validator
                 .database
                 .owned_transactions(recipient, None, None)
                 .next()
                 .unwrap()
                 .unwrap();
             // Should own `Mint` transaction
-            assert_eq!(owned_transactions_td_id, produced_txs[0].id());
+            assert_eq!(owned_transaction.tx_id, produced_txs[0].id());
         }
 
         #[test]
@@ -2297,6 +2622,73 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn dry_run_batch_does_not_commit_overrides_and_results() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let secret = SecretKey::random(&mut rng);
+        let owner = Input::owner(&secret.public_key());
+        let utxo_id: UtxoId = rng.gen();
+
+        let tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            10,
+            AssetId::BASE,
+            Default::default(),
+            0,
+        )
+        .add_output(Output::coin(owner, 10, AssetId::BASE))
+        .finalize_as_transaction();
+
+        let executor = Executor::test(
+            Database::default(),
+            Config {
+                utxo_validation: true,
+                ..Config::local_node()
+            },
+        );
+        let overrides = StateOverrides {
+            coins: vec![Coin {
+                utxo_id,
+                owner,
+                amount: 10,
+                asset_id: AssetId::BASE,
+                maturity: Default::default(),
+                tx_pointer: Default::default(),
+            }],
+            ..Default::default()
+        };
+        let block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![tx.clone()],
+        };
+
+        let results = executor.dry_run_batch(block, overrides, None).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, tx.id());
+        let created = UtxoId::new(tx.id(), 0);
+        assert_eq!(results[0].state_diff.spent_coins, vec![utxo_id]);
+        assert_eq!(
+            results[0]
+                .state_diff
+                .created_coins
+                .iter()
+                .map(|coin| coin.utxo_id)
+                .collect_vec(),
+            vec![created]
+        );
+
+        // Neither the overrides nor the results of the execution are committed.
+        let db = &executor.database;
+        assert!(!db.storage::<Coins>().contains_key(&utxo_id).unwrap());
+        assert!(!db.storage::<Coins>().contains_key(&created).unwrap());
+        assert!(!db.storage::<Transactions>().contains_key(&tx.id()).unwrap());
+    }
+
     // corrupt a produced block by randomizing change amount
     // and verify that the executor invalidates the tx
     #[test]
@@ -3677,4 +4069,53 @@ mod tests {
 
         assert_eq!(time.0, receipts[0].val().unwrap());
     }
+
+    #[test]
+    fn transaction_trace_builds_nested_call_frames() {
+        use fuel_core_types::fuel_tx::ScriptExecutionResult;
+
+        let script = ContractId::zeroed();
+        let outer = ContractId::from([1; 32]);
+        let inner = ContractId::from([2; 32]);
+        let call = |from, to, gas| {
+            Receipt::call(from, to, 0, Default::default(), gas, 0, 0, 0, 0)
+        };
+        let receipts = vec![
+            call(script, outer, 1000),
+            call(outer, inner, 500),
+            Receipt::log(inner, 1, 0, 0, 0, 0, 0),
+            Receipt::ret(inner, 1, 0, 0),
+            Receipt::log(outer, 2, 0, 0, 0, 0, 0),
+            Receipt::ret(outer, 1, 0, 0),
+            call(script, inner, 200),
+            Receipt::ret(inner, 1, 0, 0),
+            Receipt::ret(script, 1, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Success, 123),
+        ];
+
+        let trace = transaction_trace(10_000, &receipts);
+
+        assert_eq!(trace.gas_used, 123);
+        let frames: Vec<_> = trace
+            .frames
+            .iter()
+            .map(|frame| {
+                (
+                    frame.parent,
+                    frame.contract_id,
+                    frame.gas_forwarded,
+                    frame.receipts.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            frames,
+            vec![
+                (None, None, 10_000, vec![0, 6, 8, 9]),
+                (Some(0), Some(outer), 1000, vec![1, 4, 5]),
+                (Some(1), Some(inner), 500, vec![2, 3]),
+                (Some(0), Some(inner), 200, vec![7]),
+            ]
+        );
+    }
 }
@@ -5,12 +5,20 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_tx::ConsensusParameters,
+    fuel_vm::GasCosts,
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+    time::Duration,
+};
 
+pub(crate) mod auth;
 pub mod ports;
+pub(crate) mod rate_limiter;
 pub mod service;
+pub(crate) mod websocket;
 
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -22,7 +30,81 @@ pub struct Config {
     pub max_tx: usize,
     pub max_depth: usize,
     pub transaction_parameters: ConsensusParameters,
+    pub gas_costs: GasCosts,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    pub backup_dir: Option<PathBuf>,
+    pub websocket: WebSocketConfig,
+    pub query_limits: QueryLimits,
+    /// The bearer token required by the privileged operations. Everyone can use them
+    /// if the token is not set.
+    pub admin_token: Option<Secret<String>>,
+}
+
+/// The limits that protect the node from the expensive GraphQL requests.
+#[derive(Clone, Debug)]
+pub struct QueryLimits {
+    /// The maximum nesting depth of the query.
+    pub max_depth: usize,
+    /// The maximum complexity of the query. Each field costs one, the paginated
+    /// connections multiply the cost of their elements by the page size, and the lists
+    /// loaded from the database, like the transactions of the block, by the estimated
+    /// size of the list.
+    pub max_complexity: usize,
+    /// The maximum number of elements requested by `first` or `last` of one connection.
+    pub max_page_size: usize,
+    /// The maximum number of the owner's transactions scanned by one page of the
+    /// filtered transaction history. At least one transaction is scanned.
+    pub max_scanned_entries: usize,
+    /// The requests from one IP address are throttled if set.
+    pub rate_limit: Option<RateLimitConfig>,
+    /// The maximum number of transactions in one batch dry run.
+    pub max_dry_run_txs: usize,
+    /// The maximum total gas limit of the scripts in one batch dry run.
+    pub max_dry_run_gas: u64,
+}
+
+impl Default for QueryLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 16,
+            max_complexity: 50_000,
+            max_page_size: 10_000,
+            max_scanned_entries: 100_000,
+            rate_limit: None,
+            max_dry_run_txs: 100,
+            max_dry_run_gas: 1_000_000_000,
+        }
+    }
+}
+
+/// The token bucket parameters of the per-IP rate limiter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// The number of requests restored every second.
+    pub requests_per_second: u32,
+    /// The maximum number of requests that can be sent at once.
+    pub burst: u32,
+}
+
+/// The configuration of the WebSocket transport for subscriptions.
+#[derive(Clone, Debug)]
+pub struct WebSocketConfig {
+    /// The interval between keep-alive messages sent to the client.
+    pub keep_alive_interval: Duration,
+    /// The connection is closed if the client doesn't initialize it during this time.
+    pub connection_init_timeout: Duration,
+    /// The maximum number of active subscriptions of one connection.
+    pub max_subscriptions_per_connection: usize,
+}
+
+impl Default for WebSocketConfig {
+    fn default() -> Self {
+        Self {
+            keep_alive_interval: Duration::from_secs(15),
+            connection_init_timeout: Duration::from_secs(10),
+            max_subscriptions_per_connection: 100,
+        }
+    }
 }
 
 pub trait IntoApiResult<T> {
@@ -0,0 +1,90 @@
+//! The bearer token authentication of the privileged GraphQL operations: block
+//! production, debugger sessions, dry runs and backups.
+
+use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use async_graphql::{
+    Context,
+    ErrorExtensions,
+    Guard,
+};
+use axum::http::{
+    header::AUTHORIZATION,
+    HeaderMap,
+};
+use fuel_core_types::secrecy::ExposeSecret;
+
+/// The bearer token sent by the client in the `Authorization` header.
+pub(crate) struct BearerToken(String);
+
+impl BearerToken {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let value = headers.get(AUTHORIZATION)?.to_str().ok()?;
+        let (scheme, token) = value.split_once(' ')?;
+        scheme
+            .eq_ignore_ascii_case("bearer")
+            .then(|| Self(token.trim().to_string()))
+    }
+}
+
+/// Allows the field only to the requests with the admin token of the node.
+/// Everything is allowed if the node doesn't have the admin token.
+pub(crate) struct AdminGuard;
+
+#[async_trait::async_trait]
+impl Guard for AdminGuard {
+    async fn check(&self, ctx: &Context<'_>) -> async_graphql::Result<()> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let expected = match &config.admin_token {
+            Some(token) => token.expose_secret(),
+            None => return Ok(()),
+        };
+        let authorized = matches!(
+            ctx.data_opt::<BearerToken>(),
+            Some(BearerToken(token)) if constant_time_eq(token.as_bytes(), expected.as_bytes())
+        );
+
+        if authorized {
+            Ok(())
+        } else {
+            Err(async_graphql::Error::new(
+                "The operation requires the admin token in the `Authorization` header",
+            )
+            .extend_with(|_, extensions| extensions.set("code", "UNAUTHORIZED")))
+        }
+    }
+}
+
+/// Compares the tokens without leaking the length of the matching prefix via timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(authorization: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static(authorization));
+        headers
+    }
+
+    #[test]
+    fn bearer_token_is_parsed_from_headers() {
+        let token = |value| BearerToken::from_headers(&headers(value)).map(|t| t.0);
+
+        assert_eq!(token("Bearer secret"), Some("secret".to_string()));
+        assert_eq!(token("bearer secret"), Some("secret".to_string()));
+        assert_eq!(token("Basic secret"), None);
+        assert_eq!(token("secret"), None);
+        assert!(BearerToken::from_headers(&HeaderMap::new()).is_none());
+    }
+
+    #[test]
+    fn tokens_are_compared_by_content() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+    }
+}
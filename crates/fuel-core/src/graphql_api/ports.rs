@@ -23,10 +23,13 @@ use fuel_core_storage::{
 };
 use fuel_core_txpool::service::TxUpdate;
 use fuel_core_types::{
-    blockchain::primitives::{
-        BlockHeight,
-        BlockId,
-        DaBlockHeight,
+    blockchain::{
+        block::CompressedBlock,
+        primitives::{
+            BlockHeight,
+            BlockId,
+            DaBlockHeight,
+        },
     },
     entities::message::Message,
     fuel_tx::{
@@ -43,7 +46,16 @@ use fuel_core_types::{
         Nonce,
     },
     services::{
-        graphql_api::ContractBalance,
+        block_importer::ImportResult,
+        executor::{
+            DryRunResult,
+            StateOverrides,
+            TransactionTrace,
+        },
+        graphql_api::{
+            ContractBalance,
+            OwnedTransaction,
+        },
         txpool::{
             InsertionResult,
             TransactionStatus,
@@ -51,7 +63,10 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+};
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 
 /// The database port expected by GraphQL API service.
@@ -62,8 +77,10 @@ pub trait DatabasePort:
     + DatabaseTransactions
     + DatabaseMessages
     + DatabaseCoins
+    + DatabaseBalances
     + DatabaseContracts
     + DatabaseChain
+    + DatabaseBackup
 {
 }
 
@@ -72,15 +89,28 @@ pub trait DatabaseBlocks:
     StorageInspect<FuelBlocks, Error = StorageError>
     + StorageInspect<SealedBlockConsensus, Error = StorageError>
 {
-    fn block_id(&self, height: &BlockHeight) -> StorageResult<BlockId>;
+    fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight>;
 
-    fn blocks_ids(
+    fn blocks(
         &self,
         start: Option<BlockHeight>,
         direction: IterDirection,
-    ) -> BoxedIter<'_, StorageResult<(BlockHeight, BlockId)>>;
+    ) -> BoxedIter<'_, StorageResult<CompressedBlock>>;
+
+    fn latest_height(&self) -> StorageResult<BlockHeight>;
+
+    /// Returns `true` if the transactions of the block at the `height` are pruned.
+    fn is_pruned(&self, height: &BlockHeight) -> StorageResult<bool>;
 
-    fn ids_of_latest_block(&self) -> StorageResult<(BlockHeight, BlockId)>;
+    /// Returns the height of the last block with pruned transactions, receipts and
+    /// statuses. `None` means that the full history of transactions is available.
+    fn pruned_transactions_height(&self) -> StorageResult<Option<BlockHeight>>;
+
+    /// Returns `true` if the block at the `height` was imported without its transactions.
+    fn is_header_only(&self, height: &BlockHeight) -> StorageResult<bool>;
+
+    /// Returns `true` if any block was imported without its transactions.
+    fn has_header_only_blocks(&self) -> StorageResult<bool>;
 }
 
 /// Trait that specifies all the getters required for transactions.
@@ -90,12 +120,16 @@ pub trait DatabaseTransactions:
 {
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
 
-    fn owned_transactions_ids(
+    /// Returns the call trace of the transaction if it was recorded.
+    fn tx_trace(&self, tx_id: &TxId) -> StorageResult<Option<TransactionTrace>>;
+
+    /// Returns the transactions of the `owner` with the changes of its balances.
+    fn owned_transaction_entries(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
-    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+    ) -> BoxedIter<StorageResult<(TxPointer, OwnedTransaction)>>;
 }
 
 /// Trait that specifies all the getters required for messages.
@@ -127,6 +161,17 @@ pub trait DatabaseCoins: StorageInspect<Coins, Error = StorageError> {
     ) -> BoxedIter<'_, StorageResult<UtxoId>>;
 }
 
+/// Trait that specifies all the getters required for owners' balances.
+pub trait DatabaseBalances {
+    fn owned_balance(&self, owner: &Address, asset_id: &AssetId) -> StorageResult<u128>;
+
+    fn owned_balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u128)>>;
+}
+
 /// Trait that specifies all the getters required for contract.
 pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
@@ -148,6 +193,13 @@ pub trait DatabaseChain {
     fn base_chain_height(&self) -> StorageResult<DaBlockHeight>;
 }
 
+/// Trait that specifies the backup of the database.
+#[async_trait]
+pub trait DatabaseBackup {
+    /// Creates a consistent backup of the database in the new directory at the `path`.
+    async fn backup(&self, path: PathBuf) -> StorageResult<()>;
+}
+
 pub trait TxPoolPort: Send + Sync {
     fn transaction(&self, id: TxId) -> Option<Transaction>;
 
@@ -168,10 +220,23 @@ pub trait DryRunExecution {
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<Receipt>>;
+
+    async fn dry_run_batch(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        overrides: StateOverrides,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<DryRunResult>>;
 }
 
 pub trait BlockProducerPort: Send + Sync + DryRunExecution {}
 
+pub trait BlockImporterPort: Send + Sync {
+    /// Returns the stream of the blocks imported after the subscription.
+    fn block_events(&self) -> BoxStream<Arc<ImportResult>>;
+}
+
 #[async_trait::async_trait]
 pub trait ConsensusModulePort: Send + Sync {
     async fn manually_produce_blocks(
@@ -0,0 +1,146 @@
+//! The token bucket rate limiter of the GraphQL requests per client IP address.
+
+use crate::graphql_api::RateLimitConfig;
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::Mutex,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The full buckets are removed with this period to not keep the addresses
+/// of the clients that don't send requests anymore.
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
+pub(crate) struct RateLimiter {
+    config: Option<RateLimitConfig>,
+    state: Mutex<State>,
+}
+
+struct State {
+    buckets: HashMap<IpAddr, Bucket>,
+    last_cleanup: Instant,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: Option<RateLimitConfig>) -> Self {
+        Self {
+            config,
+            state: Mutex::new(State {
+                buckets: HashMap::new(),
+                last_cleanup: Instant::now(),
+            }),
+        }
+    }
+
+    /// Takes a token from the bucket of the `address`. Returns the time after which
+    /// the next request is allowed if the bucket is empty.
+    pub(crate) fn check(&self, address: IpAddr) -> Result<(), Duration> {
+        self.check_at(address, Instant::now())
+    }
+
+    fn check_at(&self, address: IpAddr, now: Instant) -> Result<(), Duration> {
+        let config = match self.config {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+        let capacity = config.burst.max(1) as f64;
+        let rate = config.requests_per_second.max(1) as f64;
+        let refill = |bucket: &Bucket| {
+            let elapsed = now.saturating_duration_since(bucket.updated_at);
+            (bucket.tokens + elapsed.as_secs_f64() * rate).min(capacity)
+        };
+
+        let mut state = self.state.lock().expect("The rate limiter is poisoned");
+        if now.saturating_duration_since(state.last_cleanup) >= CLEANUP_INTERVAL {
+            state.buckets.retain(|_, bucket| refill(bucket) < capacity);
+            state.last_cleanup = now;
+        }
+
+        let bucket = state.buckets.entry(address).or_insert(Bucket {
+            tokens: capacity,
+            updated_at: now,
+        });
+        bucket.tokens = refill(bucket);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / rate))
+        }
+    }
+}
+
+/// Returns the whole number of seconds to wait before the next request, rounded up
+/// to not invite the client to retry too early.
+pub(crate) fn retry_after_secs(retry_after: Duration) -> u64 {
+    retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    const FIRST: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+    const SECOND: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 2));
+
+    #[test]
+    fn unlimited_without_config() {
+        let limiter = RateLimiter::new(None);
+        let now = Instant::now();
+
+        for _ in 0..1000 {
+            assert!(limiter.check_at(FIRST, now).is_ok());
+        }
+    }
+
+    #[test]
+    fn burst_is_allowed_and_refilled_with_rate() {
+        let limiter = RateLimiter::new(Some(RateLimitConfig {
+            requests_per_second: 2,
+            burst: 3,
+        }));
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.check_at(FIRST, now).is_ok());
+        }
+        assert_eq!(
+            limiter.check_at(FIRST, now),
+            Err(Duration::from_millis(500))
+        );
+        // Other clients have their own buckets.
+        assert!(limiter.check_at(SECOND, now).is_ok());
+
+        let later = now + Duration::from_millis(500);
+        assert!(limiter.check_at(FIRST, later).is_ok());
+        assert!(limiter.check_at(FIRST, later).is_err());
+    }
+
+    #[test]
+    fn idle_buckets_are_removed() {
+        let limiter = RateLimiter::new(Some(RateLimitConfig {
+            requests_per_second: 1,
+            burst: 1,
+        }));
+        let now = Instant::now();
+        limiter.check_at(FIRST, now).unwrap();
+
+        limiter.check_at(SECOND, now + CLEANUP_INTERVAL).unwrap();
+
+        let state = limiter.state.lock().unwrap();
+        assert!(!state.buckets.contains_key(&FIRST));
+        assert!(state.buckets.contains_key(&SECOND));
+    }
+}
@@ -1,11 +1,20 @@
 use crate::{
     fuel_core_graphql_api::ports::{
+        BlockImporterPort,
         BlockProducerPort,
         ConsensusModulePort,
         DatabasePort,
         TxPoolPort,
     },
-    graphql_api::Config,
+    graphql_api::{
+        auth::BearerToken,
+        rate_limiter::{
+            retry_after_secs,
+            RateLimiter,
+        },
+        websocket::graphql_ws_handler,
+        Config,
+    },
     schema::{
         CoreSchema,
         CoreSchemaBuilder,
@@ -18,11 +27,15 @@ use async_graphql::{
         playground_source,
         GraphQLPlaygroundConfig,
     },
+    ErrorExtensions,
+    Pos,
     Request,
     Response,
+    ServerError,
 };
 use axum::{
     extract::{
+        ConnectInfo,
         DefaultBodyLimit,
         Extension,
     },
@@ -31,13 +44,17 @@ use axum::{
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
+            RETRY_AFTER,
         },
+        HeaderMap,
         HeaderValue,
+        StatusCode,
     },
     response::{
         sse::Event,
         Html,
         IntoResponse,
+        Response as HttpResponse,
         Sse,
     },
     routing::{
@@ -52,7 +69,6 @@ use fuel_core_services::{
     RunnableTask,
     StateWatcher,
 };
-use futures::Stream;
 use serde_json::json;
 use std::{
     future::Future,
@@ -61,6 +77,8 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    sync::Arc,
+    time::Duration,
 };
 use tokio_stream::StreamExt;
 use tower_http::{
@@ -77,6 +95,7 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 //  use only `Database` to receive all information about transactions.
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
+pub type BlockImporter = Box<dyn BlockImporterPort>;
 
 #[derive(Clone)]
 pub struct SharedState {
@@ -111,7 +130,10 @@ impl RunnableService for NotInitializedTask {
         let mut state = state.clone();
         let server = axum::Server::from_tcp(self.listener)
             .unwrap()
-            .serve(self.router.into_make_service())
+            .serve(
+                self.router
+                    .into_make_service_with_connect_info::<SocketAddr>(),
+            )
             .with_graceful_shutdown(async move {
                 state
                     .while_started()
@@ -150,15 +172,21 @@ pub fn new_service(
     txpool: TxPool,
     producer: BlockProducer,
     consensus_module: ConsensusModule,
+    block_importer: BlockImporter,
 ) -> anyhow::Result<Service> {
     let network_addr = config.addr;
+    let websocket_config = config.websocket.clone();
+    let rate_limiter = Arc::new(RateLimiter::new(config.query_limits.rate_limit));
 
     let schema = schema
+        .limit_depth(config.query_limits.max_depth)
+        .limit_complexity(config.query_limits.max_complexity)
         .data(config)
         .data(database)
         .data(txpool)
         .data(producer)
         .data(consensus_module)
+        .data(block_importer)
         .extension(Tracing)
         .finish();
 
@@ -169,9 +197,12 @@ pub fn new_service(
             "/graphql-sub",
             post(graphql_subscription_handler).options(ok),
         )
+        .route("/graphql-ws", get(graphql_ws_handler))
         .route("/metrics", get(metrics))
         .route("/health", get(health))
         .layer(Extension(schema))
+        .layer(Extension(websocket_config))
+        .layer(Extension(rate_limiter))
         .layer(TraceLayer::new_for_http())
         .layer(SetResponseHeaderLayer::<_>::overriding(
             ACCESS_CONTROL_ALLOW_ORIGIN,
@@ -200,7 +231,9 @@ pub fn new_service(
 }
 
 async fn graphql_playground() -> impl IntoResponse {
-    Html(playground_source(GraphQLPlaygroundConfig::new("/graphql")))
+    Html(playground_source(
+        GraphQLPlaygroundConfig::new("/graphql").subscription_endpoint("/graphql-ws"),
+    ))
 }
 
 async fn health() -> Json<serde_json::Value> {
@@ -209,20 +242,62 @@ async fn health() -> Json<serde_json::Value> {
 
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    rate_limiter: Extension<Arc<RateLimiter>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     req: Json<Request>,
-) -> Json<Response> {
-    schema.execute(req.0).await.into()
+) -> HttpResponse {
+    if let Err(retry_after) = rate_limiter.check(address.ip()) {
+        return rate_limited(retry_after)
+    }
+    Json(schema.execute(with_bearer_token(req.0, &headers)).await).into_response()
 }
 
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
+    rate_limiter: Extension<Arc<RateLimiter>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     req: Json<Request>,
-) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
+) -> HttpResponse {
+    if let Err(retry_after) = rate_limiter.check(address.ip()) {
+        return rate_limited(retry_after)
+    }
     let stream = schema
-        .execute_stream(req.0)
-        .map(|r| Ok(Event::default().json_data(r).unwrap()));
+        .execute_stream(with_bearer_token(req.0, &headers))
+        .map(|r| Ok::<_, serde_json::Error>(Event::default().json_data(r).unwrap()));
     Sse::new(stream)
         .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
+        .into_response()
+}
+
+fn with_bearer_token(request: Request, headers: &HeaderMap) -> Request {
+    match BearerToken::from_headers(headers) {
+        Some(token) => request.data(token),
+        None => request,
+    }
+}
+
+/// The GraphQL error for the throttled client with the `RATE_LIMITED` error code.
+pub(crate) fn rate_limited_error(retry_after: Duration) -> ServerError {
+    async_graphql::Error::new("Too many requests")
+        .extend_with(|_, extensions| {
+            extensions.set("code", "RATE_LIMITED");
+            extensions.set("retryAfterSecs", retry_after_secs(retry_after));
+        })
+        .into_server_error(Pos::default())
+}
+
+/// The GraphQL response for the throttled client with the `RATE_LIMITED` error code.
+pub(crate) fn rate_limited(retry_after: Duration) -> HttpResponse {
+    let retry_after_secs = retry_after_secs(retry_after);
+    let error = rate_limited_error(retry_after);
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(RETRY_AFTER, retry_after_secs.to_string())],
+        Json(Response::from_errors(vec![error])),
+    )
+        .into_response()
 }
 
 async fn ok() -> anyhow::Result<(), ()> {
@@ -0,0 +1,360 @@
+//! The WebSocket transport for GraphQL subscriptions. It supports the
+//! [`graphql-transport-ws`](https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md)
+//! protocol and the legacy
+//! [`graphql-ws`](https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md)
+//! protocol used by the Apollo clients.
+
+use crate::{
+    graphql_api::{
+        auth::BearerToken,
+        rate_limiter::RateLimiter,
+        service::{
+            rate_limited,
+            rate_limited_error,
+        },
+        WebSocketConfig,
+    },
+    schema::CoreSchema,
+};
+use async_graphql::{
+    http::{
+        ClientMessage,
+        WebSocket as GraphQLWebSocket,
+        WebSocketProtocols,
+        WsMessage,
+        ALL_WEBSOCKET_PROTOCOLS,
+    },
+    Data,
+};
+use axum::{
+    extract::{
+        ws::{
+            CloseFrame,
+            Message,
+            WebSocket,
+            WebSocketUpgrade,
+        },
+        ConnectInfo,
+        Extension,
+    },
+    http::{
+        header::SEC_WEBSOCKET_PROTOCOL,
+        HeaderMap,
+        StatusCode,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+};
+use futures::{
+    SinkExt,
+    StreamExt,
+};
+use serde_json::json;
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    net::{
+        IpAddr,
+        SocketAddr,
+    },
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc,
+    time::Instant,
+};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+/// The close code of the `graphql-transport-ws` protocol for the connection that
+/// wasn't initialized in time.
+const CONNECTION_INIT_TIMEOUT_CODE: u16 = 4408;
+
+pub(crate) async fn graphql_ws_handler(
+    Extension(schema): Extension<CoreSchema>,
+    Extension(config): Extension<WebSocketConfig>,
+    Extension(rate_limiter): Extension<Arc<RateLimiter>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    if let Err(retry_after) = rate_limiter.check(address.ip()) {
+        return rate_limited(retry_after)
+    }
+
+    // The first protocol requested by the client is used, the same as
+    // `WebSocketUpgrade` selects it for the response.
+    let protocol = headers
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|protocols| protocols.to_str().ok())
+        .and_then(|protocols| {
+            protocols
+                .split(',')
+                .find_map(|protocol| WebSocketProtocols::from_str(protocol.trim()).ok())
+        });
+
+    // The privileged operations sent over the connection are authorized by the token
+    // of the upgrade request.
+    let mut data = Data::default();
+    if let Some(token) = BearerToken::from_headers(&headers) {
+        data.insert(token);
+    }
+
+    match protocol {
+        Some(protocol) => upgrade
+            .protocols(ALL_WEBSOCKET_PROTOCOLS)
+            .on_upgrade(move |socket| {
+                let limiter = ClientRateLimiter {
+                    rate_limiter,
+                    address: address.ip(),
+                };
+                serve_connection(socket, schema, data, protocol, config, limiter)
+            }),
+        None => (
+            StatusCode::BAD_REQUEST,
+            "The `Sec-WebSocket-Protocol` should be `graphql-transport-ws` or `graphql-ws`",
+        )
+            .into_response(),
+    }
+}
+
+async fn serve_connection(
+    socket: WebSocket,
+    schema: CoreSchema,
+    data: Data,
+    protocol: WebSocketProtocols,
+    config: WebSocketConfig,
+    limiter: ClientRateLimiter,
+) {
+    let (mut sink, mut incoming) = socket.split();
+    let (requests, requests_receiver) = mpsc::unbounded_channel();
+    let mut responses = GraphQLWebSocket::from_message_stream(
+        schema,
+        UnboundedReceiverStream::new(requests_receiver),
+        protocol,
+    )
+    .on_connection_init(|_| async move { Ok(data) });
+    let mut subscriptions =
+        Subscriptions::new(protocol, config.max_subscriptions_per_connection);
+    let mut initialized = false;
+    let init_timeout = tokio::time::sleep(config.connection_init_timeout);
+    tokio::pin!(init_timeout);
+    let mut keep_alive = tokio::time::interval_at(
+        Instant::now() + config.keep_alive_interval,
+        config.keep_alive_interval,
+    );
+
+    loop {
+        tokio::select! {
+            message = incoming.next() => {
+                let message = match message {
+                    Some(Ok(Message::Text(text))) => ClientMessage::from_bytes(text),
+                    Some(Ok(Message::Binary(bytes))) => ClientMessage::from_bytes(bytes),
+                    // Pings are answered by the WebSocket itself.
+                    Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+                    Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                };
+                if matches!(message, Ok(ClientMessage::ConnectionInit { .. })) {
+                    initialized = true;
+                }
+                if let Ok(message) = &message {
+                    // Each subscription costs a request, the same as over HTTP.
+                    let rejection = match message {
+                        ClientMessage::Start { id, .. } => limiter
+                            .check()
+                            .map_err(|retry_after| {
+                                subscriptions.rate_limited_message(id, retry_after)
+                            }),
+                        _ => Ok(()),
+                    }
+                    .and_then(|_| subscriptions.on_client_message(message));
+                    if let Err(rejection) = rejection {
+                        if sink.send(Message::Text(rejection)).await.is_err() {
+                            break
+                        }
+                        continue
+                    }
+                }
+                if requests.send(message).is_err() {
+                    break
+                }
+            }
+            response = responses.next() => match response {
+                Some(WsMessage::Text(text)) => {
+                    subscriptions.on_server_message(&text);
+                    if sink.send(Message::Text(text)).await.is_err() {
+                        break
+                    }
+                }
+                Some(WsMessage::Close(code, reason)) => {
+                    let _ = sink.send(close_message(code, reason)).await;
+                    break
+                }
+                None => break,
+            },
+            _ = keep_alive.tick(), if initialized => {
+                if sink.send(keep_alive_message(protocol)).await.is_err() {
+                    break
+                }
+            }
+            _ = &mut init_timeout, if !initialized => {
+                let close = close_message(
+                    CONNECTION_INIT_TIMEOUT_CODE,
+                    "Connection initialisation timeout".to_string(),
+                );
+                let _ = sink.send(close).await;
+                break
+            }
+        }
+    }
+}
+
+fn close_message(code: u16, reason: String) -> Message {
+    Message::Close(Some(CloseFrame {
+        code,
+        reason: reason.into(),
+    }))
+}
+
+fn keep_alive_message(protocol: WebSocketProtocols) -> Message {
+    let message = match protocol {
+        WebSocketProtocols::GraphQLWS => json!({ "type": "ping" }),
+        WebSocketProtocols::SubscriptionsTransportWS => json!({ "type": "ka" }),
+    };
+    Message::Text(message.to_string())
+}
+
+/// The rate limiter of the client that opened the connection.
+struct ClientRateLimiter {
+    rate_limiter: Arc<RateLimiter>,
+    address: IpAddr,
+}
+
+impl ClientRateLimiter {
+    fn check(&self) -> Result<(), Duration> {
+        self.rate_limiter.check(self.address)
+    }
+}
+
+/// Tracks the active subscriptions of the connection to limit their number.
+struct Subscriptions {
+    protocol: WebSocketProtocols,
+    limit: usize,
+    active: HashSet<String>,
+}
+
+impl Subscriptions {
+    fn new(protocol: WebSocketProtocols, limit: usize) -> Self {
+        Self {
+            protocol,
+            limit,
+            active: HashSet::new(),
+        }
+    }
+
+    /// Returns the error message for the client if the message starts a new
+    /// subscription above the limit.
+    fn on_client_message(&mut self, message: &ClientMessage) -> Result<(), String> {
+        match message {
+            ClientMessage::Start { id, .. } => {
+                if !self.active.contains(id) && self.active.len() >= self.limit {
+                    let message = format!(
+                        "The connection can't have more than {} active subscriptions",
+                        self.limit
+                    );
+                    return Err(self.error_message(id, json!({ "message": message })))
+                }
+                self.active.insert(id.clone());
+            }
+            ClientMessage::Stop { id } => {
+                self.active.remove(id);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Forgets the subscriptions completed by the server.
+    fn on_server_message(&mut self, message: &str) {
+        /// The fields of the server message used to track the subscriptions.
+        #[derive(serde::Deserialize)]
+        struct ServerMessage<'a> {
+            #[serde(rename = "type", borrow)]
+            ty: Cow<'a, str>,
+            id: Option<String>,
+        }
+        if let Ok(ServerMessage { ty, id: Some(id) }) = serde_json::from_str(message) {
+            if ty == "complete" {
+                self.active.remove(&id);
+            }
+        }
+    }
+
+    /// Returns the error message for the client that started the subscription too
+    /// often.
+    fn rate_limited_message(&self, id: &str, retry_after: Duration) -> String {
+        let error = serde_json::to_value(rate_limited_error(retry_after))
+            .expect("The GraphQL error is serializable");
+        self.error_message(id, error)
+    }
+
+    fn error_message(&self, id: &str, error: serde_json::Value) -> String {
+        let payload = match self.protocol {
+            WebSocketProtocols::GraphQLWS => json!([error]),
+            WebSocketProtocols::SubscriptionsTransportWS => error,
+        };
+        json!({ "type": "error", "id": id, "payload": payload }).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start(id: &str) -> ClientMessage {
+        ClientMessage::from_bytes(
+            json!({
+                "type": "subscribe",
+                "id": id,
+                "payload": { "query": "subscription { newHeaders { height } }" },
+            })
+            .to_string(),
+        )
+        .unwrap()
+    }
+
+    fn stop(id: &str) -> ClientMessage {
+        ClientMessage::from_bytes(json!({ "type": "complete", "id": id }).to_string())
+            .unwrap()
+    }
+
+    #[test]
+    fn subscriptions_above_the_limit_are_rejected() {
+        let mut subscriptions = Subscriptions::new(WebSocketProtocols::GraphQLWS, 2);
+
+        assert!(subscriptions.on_client_message(&start("1")).is_ok());
+        assert!(subscriptions.on_client_message(&start("2")).is_ok());
+        let rejection = subscriptions.on_client_message(&start("3")).unwrap_err();
+        let rejection: serde_json::Value = serde_json::from_str(&rejection).unwrap();
+        assert_eq!(rejection["type"], "error");
+        assert_eq!(rejection["id"], "3");
+
+        // Stopped by the client.
+        assert!(subscriptions.on_client_message(&stop("1")).is_ok());
+        assert!(subscriptions.on_client_message(&start("3")).is_ok());
+        assert!(subscriptions.on_client_message(&start("4")).is_err());
+
+        // The results of the subscription don't complete it.
+        subscriptions.on_server_message(
+            r#"{"id":"2","type":"next","payload":{"data":{"type":"complete"}}}"#,
+        );
+        assert!(subscriptions.on_client_message(&start("4")).is_err());
+
+        // Completed by the server, regardless of the order of the fields.
+        subscriptions.on_server_message(r#"{ "id": "2", "type": "complete" }"#);
+        assert!(subscriptions.on_client_message(&start("4")).is_ok());
+    }
+}
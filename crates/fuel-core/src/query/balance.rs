@@ -1,9 +1,4 @@
 use crate::fuel_core_graphql_api::service::Database;
-use asset_query::{
-    AssetQuery,
-    AssetSpendTarget,
-    AssetsQuery,
-};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -19,11 +14,6 @@ use fuel_core_types::{
     },
     services::graphql_api::AddressBalance,
 };
-use itertools::Itertools;
-use std::{
-    cmp::Ordering,
-    collections::HashMap,
-};
 
 pub mod asset_query;
 
@@ -44,26 +34,11 @@ impl BalanceQueryData for Database {
         owner: Address,
         asset_id: AssetId,
     ) -> StorageResult<AddressBalance> {
-        let amount = AssetQuery::new(
-            &owner,
-            &AssetSpendTarget::new(asset_id, u64::MAX, u64::MAX),
-            None,
-            self,
-        )
-        .coins()
-        .map(|res| res.map(|coins| coins.amount()))
-        .try_fold(0u64, |mut balance, res| -> StorageResult<_> {
-            let amount = res?;
-
-            // Increase the balance
-            balance += amount;
-
-            Ok(balance)
-        })?;
+        let amount = self.owned_balance(&owner, &asset_id)?;
 
         Ok(AddressBalance {
             owner,
-            amount,
+            amount: saturated_amount(amount),
             asset_id,
         })
     }
@@ -73,46 +48,20 @@ impl BalanceQueryData for Database {
         owner: Address,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<AddressBalance>> {
-        let mut amounts_per_asset = HashMap::new();
-        let mut errors = vec![];
-
-        for coin in AssetsQuery::new(&owner, None, None, self).coins() {
-            match coin {
-                Ok(coin) => {
-                    *amounts_per_asset.entry(*coin.asset_id()).or_default() +=
-                        coin.amount();
-                }
-                Err(err) => {
-                    errors.push(err);
-                }
-            }
-        }
-
-        let mut balances = amounts_per_asset
-            .into_iter()
-            .map(|(asset_id, amount)| AddressBalance {
-                owner,
-                amount,
-                asset_id,
+        self.owned_balances(&owner, direction)
+            .map(move |result| {
+                result.map(|(asset_id, amount)| AddressBalance {
+                    owner,
+                    amount: saturated_amount(amount),
+                    asset_id,
+                })
             })
-            .collect_vec();
-
-        balances.sort_by(|l, r| {
-            if l.asset_id < r.asset_id {
-                Ordering::Less
-            } else {
-                Ordering::Greater
-            }
-        });
-
-        if direction == IterDirection::Reverse {
-            balances.reverse();
-        }
-
-        balances
-            .into_iter()
-            .map(Ok)
-            .chain(errors.into_iter().map(Err))
             .into_boxed()
     }
 }
+
+/// The GraphQL API represents the balance as `u64`, so the larger balances
+/// are reported as `u64::MAX`.
+fn saturated_amount(amount: u128) -> u64 {
+    u64::try_from(amount).unwrap_or(u64::MAX)
+}
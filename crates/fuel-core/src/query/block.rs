@@ -2,7 +2,6 @@ use crate::graphql_api::ports::DatabasePort;
 use fuel_core_storage::{
     iter::{
         BoxedIter,
-        IntoBoxedIter,
         IterDirection,
     },
     not_found,
@@ -23,11 +22,11 @@ use fuel_core_types::blockchain::{
 };
 
 pub trait SimpleBlockData: Send + Sync {
-    fn block(&self, id: &BlockId) -> StorageResult<CompressedBlock>;
+    fn block(&self, id: &BlockHeight) -> StorageResult<CompressedBlock>;
 }
 
 impl<D: DatabasePort + ?Sized> SimpleBlockData for D {
-    fn block(&self, id: &BlockId) -> StorageResult<CompressedBlock> {
+    fn block(&self, id: &BlockHeight) -> StorageResult<CompressedBlock> {
         let block = self
             .storage::<FuelBlocks>()
             .get(id)?
@@ -39,9 +38,7 @@ impl<D: DatabasePort + ?Sized> SimpleBlockData for D {
 }
 
 pub trait BlockQueryData: Send + Sync + SimpleBlockData {
-    fn block_id(&self, height: &BlockHeight) -> StorageResult<BlockId>;
-
-    fn latest_block_id(&self) -> StorageResult<BlockId>;
+    fn block_height(&self, id: &BlockId) -> StorageResult<BlockHeight>;
 
     fn latest_block_height(&self) -> StorageResult<BlockHeight>;
 
@@ -57,20 +54,17 @@ pub trait BlockQueryData: Send + Sync + SimpleBlockData {
 }
 
 impl<D: DatabasePort + ?Sized> BlockQueryData for D {
-    fn block_id(&self, height: &BlockHeight) -> StorageResult<BlockId> {
-        self.block_id(height)
-    }
-
-    fn latest_block_id(&self) -> StorageResult<BlockId> {
-        self.ids_of_latest_block().map(|(_, id)| id)
+    fn block_height(&self, id: &BlockId) -> StorageResult<BlockHeight> {
+        self.block_height(id)
     }
 
     fn latest_block_height(&self) -> StorageResult<BlockHeight> {
-        self.ids_of_latest_block().map(|(height, _)| height)
+        self.latest_height()
     }
 
     fn latest_block(&self) -> StorageResult<CompressedBlock> {
-        self.latest_block_id().and_then(|id| self.block(&id))
+        self.latest_block_height()
+            .and_then(|height| self.block(&height))
     }
 
     fn compressed_blocks(
@@ -78,16 +72,7 @@ impl<D: DatabasePort + ?Sized> BlockQueryData for D {
         start: Option<BlockHeight>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<CompressedBlock>> {
-        self.blocks_ids(start.map(Into::into), direction)
-            .into_iter()
-            .map(|result| {
-                result.and_then(|(_, id)| {
-                    let block = self.block(&id)?;
-
-                    Ok(block)
-                })
-            })
-            .into_boxed()
+        self.blocks(start, direction)
     }
 
     fn consensus(&self, id: &BlockId) -> StorageResult<Consensus> {
@@ -19,6 +19,7 @@ use fuel_core_storage::{
     not_found,
     tables::Messages,
     Error as StorageError,
+    IsNotFound,
     Result as StorageResult,
     StorageAsRef,
 };
@@ -26,7 +27,10 @@ use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
         consensus::Consensus,
-        primitives::BlockId,
+        primitives::{
+            BlockHeight,
+            BlockId,
+        },
     },
     entities::message::{
         Message,
@@ -122,10 +126,14 @@ pub trait MessageProofData:
         &self,
         transaction_id: &TxId,
     ) -> StorageResult<TransactionStatus>;
+    /// Get the height of a fuel block.
+    fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight>;
     /// Get all transactions on a block.
     fn transactions_on_block(&self, block_id: &BlockId) -> StorageResult<Vec<Bytes32>>;
     /// Get the signature of a fuel block.
     fn signature(&self, block_id: &BlockId) -> StorageResult<Signature>;
+    /// Returns `true` if any block was imported without its transactions.
+    fn has_header_only_blocks(&self) -> StorageResult<bool>;
 }
 
 impl<D: DatabasePort + ?Sized> MessageProofData for D {
@@ -136,8 +144,14 @@ impl<D: DatabasePort + ?Sized> MessageProofData for D {
         self.status(transaction_id)
     }
 
+    fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight> {
+        self.block_height(block_id)
+    }
+
     fn transactions_on_block(&self, block_id: &BlockId) -> StorageResult<Vec<TxId>> {
-        self.block(block_id).map(|block| block.into_inner().1)
+        self.block_height(block_id)
+            .and_then(|height| self.block(&height))
+            .map(|block| block.into_inner().1)
     }
 
     fn signature(&self, block_id: &BlockId) -> StorageResult<Signature> {
@@ -148,6 +162,10 @@ impl<D: DatabasePort + ?Sized> MessageProofData for D {
             Consensus::PoA(c) => Ok(c.signature),
         }
     }
+
+    fn has_header_only_blocks(&self) -> StorageResult<bool> {
+        self.has_header_only_blocks()
+    }
 }
 
 /// Generate an output proof.
@@ -157,9 +175,24 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     transaction_id: Bytes32,
     message_id: MessageId,
 ) -> StorageResult<Option<MessageProof>> {
+    // The blocks imported without their transactions don't have the receipts, so the
+    // proof can't be built for their messages.
+    // TODO: Request the transactions and receipts of the block from the peers to build
+    //  the proof. The p2p protocol doesn't serve the receipts yet, and the node doesn't
+    //  know the block of the unknown transaction.
+    let receipts = match data.receipts(&transaction_id) {
+        Err(e) if e.is_not_found() && data.has_header_only_blocks()? => {
+            return Err(StorageError::Other(anyhow::anyhow!(
+                "The message proof is not available on a headers-only node: the \
+                transaction is unknown, and the blocks imported without their \
+                transactions don't have the receipts"
+            )))
+        }
+        result => result?,
+    };
+
     // Check if the receipts for this transaction actually contain this message id or exit.
-    let receipt = data
-        .receipts(&transaction_id)?
+    let receipt = receipts
         .into_iter()
         .find_map(|r| match r {
             Receipt::MessageOut {
@@ -239,7 +272,8 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
 
             // Get the fuel block.
             let header = match data
-                .block(&block_id)
+                .block_height(&block_id)
+                .and_then(|height| data.block(&height))
                 .into_api_result::<CompressedBlock, StorageError>()?
             {
                 Some(t) => t.into_inner().0,
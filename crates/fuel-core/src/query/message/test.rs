@@ -55,7 +55,7 @@ fn receipt(i: Option<u8>) -> Receipt {
 mockall::mock! {
     pub ProofDataStorage {}
     impl SimpleBlockData for ProofDataStorage{
-        fn block(&self, block_id: &BlockId) -> StorageResult<CompressedBlock>;
+        fn block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock>;
     }
 
     impl SimpleTransactionData for ProofDataStorage{
@@ -65,8 +65,10 @@ mockall::mock! {
 
     impl MessageProofData for ProofDataStorage {
         fn transaction_status(&self, transaction_id: &TxId) -> StorageResult<TransactionStatus>;
+        fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight>;
         fn transactions_on_block(&self, block_id: &BlockId) -> StorageResult<Vec<Bytes32>>;
         fn signature(&self, block_id: &BlockId) -> StorageResult<Signature>;
+        fn has_header_only_blocks(&self) -> StorageResult<bool>;
     }
 }
 
@@ -145,9 +147,14 @@ async fn can_build_message_proof() {
             generated: Default::default(),
         },
     };
-    data.expect_block()
+    data.expect_block_height()
         .once()
         .with(eq(BlockId::default()))
+        .returning(|_| Ok(1u32.into()));
+
+    data.expect_block()
+        .once()
+        .with(eq(BlockHeight::from(1u32)))
         .returning({
             let header = header.clone();
             let message_ids = message_ids.clone();
@@ -168,3 +175,17 @@ async fn can_build_message_proof() {
     let header = header.generate(&[], &message_ids);
     assert_eq!(p.header.message_receipt_root, header.message_receipt_root);
 }
+
+#[test]
+fn message_proof_is_not_available_on_headers_only_node() {
+    let mut data = MockProofDataStorage::new();
+    data.expect_receipts()
+        .returning(|_| Err(not_found!("Receipts")));
+    data.expect_has_header_only_blocks().returning(|| Ok(true));
+
+    let data: Box<dyn MessageProofData> = Box::new(data);
+
+    let result = message_proof(data.deref(), txn_id(1), MessageId::default());
+    let err = result.err().expect("The proof can't be built without the receipts");
+    assert!(err.to_string().contains("headers-only"), "{err}");
+}
@@ -1,16 +1,30 @@
-use crate::schema::tx::types::{
-    SqueezedOutStatus,
-    TransactionStatus,
+use crate::{
+    query::SimpleBlockData,
+    schema::tx::types::{
+        SqueezedOutStatus,
+        TransactionStatus,
+    },
+};
+use fuel_core_storage::{
+    IsNotFound,
+    Result as StorageResult,
 };
-use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::service::TxUpdate;
-use fuel_core_types::fuel_types::Bytes32;
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        primitives::BlockHeight,
+    },
+    fuel_types::Bytes32,
+    services::block_importer::ImportResult,
+};
 use futures::{
     stream::BoxStream,
     Stream,
     StreamExt,
     TryStreamExt,
 };
+use std::sync::Arc;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 use tracing::Instrument;
 
@@ -132,3 +146,61 @@ where
                 status
             })
 }
+
+/// Returns the stream of blocks in the order of their heights. Without the
+/// `start_height`, the stream begins from the next imported block.
+///
+/// Blocks that are already in the `database` are loaded from it first, and then
+/// the stream switches to the imported `blocks`. The importer commits the block
+/// before notifying about it, so if some notifications are missed (the subscriber
+/// lagged or subscribed too late), missing blocks are loaded from the `database`.
+pub(crate) fn new_blocks<'a, D>(
+    database: &'a D,
+    blocks: BoxStream<'a, Arc<ImportResult>>,
+    start_height: Option<BlockHeight>,
+) -> impl Stream<Item = anyhow::Result<CompressedBlock>> + 'a
+where
+    D: SimpleBlockData + ?Sized,
+{
+    let state = NewBlocksState {
+        next_height: start_height,
+        catching_up: start_height.is_some(),
+        blocks,
+    };
+    futures::stream::unfold(state, move |mut state| async move {
+        loop {
+            if let (true, Some(height)) = (state.catching_up, state.next_height) {
+                match database.block(&height) {
+                    Ok(block) => {
+                        state.next_height = Some((*height + 1).into());
+                        return Some((Ok(block), state))
+                    }
+                    Err(err) if err.is_not_found() => state.catching_up = false,
+                    Err(err) => return Some((Err(err.into()), state)),
+                }
+            }
+
+            let result = state.blocks.next().await?;
+            let block = result.sealed_block.entity.compress();
+            let height = *block.header().height();
+            match state.next_height {
+                // The block was already loaded from the database.
+                Some(next_height) if height < next_height => continue,
+                // Some blocks were missed, load them from the database.
+                Some(next_height) if height > next_height => state.catching_up = true,
+                _ => {
+                    state.next_height = Some((*height + 1).into());
+                    return Some((Ok(block), state))
+                }
+            }
+        }
+    })
+}
+
+struct NewBlocksState<'a> {
+    /// The height of the next block in the stream, if known.
+    next_height: Option<BlockHeight>,
+    /// `true` while blocks are loaded from the database.
+    catching_up: bool,
+    blocks: BoxStream<'a, Arc<ImportResult>>,
+}
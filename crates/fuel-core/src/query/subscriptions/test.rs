@@ -263,3 +263,55 @@ where
         Err(_) => Expected::Error,
     }
 }
+
+struct Blocks(Vec<u32>);
+
+impl SimpleBlockData for Blocks {
+    fn block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock> {
+        self.0
+            .iter()
+            .find(|h| **h == **height)
+            .map(|h| block(*h).compress())
+            .ok_or(fuel_core_storage::not_found!(
+                fuel_core_storage::tables::FuelBlocks
+            ))
+    }
+}
+
+fn block(height: u32) -> fuel_core_types::blockchain::block::Block {
+    let mut block = fuel_core_types::blockchain::block::Block::default();
+    block.header_mut().consensus.height = height.into();
+    block
+}
+
+fn imported(height: u32) -> Arc<ImportResult> {
+    Arc::new(ImportResult {
+        sealed_block: fuel_core_types::blockchain::SealedBlock {
+            entity: block(height),
+            consensus: Default::default(),
+        },
+        tx_status: vec![],
+    })
+}
+
+#[test_case(vec![], vec![1, 2], None => vec![1, 2]; "without start height only imported blocks")]
+#[test_case(vec![1, 2, 3], vec![3, 4], Some(2) => vec![2, 3, 4]; "blocks from the database then imported blocks")]
+#[test_case(vec![1, 2, 3, 4, 5], vec![1, 4], None => vec![1, 2, 3, 4, 5]; "missed blocks loaded from the database")]
+#[test_case(vec![1, 2, 3], vec![1, 2, 3], Some(5) => Vec::<u32>::new(); "start height above the latest block")]
+#[tokio::test]
+async fn create_new_blocks_stream(
+    database: Vec<u32>,
+    imported_blocks: Vec<u32>,
+    start_height: Option<u32>,
+) -> Vec<u32> {
+    let database = Blocks(database);
+    let imported_blocks =
+        futures::stream::iter(imported_blocks.into_iter().map(imported)).boxed();
+
+    let stream = new_blocks(&database, imported_blocks, start_height.map(Into::into));
+    let blocks: Vec<_> = stream.try_collect().await.unwrap();
+    blocks
+        .into_iter()
+        .map(|block| **block.header().height())
+        .collect()
+}
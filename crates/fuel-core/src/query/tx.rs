@@ -10,18 +10,27 @@ use fuel_core_storage::{
         Receipts,
         Transactions,
     },
+    Error as StorageError,
+    IsNotFound,
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
     fuel_tx::{
         Receipt,
         Transaction,
         TxPointer,
     },
-    fuel_types::Address,
-    services::txpool::TransactionStatus,
+    fuel_types::{
+        Address,
+        AssetId,
+    },
+    services::{
+        graphql_api::OwnedTransaction,
+        txpool::TransactionStatus,
+    },
 };
 
 pub trait SimpleTransactionData: Send + Sync {
@@ -34,15 +43,19 @@ pub trait SimpleTransactionData: Send + Sync {
 
 impl<D: DatabasePort + ?Sized> SimpleTransactionData for D {
     fn transaction(&self, tx_id: &TxId) -> StorageResult<Transaction> {
-        self.storage::<Transactions>()
+        let transaction = self
+            .storage::<Transactions>()
             .get(tx_id)
-            .and_then(|v| v.ok_or(not_found!(Transactions)).map(|tx| tx.into_owned()))
+            .and_then(|v| v.ok_or(not_found!(Transactions)).map(|tx| tx.into_owned()));
+        not_found_or_pruned(self, transaction)
     }
 
     fn receipts(&self, tx_id: &TxId) -> StorageResult<Vec<Receipt>> {
-        self.storage::<Receipts>()
+        let receipts = self
+            .storage::<Receipts>()
             .get(tx_id)
-            .and_then(|v| v.ok_or(not_found!(Transactions)).map(|tx| tx.into_owned()))
+            .and_then(|v| v.ok_or(not_found!(Transactions)).map(|tx| tx.into_owned()));
+        not_found_or_pruned(self, receipts)
     }
 }
 
@@ -55,11 +68,103 @@ pub trait TransactionQueryData: Send + Sync + SimpleTransactionData {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
+
+    /// Returns the scanned transactions of the `owner` with the changes of the owner's
+    /// balances. The transactions not matching the `filter` are `None`, so the caller
+    /// can limit the number of the scanned entries.
+    fn transaction_history(
+        &self,
+        owner: Address,
+        filter: TransactionHistoryFilter,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<TransactionHistoryEntry>>;
+}
+
+/// The scanned transaction of the owner with the changes of the owner's balances,
+/// `None` if it doesn't match the filter.
+pub type TransactionHistoryEntry = (TxPointer, Option<(OwnedTransaction, Transaction)>);
+
+/// The direction of the transfer relative to the owner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferDirection {
+    /// The owner's balance was increased.
+    In,
+    /// The owner's balance was decreased.
+    Out,
+}
+
+/// The filter of the transactions of the owner. Empty fields match any transaction.
+#[derive(Clone, Debug, Default)]
+pub struct TransactionHistoryFilter {
+    /// Only transactions changing the balance of the asset.
+    pub asset_id: Option<AssetId>,
+    /// Only transactions increasing or decreasing the balance of the owner. With the
+    /// `asset_id`, only the balance of this asset is considered.
+    pub direction: Option<TransferDirection>,
+    /// Only reverted(`true`) or successful(`false`) transactions.
+    pub reverted: Option<bool>,
+    /// The lowest height of the block with the transaction, inclusive.
+    pub from_height: Option<BlockHeight>,
+    /// The highest height of the block with the transaction, inclusive.
+    pub to_height: Option<BlockHeight>,
+}
+
+impl TransactionHistoryFilter {
+    /// Returns `true` if the transaction from the block at the `height` matches the filter.
+    pub fn matches(&self, height: BlockHeight, owned: &OwnedTransaction) -> bool {
+        if !self.contains_height(height) {
+            return false
+        }
+        if matches!(self.reverted, Some(reverted) if reverted != owned.reverted) {
+            return false
+        }
+        let mut deltas = owned.deltas.iter().filter(|delta| {
+            self.asset_id
+                .map(|asset_id| asset_id == delta.asset_id)
+                .unwrap_or(true)
+        });
+        match self.direction {
+            Some(TransferDirection::In) => deltas.any(|delta| delta.net() > 0),
+            Some(TransferDirection::Out) => deltas.any(|delta| delta.net() < 0),
+            None => self.asset_id.is_none() || deltas.next().is_some(),
+        }
+    }
+
+    fn contains_height(&self, height: BlockHeight) -> bool {
+        self.from_height.map(|from| from <= height).unwrap_or(true)
+            && self.to_height.map(|to| height <= to).unwrap_or(true)
+    }
+
+    /// Returns `true` if the iteration in the `direction` reached the `height`
+    /// after which no transaction can match the filter.
+    fn is_past(&self, height: BlockHeight, direction: IterDirection) -> bool {
+        match direction {
+            IterDirection::Forward => self.to_height.map(|to| height > to),
+            IterDirection::Reverse => self.from_height.map(|from| height < from),
+        }
+        .unwrap_or(false)
+    }
+
+    /// The first position of the iteration in the `direction`, if it is not
+    /// defined by the pagination.
+    fn first_position(&self, direction: IterDirection) -> Option<TxPointer> {
+        match direction {
+            IterDirection::Forward => self
+                .from_height
+                .map(|height| TxPointer::new(height.into(), 0)),
+            IterDirection::Reverse => {
+                // The reverse iteration over the prefix requires the start key.
+                let height = self.to_height.map(u32::from).unwrap_or(u32::MAX);
+                Some(TxPointer::new(height, u16::MAX))
+            }
+        }
+    }
 }
 
 impl<D: DatabasePort + ?Sized> TransactionQueryData for D {
     fn status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
-        self.tx_status(tx_id)
+        not_found_or_pruned(self, self.tx_status(tx_id))
     }
 
     fn owned_transactions(
@@ -68,14 +173,62 @@ impl<D: DatabasePort + ?Sized> TransactionQueryData for D {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
-        self.owned_transactions_ids(owner, start, direction)
+        self.owned_transaction_entries(owner, start, direction)
             .map(|result| {
-                result.and_then(|(tx_pointer, tx_id)| {
-                    let tx = self.transaction(&tx_id)?;
+                result.and_then(|(tx_pointer, owned)| {
+                    let tx = self.transaction(&owned.tx_id)?;
 
                     Ok((tx_pointer, tx))
                 })
             })
             .into_boxed()
     }
+
+    fn transaction_history(
+        &self,
+        owner: Address,
+        filter: TransactionHistoryFilter,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<TransactionHistoryEntry>> {
+        let start = start.or_else(|| filter.first_position(direction));
+        let range = filter.clone();
+        self.owned_transaction_entries(owner, start, direction)
+            .take_while(move |result| match result {
+                Ok((tx_pointer, _)) => {
+                    !range.is_past(tx_pointer.block_height().into(), direction)
+                }
+                Err(_) => true,
+            })
+            .map(move |result| {
+                result.and_then(|(tx_pointer, owned)| {
+                    if !filter.matches(tx_pointer.block_height().into(), &owned) {
+                        return Ok((tx_pointer, None))
+                    }
+                    let tx = self.transaction(&owned.tx_id)?;
+
+                    Ok((tx_pointer, Some((owned, tx))))
+                })
+            })
+            .into_boxed()
+    }
+}
+
+/// Replaces the "not found" error with the error about the pruned history if the node
+/// pruned any transactions. Nothing is kept per pruned transaction, so the missing
+/// transaction may be either unknown or pruned.
+fn not_found_or_pruned<D, T>(database: &D, result: StorageResult<T>) -> StorageResult<T>
+where
+    D: DatabasePort + ?Sized,
+{
+    if result.is_not_found() {
+        if let Some(height) = database.pruned_transactions_height()? {
+            return Err(StorageError::Other(anyhow::anyhow!(
+                "The transaction is unknown or its data is pruned: the node keeps \
+                transactions, receipts and statuses only after the block height {}",
+                u32::from(height)
+            )))
+        }
+    }
+    result
 }
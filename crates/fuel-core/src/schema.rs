@@ -1,3 +1,4 @@
+use crate::fuel_core_graphql_api::Config as GraphQLConfig;
 use anyhow::anyhow;
 use async_graphql::{
     connection::{
@@ -7,6 +8,8 @@ use async_graphql::{
         Edge,
         EmptyFields,
     },
+    Context,
+    ErrorExtensions,
     MergedObject,
     MergedSubscription,
     OutputType,
@@ -19,12 +22,14 @@ use fuel_core_storage::{
 };
 use itertools::Itertools;
 
+pub mod admin;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
 pub mod dap;
+pub mod gas;
 pub mod health;
 pub mod message;
 pub mod node_info;
@@ -38,6 +43,7 @@ pub struct Query(
     block::BlockQuery,
     chain::ChainQuery,
     tx::TxQuery,
+    gas::GasQuery,
     health::HealthQuery,
     coins::CoinQuery,
     contract::ContractQuery,
@@ -47,10 +53,15 @@ pub struct Query(
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    admin::AdminMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(tx::TxStatusSubscription, block::BlockSubscription);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -64,7 +75,30 @@ pub fn build_schema() -> CoreSchemaBuilder {
     )
 }
 
+/// The estimated number of elements of the list fields loaded from the database,
+/// like the transactions of the block or the receipts of the transaction.
+pub(crate) const LIST_SIZE_ESTIMATE: usize = 10;
+
+/// The complexity of the list field loaded from the database is the complexity of one
+/// element multiplied by [`LIST_SIZE_ESTIMATE`], because the real size of the list is
+/// unknown before the execution.
+pub(crate) fn list_complexity(child_complexity: usize) -> usize {
+    child_complexity.saturating_mul(LIST_SIZE_ESTIMATE)
+}
+
+/// The complexity of the paginated connection is the complexity of one element
+/// multiplied by the requested page size.
+pub(crate) fn connection_complexity(
+    child_complexity: usize,
+    first: Option<i32>,
+    last: Option<i32>,
+) -> usize {
+    let page_size = first.or(last).unwrap_or_default().max(1) as usize;
+    child_complexity.saturating_mul(page_size)
+}
+
 async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
+    ctx: &Context<'_>,
     after: Option<String>,
     before: Option<String>,
     first: Option<i32>,
@@ -107,6 +141,21 @@ where
         (_, _, _, _) => { /* Other combinations are allowed */ }
     };
 
+    let max_page_size = ctx
+        .data_unchecked::<GraphQLConfig>()
+        .query_limits
+        .max_page_size;
+    let page_size = first.or(last).unwrap_or_default();
+    if page_size > 0 && page_size as usize > max_page_size {
+        return Err(async_graphql::Error::new(format!(
+            "The page size `{page_size}` exceeds the maximum `{max_page_size}`"
+        ))
+        .extend_with(|_, extensions| {
+            extensions.set("code", "PAGE_SIZE_LIMIT");
+            extensions.set("maxPageSize", max_page_size);
+        }))
+    }
+
     query(
         after,
         before,
@@ -0,0 +1,53 @@
+use crate::fuel_core_graphql_api::{
+    auth::AdminGuard,
+    service::Database,
+    Config as GraphQLConfig,
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    Object,
+};
+use std::path::{
+    Component,
+    Path,
+};
+
+#[derive(Default)]
+pub struct AdminMutation;
+
+#[Object]
+impl AdminMutation {
+    /// Creates a consistent backup of the database in the `name` directory inside of
+    /// the backup directory of the node. The node keeps processing blocks and requests
+    /// during the backup. Returns the path to the created backup.
+    #[graphql(guard = "AdminGuard")]
+    async fn backup_database(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The name of the new directory for the backup")] name: String,
+    ) -> async_graphql::Result<String> {
+        let query: &Database = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        let backup_dir = config.backup_dir.as_ref().ok_or_else(|| {
+            anyhow!("The backup directory must be set to use this endpoint")
+        })?;
+
+        let mut components = Path::new(&name).components();
+        if !matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        ) {
+            return Err(anyhow!(
+                "The name of the backup `{name}` must be a name of the directory, not a path"
+            )
+            .into())
+        }
+
+        let path = backup_dir.join(&name);
+        query.backup(path.clone()).await?;
+
+        Ok(path.display().to_string())
+    }
+}
@@ -62,6 +62,9 @@ impl BalanceQuery {
     //  It should be replaced with `UtxoId`.
     //  This API should be migrated to the indexer for better support and
     //  discontinued within fuel-core.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn balances(
         &self,
         ctx: &Context<'_>,
@@ -76,12 +79,19 @@ impl BalanceQuery {
             return Err(anyhow!("pagination is not yet supported").into())
         }
         let query: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |_, direction| {
-            let owner = filter.owner.into();
-            Ok(query.balances(owner, direction).map(|result| {
-                result.map(|balance| (balance.asset_id.into(), balance.into()))
-            }))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |_, direction| {
+                let owner = filter.owner.into();
+                Ok(query.balances(owner, direction).map(|result| {
+                    result.map(|balance| (balance.asset_id.into(), balance.into()))
+                }))
+            },
+        )
         .await
     }
 }
@@ -4,7 +4,9 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
+        auth::AdminGuard,
         service::{
+            BlockImporter,
             ConsensusModule,
             Database,
         },
@@ -12,6 +14,7 @@ use crate::{
     },
     graphql_api::IntoApiResult,
     query::{
+        new_blocks,
         BlockQueryData,
         SimpleBlockData,
         SimpleTransactionData,
@@ -34,6 +37,7 @@ use async_graphql::{
     Context,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
 use fuel_core_storage::{
@@ -51,6 +55,10 @@ use fuel_core_types::{
     },
     fuel_types,
 };
+use futures::{
+    Stream,
+    TryStreamExt,
+};
 
 pub struct Block(pub(crate) CompressedBlock);
 
@@ -76,6 +84,9 @@ pub struct Genesis {
     pub contracts_root: Bytes32,
     /// The Binary Merkle Tree root of all genesis messages.
     pub messages_root: Bytes32,
+    /// The id of the last block of the previous chain, if the network was started
+    /// from its snapshot.
+    pub previous_chain_block_id: Option<BlockId>,
 }
 
 pub struct PoAConsensus {
@@ -101,11 +112,27 @@ impl Block {
         Ok(consensus.into())
     }
 
+    #[graphql(complexity = "crate::schema::list_complexity(child_complexity)")]
     async fn transactions(
         &self,
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Vec<Transaction>> {
         let query: &Database = ctx.data_unchecked();
+        let height = self.0.header().height();
+        if query.is_header_only(height)? {
+            return Err(anyhow!(
+                "The block {} was imported without its transactions by the headers-only sync",
+                u32::from(*height)
+            )
+            .into())
+        }
+        if query.is_pruned(height)? {
+            return Err(anyhow!(
+                "The transactions of the block {} are pruned",
+                u32::from(*height)
+            )
+            .into())
+        }
         self.0
             .transactions()
             .iter()
@@ -191,26 +218,31 @@ impl BlockQuery {
         #[graphql(desc = "Height of the block")] height: Option<U64>,
     ) -> async_graphql::Result<Option<Block>> {
         let data: &Database = ctx.data_unchecked();
-        let id = match (id, height) {
+        let height = match (id, height) {
             (Some(_), Some(_)) => {
                 return Err(async_graphql::Error::new(
                     "Can't provide both an id and a height",
                 ))
             }
-            (Some(id), None) => Ok(id.0.into()),
+            (Some(id), None) => data.block_height(&id.0.into()),
             (None, Some(height)) => {
                 let height: u64 = height.into();
                 let height: u32 = height.try_into()?;
-                data.block_id(&height.into())
+                Ok(height.into())
             }
             (None, None) => {
                 return Err(async_graphql::Error::new("Missing either id or height"))
             }
         };
 
-        id.and_then(|id| data.block(&id)).into_api_result()
+        height
+            .and_then(|height| data.block(&height))
+            .into_api_result()
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn blocks(
         &self,
         ctx: &Context<'_>,
@@ -220,9 +252,14 @@ impl BlockQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<usize, Block, EmptyFields, EmptyFields>> {
         let db: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(db, *start, direction))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| Ok(blocks_query(db, *start, direction)),
+        )
         .await
     }
 }
@@ -244,6 +281,9 @@ impl HeaderQuery {
             .map(|b| b.0.header().clone().into()))
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn headers(
         &self,
         ctx: &Context<'_>,
@@ -253,9 +293,14 @@ impl HeaderQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<usize, Header, EmptyFields, EmptyFields>> {
         let db: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(db, *start, direction))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| Ok(blocks_query(db, *start, direction)),
+        )
         .await
     }
 }
@@ -278,6 +323,60 @@ where
     blocks.into_boxed()
 }
 
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of blocks in the order of their heights.
+    ///
+    /// If `start_height` is set, the stream begins with the blocks from the database
+    /// starting at this height and then continues with the new blocks. It allows the
+    /// reconnected client to resume from the next height after the last received block.
+    /// Otherwise, the stream begins with the next imported block.
+    async fn new_blocks<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The height of the first block in the stream")]
+        start_height: Option<U64>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Block>> + 'a>
+    {
+        new_blocks_stream(ctx, start_height)
+    }
+
+    /// Returns a stream of block headers in the order of their heights. The same as
+    /// `newBlocks`, but for headers.
+    async fn new_headers<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The height of the first header in the stream")]
+        start_height: Option<U64>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Header>> + 'a>
+    {
+        new_blocks_stream(ctx, start_height)
+    }
+}
+
+fn new_blocks_stream<'a, T>(
+    ctx: &Context<'a>,
+    start_height: Option<U64>,
+) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<T>> + 'a>
+where
+    T: From<CompressedBlock> + 'a,
+{
+    let db: &Database = ctx.data_unchecked();
+    let block_importer = ctx.data_unchecked::<BlockImporter>();
+    let start_height = start_height
+        .map(|height| u32::try_from(u64::from(height)))
+        .transpose()?
+        .map(Into::into);
+
+    let blocks = new_blocks(db.as_ref(), block_importer.block_events(), start_height)
+        .map_ok(Into::into)
+        .map_err(async_graphql::Error::from);
+    Ok(blocks)
+}
+
 #[derive(Default)]
 pub struct BlockMutation;
 
@@ -287,6 +386,7 @@ impl BlockMutation {
     /// `start_timestamp`. If the block production in the [`crate::service::Config`] is
     /// `Trigger::Interval { block_time }`, produces blocks with `block_time ` intervals between
     /// them. The `start_timestamp` is the timestamp in seconds.
+    #[graphql(guard = "AdminGuard")]
     async fn produce_blocks(
         &self,
         ctx: &Context<'_>,
@@ -341,6 +441,9 @@ impl From<CoreGenesis> for Genesis {
             coins_root: genesis.coins_root.into(),
             contracts_root: genesis.contracts_root.into(),
             messages_root: genesis.messages_root.into(),
+            previous_chain_block_id: genesis
+                .previous_chain_block_id
+                .map(|id| fuel_types::Bytes32::from(id).into()),
         }
     }
 }
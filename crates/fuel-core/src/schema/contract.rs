@@ -125,6 +125,9 @@ impl ContractBalanceQuery {
             })
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn contract_balances(
         &self,
         ctx: &Context<'_>,
@@ -145,22 +148,29 @@ impl ContractBalanceQuery {
             )
         }
 
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let balances = query
-                .contract_balances(
-                    filter.contract.into(),
-                    (*start).map(Into::into),
-                    direction,
-                )
-                .map(move |balance| {
-                    let balance = balance?;
-                    let asset_id = balance.asset_id;
-
-                    Ok((asset_id.into(), balance.into()))
-                });
-
-            Ok(balances)
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let balances = query
+                    .contract_balances(
+                        filter.contract.into(),
+                        (*start).map(Into::into),
+                        direction,
+                    )
+                    .map(move |balance| {
+                        let balance = balance?;
+                        let asset_id = balance.asset_id;
+
+                        Ok((asset_id.into(), balance.into()))
+                    });
+
+                Ok(balances)
+            },
+        )
         .await
     }
 }
@@ -4,6 +4,7 @@ use crate::{
         vm_database::VmDatabase,
         Database,
     },
+    fuel_core_graphql_api::auth::AdminGuard,
     schema::scalars::U64,
 };
 use async_graphql::{
@@ -196,6 +197,7 @@ pub fn init<Q, M, S>(
 
 #[Object]
 impl DapQuery {
+    #[graphql(guard = "AdminGuard")]
     async fn register(
         &self,
         ctx: &Context<'_>,
@@ -210,6 +212,7 @@ impl DapQuery {
             .map(|val| val.into())
     }
 
+    #[graphql(guard = "AdminGuard")]
     async fn memory(
         &self,
         ctx: &Context<'_>,
@@ -228,6 +231,7 @@ impl DapQuery {
 
 #[Object]
 impl DapMutation {
+    #[graphql(guard = "AdminGuard")]
     async fn start_session(&self, ctx: &Context<'_>) -> async_graphql::Result<ID> {
         trace!("Initializing new interpreter");
 
@@ -244,6 +248,7 @@ impl DapMutation {
         Ok(id)
     }
 
+    #[graphql(guard = "AdminGuard")]
     async fn end_session(&self, ctx: &Context<'_>, id: ID) -> bool {
         let existed = ctx.data_unchecked::<GraphStorage>().lock().await.kill(&id);
 
@@ -252,6 +257,7 @@ impl DapMutation {
         existed
     }
 
+    #[graphql(guard = "AdminGuard")]
     async fn reset(&self, ctx: &Context<'_>, id: ID) -> async_graphql::Result<bool> {
         let db = ctx.data_unchecked::<Database>();
 
@@ -265,6 +271,7 @@ impl DapMutation {
         Ok(true)
     }
 
+    #[graphql(guard = "AdminGuard")]
     async fn execute(
         &self,
         ctx: &Context<'_>,
@@ -286,6 +293,7 @@ impl DapMutation {
     }
 
     #[cfg(not(feature = "debug"))]
+    #[graphql(guard = "AdminGuard")]
     async fn set_single_stepping(
         &self,
         _ctx: &Context<'_>,
@@ -298,6 +306,7 @@ impl DapMutation {
     }
 
     #[cfg(feature = "debug")]
+    #[graphql(guard = "AdminGuard")]
     async fn set_single_stepping(
         &self,
         ctx: &Context<'_>,
@@ -317,6 +326,7 @@ impl DapMutation {
     }
 
     #[cfg(not(feature = "debug"))]
+    #[graphql(guard = "AdminGuard")]
     async fn set_breakpoint(
         &self,
         _ctx: &Context<'_>,
@@ -329,6 +339,7 @@ impl DapMutation {
     }
 
     #[cfg(feature = "debug")]
+    #[graphql(guard = "AdminGuard")]
     async fn set_breakpoint(
         &self,
         ctx: &Context<'_>,
@@ -347,6 +358,7 @@ impl DapMutation {
         Ok(true)
     }
 
+    #[graphql(guard = "AdminGuard")]
     async fn start_tx(
         &self,
         ctx: &Context<'_>,
@@ -431,6 +443,7 @@ impl DapMutation {
     }
 
     #[cfg(not(feature = "debug"))]
+    #[graphql(guard = "AdminGuard")]
     async fn continue_tx(
         &self,
         _ctx: &Context<'_>,
@@ -442,6 +455,7 @@ impl DapMutation {
     }
 
     #[cfg(feature = "debug")]
+    #[graphql(guard = "AdminGuard")]
     async fn continue_tx(
         &self,
         ctx: &Context<'_>,
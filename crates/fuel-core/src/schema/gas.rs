@@ -0,0 +1,236 @@
+use crate::{
+    fuel_core_graphql_api::{
+        auth::AdminGuard,
+        service::{
+            BlockProducer,
+            Database,
+        },
+        Config as GraphQLConfig,
+    },
+    query::BlockQueryData,
+    schema::scalars::{
+        HexString,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+    SimpleObject,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        field::{
+            GasLimit,
+            GasPrice,
+        },
+        Cacheable,
+        Chargeable,
+        Receipt,
+        ScriptExecutionResult,
+        Transaction as FuelTx,
+        TransactionFee,
+    },
+    fuel_types::bytes::Deserializable,
+    fuel_vm::{
+        checked_transaction::{
+            CheckedTransaction,
+            IntoChecked,
+        },
+        Interpreter,
+        PredicateStorage,
+    },
+};
+
+#[derive(Default)]
+pub struct GasQuery;
+
+#[Object]
+impl GasQuery {
+    /// Estimate the gas required by the script or create transaction.
+    ///
+    /// The transaction is dry-run against the latest state with input utxo validation
+    /// disabled, so its gas limit, gas price and witnesses don't need to be final.
+    #[graphql(guard = "AdminGuard")]
+    async fn estimate_gas(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<GasEstimation> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let params = &config.transaction_parameters;
+
+        let tx = FuelTx::from_bytes(&tx.0)?;
+        let predicate_gas_used = predicate_gas_used(ctx, tx.clone())?;
+
+        let max_gas = params.max_gas_per_tx;
+        let script_gas_used = match dry_run(block_producer, &tx, max_gas).await? {
+            Some(gas_used) => gas_used,
+            None => {
+                return Err(anyhow::anyhow!(
+                    "The transaction fails with the maximum gas limit {max_gas}"
+                )
+                .into())
+            }
+        };
+
+        // The gas consumed by the execution isn't always enough to pass the execution,
+        // because some instructions require the gas to be available before the call.
+        // Search for the smallest gas limit that still succeeds between the used gas
+        // and the maximum.
+        let script_gas_limit = if dry_run(block_producer, &tx, script_gas_used)
+            .await?
+            .is_some()
+        {
+            script_gas_used
+        } else {
+            let (mut failed, mut succeeded) = (script_gas_used, max_gas);
+            while succeeded - failed > 1 {
+                let middle = failed + (succeeded - failed) / 2;
+                if dry_run(block_producer, &tx, middle).await?.is_some() {
+                    succeeded = middle;
+                } else {
+                    failed = middle;
+                }
+            }
+            succeeded
+        };
+
+        // Predicates and the script share the gas limit of the transaction.
+        let gas_limit = script_gas_limit.saturating_add(predicate_gas_used);
+        let gas_price = gas_price(&tx).max(config.min_gas_price);
+        let total_fee = TransactionFee::checked_from_values(
+            params,
+            metered_bytes_size(&tx) as u64,
+            gas_limit,
+            gas_price,
+        )
+        .ok_or_else(|| anyhow::anyhow!("The transaction fee overflows"))?
+        .total();
+
+        Ok(GasEstimation {
+            script_gas_used: script_gas_used.into(),
+            predicate_gas_used: predicate_gas_used.into(),
+            gas_limit: gas_limit.into(),
+            gas_price: gas_price.into(),
+            total_fee: total_fee.into(),
+        })
+    }
+
+    /// Estimate the gas used by all predicates of the transaction.
+    #[graphql(guard = "AdminGuard")]
+    async fn estimate_predicates(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<U64> {
+        let tx = FuelTx::from_bytes(&tx.0)?;
+        Ok(predicate_gas_used(ctx, tx)?.into())
+    }
+}
+
+/// The result of the gas estimation of the transaction.
+#[derive(SimpleObject)]
+pub struct GasEstimation {
+    /// The gas used by the execution of the script. Zero for create transactions.
+    script_gas_used: U64,
+    /// The gas used by the predicates of the transaction.
+    predicate_gas_used: U64,
+    /// The recommended gas limit of the transaction.
+    gas_limit: U64,
+    /// The gas price used to calculate the fee. The price of the transaction if it is
+    /// not lower than the minimal gas price of the node.
+    gas_price: U64,
+    /// The maximum fee paid by the transaction with the recommended gas limit.
+    total_fee: U64,
+}
+
+/// Runs the predicates of the `tx` with the maximum gas limit and returns the consumed gas.
+fn predicate_gas_used(ctx: &Context<'_>, tx: FuelTx) -> anyhow::Result<u64> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    let params = config.transaction_parameters;
+    let height = ctx.data_unchecked::<Database>().latest_block_height()?;
+
+    let tx = with_gas(tx, params.max_gas_per_tx)?;
+    let checked = tx.into_checked_basic(u64::from(height), &params)?;
+    let gas_costs = config.gas_costs.clone();
+    let checked = match CheckedTransaction::from(checked) {
+        CheckedTransaction::Script(script) => {
+            Interpreter::<PredicateStorage>::check_predicates(script, params, gas_costs)
+        }
+        CheckedTransaction::Create(create) => {
+            Interpreter::<PredicateStorage>::check_predicates(create, params, gas_costs)
+        }
+        CheckedTransaction::Mint(_) => {
+            unreachable!("`with_gas` rejects mint transactions")
+        }
+    }
+    .map_err(|e| anyhow::anyhow!("The predicates verification failed: {e:?}"))?;
+
+    Ok(checked.gas_used())
+}
+
+/// Dry-runs the `tx` with the `gas_limit`. Returns the gas used by the script if the
+/// execution succeeds and `None` if the script reverts or runs out of gas. The invalid
+/// transaction or the failure of the node is an error.
+async fn dry_run(
+    block_producer: &BlockProducer,
+    tx: &FuelTx,
+    gas_limit: u64,
+) -> anyhow::Result<Option<u64>> {
+    let tx = with_gas(tx.clone(), gas_limit)?;
+    let is_script = matches!(tx, FuelTx::Script(_));
+    let receipts = block_producer.dry_run_tx(tx, None, Some(false)).await?;
+
+    if !is_script {
+        return Ok(Some(0))
+    }
+
+    let gas_used = receipts.iter().find_map(|receipt| match receipt {
+        Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            gas_used,
+        } => Some(*gas_used),
+        _ => None,
+    });
+    Ok(gas_used)
+}
+
+/// Replaces the gas limit of the `tx`. The gas price is reset to not fail the check of
+/// the balances when the estimation uses the maximum gas limit.
+fn with_gas(mut tx: FuelTx, gas_limit: u64) -> anyhow::Result<FuelTx> {
+    match &mut tx {
+        FuelTx::Script(script) => {
+            *script.gas_limit_mut() = gas_limit;
+            *script.gas_price_mut() = 0;
+        }
+        FuelTx::Create(create) => {
+            *create.gas_limit_mut() = gas_limit;
+            *create.gas_price_mut() = 0;
+        }
+        FuelTx::Mint(_) => {
+            return Err(anyhow::anyhow!(
+                "The gas of mint transactions can't be estimated"
+            ))
+        }
+    }
+    tx.precompute();
+    Ok(tx)
+}
+
+fn gas_price(tx: &FuelTx) -> u64 {
+    match tx {
+        FuelTx::Script(script) => *script.gas_price(),
+        FuelTx::Create(create) => *create.gas_price(),
+        FuelTx::Mint(_) => 0,
+    }
+}
+
+fn metered_bytes_size(tx: &FuelTx) -> usize {
+    match tx {
+        FuelTx::Script(script) => script.metered_bytes_size(),
+        FuelTx::Create(create) => create.metered_bytes_size(),
+        FuelTx::Mint(_) => 0,
+    }
+}
@@ -61,6 +61,9 @@ pub struct MessageQuery {}
 
 #[Object]
 impl MessageQuery {
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn messages(
         &self,
         ctx: &Context<'_>,
@@ -73,6 +76,7 @@ impl MessageQuery {
     {
         let query: &Database = ctx.data_unchecked();
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -1,34 +1,46 @@
 use crate::{
     fuel_core_graphql_api::{
+        auth::AdminGuard,
         service::{
             BlockProducer,
             Database,
             TxPool,
         },
+        Config as GraphQLConfig,
         IntoApiResult,
     },
     query::{
         transaction_status_change,
         BlockQueryData,
         SimpleTransactionData,
+        TransactionHistoryFilter,
         TransactionQueryData,
         TxnStatusChangeState,
     },
     schema::scalars::{
         Address,
+        AssetId,
+        Bytes32,
+        ContractId,
         HexString,
         SortedTxCursor,
         TransactionId,
         TxPointer,
+        UtxoId,
+        U64,
     },
 };
 use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
+        CursorType,
         EmptyFields,
     },
     Context,
+    Enum,
+    ErrorExtensions,
+    InputObject,
     Object,
     Subscription,
 };
@@ -37,12 +49,18 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use fuel_core_types::{
+    entities::coins::coin::Coin as CoinModel,
     fuel_tx::{
+        field::GasLimit,
         Cacheable,
         Transaction as FuelTx,
     },
     fuel_types,
     fuel_types::bytes::Deserializable,
+    services::executor::{
+        ContractStateSlot,
+        StateOverrides,
+    },
 };
 use futures::{
     Stream,
@@ -51,9 +69,19 @@ use futures::{
 use itertools::Itertools;
 use std::{
     iter,
-    sync::Arc,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+use types::{
+    DryRunResult,
+    OwnerTransaction,
+    Transaction,
+    TransactionHistoryFields,
+    TransactionTrace,
+    TransferDirection,
 };
-use types::Transaction;
 
 use self::types::TransactionStatus;
 
@@ -83,6 +111,24 @@ impl TxQuery {
         }
     }
 
+    /// The call trace of the committed transaction. It is `null` if the node doesn't
+    /// record the traces or the transaction isn't committed.
+    async fn transaction_trace(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<TransactionTrace>> {
+        let query: &Database = ctx.data_unchecked();
+        let Some(trace) = query.tx_trace(&id.0)? else {
+            return Ok(None)
+        };
+        let receipts = query.receipts(&id.0)?;
+        Ok(Some(TransactionTrace::new(trace, receipts)))
+    }
+
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -96,6 +142,7 @@ impl TxQuery {
         let db_query: &Database = ctx.data_unchecked();
         let tx_query: &Database = ctx.data_unchecked();
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -145,6 +192,9 @@ impl TxQuery {
         .await
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn transactions_by_owner(
         &self,
         ctx: &Context<'_>,
@@ -166,6 +216,7 @@ impl TxQuery {
         let owner = fuel_types::Address::from(owner);
 
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -180,6 +231,156 @@ impl TxQuery {
         )
         .await
     }
+
+    /// Returns the transactions of the owner matching the `filter` together with the
+    /// changes of the owner's balances made by them.
+    ///
+    /// The number of the owner's transactions scanned by one page is limited by the
+    /// node. The page reaching the limit may contain fewer transactions than requested,
+    /// and the next page should continue from the `scanCursor`.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
+    async fn transaction_history(
+        &self,
+        ctx: &Context<'_>,
+        filter: TransactionHistoryFilterInput,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TxPointer, OwnerTransaction, TransactionHistoryFields, EmptyFields>,
+    > {
+        let query: &Database = ctx.data_unchecked();
+        let max_scanned = ctx
+            .data_unchecked::<GraphQLConfig>()
+            .query_limits
+            .max_scanned_entries
+            .max(1);
+        let owner = fuel_types::Address::from(filter.owner);
+        let filter = TransactionHistoryFilter::try_from(filter)?;
+        let backward = last.is_some();
+
+        let scan = Mutex::new(HistoryScan::default());
+        let page = crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let scan = &scan;
+                let txs = query
+                    .transaction_history(owner, filter, start, direction)
+                    .take_while(move |result| {
+                        let mut scan = scan.lock().expect("The lock is not poisoned");
+                        if scan.scanned == max_scanned {
+                            scan.limit_reached = true;
+                            return false
+                        }
+                        if let Ok((cursor, _)) = result {
+                            // The iteration includes the `start` skipped by the pagination.
+                            if Some(*cursor) == start {
+                                return true
+                            }
+                            scan.last = Some((*cursor).into());
+                        }
+                        scan.scanned += 1;
+                        true
+                    })
+                    .filter_map(|result| match result {
+                        Ok((cursor, Some((owned, tx)))) => {
+                            Some(Ok((cursor.into(), OwnerTransaction { tx, owned })))
+                        }
+                        Ok((_, None)) => None,
+                        Err(err) => Some(Err(err)),
+                    });
+                Ok(txs)
+            },
+        )
+        .await?;
+
+        let scan = scan.into_inner().expect("The lock is not poisoned");
+        let scan_cursor = scan
+            .last
+            .filter(|_| scan.limit_reached)
+            .map(|cursor| cursor.encode_cursor());
+        // The scan limit cuts the page in the direction of the pagination.
+        let (has_previous_page, has_next_page) = if backward {
+            (
+                page.has_previous_page || scan.limit_reached,
+                page.has_next_page,
+            )
+        } else {
+            (
+                page.has_previous_page,
+                page.has_next_page || scan.limit_reached,
+            )
+        };
+        let mut connection = Connection::with_additional_fields(
+            has_previous_page,
+            has_next_page,
+            TransactionHistoryFields { scan_cursor },
+        );
+        connection.edges = page.edges;
+        Ok(connection)
+    }
+}
+
+/// The progress of the scan of the owner's transactions by one page of the history.
+#[derive(Default)]
+struct HistoryScan {
+    scanned: usize,
+    last: Option<TxPointer>,
+    limit_reached: bool,
+}
+
+#[derive(InputObject)]
+struct TransactionHistoryFilterInput {
+    /// The owner of the transactions
+    owner: Address,
+    /// Only transactions changing the balance of the asset.
+    asset_id: Option<AssetId>,
+    /// Only transactions increasing or decreasing the balance of the owner.
+    direction: Option<TransferDirection>,
+    /// Only transactions with the status.
+    status: Option<TransactionHistoryStatus>,
+    /// The lowest height of the block with the transaction, inclusive.
+    from_height: Option<U64>,
+    /// The highest height of the block with the transaction, inclusive.
+    to_height: Option<U64>,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum TransactionHistoryStatus {
+    Success,
+    Reverted,
+}
+
+impl TryFrom<TransactionHistoryFilterInput> for TransactionHistoryFilter {
+    type Error = async_graphql::Error;
+
+    fn try_from(filter: TransactionHistoryFilterInput) -> Result<Self, Self::Error> {
+        let height = |height: Option<U64>| -> async_graphql::Result<_> {
+            height
+                .map(|height| {
+                    let height: u32 = u64::from(height).try_into()?;
+                    Ok(height.into())
+                })
+                .transpose()
+        };
+        Ok(TransactionHistoryFilter {
+            asset_id: filter.asset_id.map(Into::into),
+            direction: filter.direction.map(Into::into),
+            reverted: filter
+                .status
+                .map(|status| status == TransactionHistoryStatus::Reverted),
+            from_height: height(filter.from_height)?,
+            to_height: height(filter.to_height)?,
+        })
+    }
 }
 
 #[derive(Default)]
@@ -188,6 +389,7 @@ pub struct TxMutation;
 #[Object]
 impl TxMutation {
     /// Execute a dry-run of the transaction using a fork of current state, no changes are committed.
+    #[graphql(guard = "AdminGuard")]
     async fn dry_run(
         &self,
         ctx: &Context<'_>,
@@ -206,6 +408,69 @@ impl TxMutation {
         Ok(receipts.iter().map(Into::into).collect())
     }
 
+    /// Execute a dry-run of the sequence of transactions after the block at the `height`
+    /// (the latest block by default) with the state `overrides`. Every transaction sees
+    /// the changes of the previous ones, no changes are committed.
+    ///
+    /// The number of transactions and their total gas are limited by the node.
+    #[graphql(guard = "AdminGuard")]
+    async fn dry_run_batch(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        // The height of the block to execute the transactions after. It only defines the
+        // block context, the state is always the latest one.
+        height: Option<U64>,
+        overrides: Option<StateOverridesInput>,
+        // If set to false, disable input utxo validation, overriding the configuration of the node.
+        utxo_validation: Option<bool>,
+    ) -> async_graphql::Result<Vec<DryRunResult>> {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let limits = &ctx.data_unchecked::<GraphQLConfig>().query_limits;
+
+        if txs.len() > limits.max_dry_run_txs {
+            return Err(dry_run_limit_error(format!(
+                "The number of transactions `{}` exceeds the maximum `{}`",
+                txs.len(),
+                limits.max_dry_run_txs
+            )))
+        }
+        let txs = txs
+            .into_iter()
+            .map(|tx| {
+                let mut tx = FuelTx::from_bytes(&tx.0)?;
+                tx.precompute();
+                Ok(tx)
+            })
+            .collect::<async_graphql::Result<Vec<_>>>()?;
+        let total_gas = txs
+            .iter()
+            .map(|tx| match tx {
+                FuelTx::Script(script) => *script.gas_limit(),
+                FuelTx::Create(_) | FuelTx::Mint(_) => 0,
+            })
+            .fold(0u64, u64::saturating_add);
+        if total_gas > limits.max_dry_run_gas {
+            return Err(dry_run_limit_error(format!(
+                "The total gas limit `{total_gas}` of the transactions exceeds the \
+                maximum `{}`",
+                limits.max_dry_run_gas
+            )))
+        }
+        let height = height
+            .map(|height| -> async_graphql::Result<_> {
+                let height: u32 = u64::from(height).try_into()?;
+                Ok(height.into())
+            })
+            .transpose()?;
+        let overrides = overrides.map(TryInto::try_into).transpose()?;
+
+        let results = block_producer
+            .dry_run_batch(txs, height, overrides.unwrap_or_default(), utxo_validation)
+            .await?;
+        Ok(results.into_iter().map(DryRunResult).collect())
+    }
+
     /// Submits transaction to the txpool
     async fn submit(
         &self,
@@ -225,6 +490,95 @@ impl TxMutation {
     }
 }
 
+/// The state placed on top of the chain state during the dry run.
+#[derive(InputObject)]
+struct StateOverridesInput {
+    /// The coins added to the state. They replace the existing coins with the same id.
+    #[graphql(default)]
+    coins: Vec<CoinOverrideInput>,
+    /// The values of the contracts storage slots.
+    #[graphql(default)]
+    contract_state: Vec<ContractStateOverrideInput>,
+    /// The bytecode of the contracts. Missing contracts are created.
+    #[graphql(default)]
+    contract_code: Vec<ContractCodeOverrideInput>,
+}
+
+#[derive(InputObject)]
+struct CoinOverrideInput {
+    utxo_id: UtxoId,
+    owner: Address,
+    amount: U64,
+    asset_id: AssetId,
+    maturity: Option<U64>,
+}
+
+#[derive(InputObject)]
+struct ContractStateOverrideInput {
+    contract: ContractId,
+    key: Bytes32,
+    /// The value of the slot, `null` clears the slot.
+    value: Option<Bytes32>,
+}
+
+#[derive(InputObject)]
+struct ContractCodeOverrideInput {
+    contract: ContractId,
+    bytecode: HexString,
+}
+
+impl TryFrom<StateOverridesInput> for StateOverrides {
+    type Error = async_graphql::Error;
+
+    fn try_from(overrides: StateOverridesInput) -> Result<Self, Self::Error> {
+        let coins = overrides
+            .coins
+            .into_iter()
+            .map(|coin| -> async_graphql::Result<_> {
+                let maturity: u32 = coin
+                    .maturity
+                    .map(u64::from)
+                    .unwrap_or_default()
+                    .try_into()?;
+                Ok(CoinModel {
+                    utxo_id: coin.utxo_id.into(),
+                    owner: coin.owner.into(),
+                    amount: coin.amount.into(),
+                    asset_id: coin.asset_id.into(),
+                    maturity: maturity.into(),
+                    tx_pointer: Default::default(),
+                })
+            })
+            .try_collect()?;
+        let contract_state = overrides
+            .contract_state
+            .into_iter()
+            .map(|slot| ContractStateSlot {
+                contract_id: slot.contract.into(),
+                key: slot.key.into(),
+                value: slot.value.map(Into::into),
+            })
+            .collect();
+        let contract_code = overrides
+            .contract_code
+            .into_iter()
+            .map(|code| (code.contract.into(), code.bytecode.0))
+            .collect();
+
+        Ok(StateOverrides {
+            coins,
+            contract_state,
+            contract_code,
+        })
+    }
+}
+
+/// The error for the batch dry run above the limits of the node.
+fn dry_run_limit_error(message: String) -> async_graphql::Error {
+    async_graphql::Error::new(message)
+        .extend_with(|_, extensions| extensions.set("code", "DRY_RUN_LIMIT"))
+}
+
 #[derive(Default)]
 pub struct TxStatusSubscription;
 
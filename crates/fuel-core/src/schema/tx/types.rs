@@ -12,21 +12,28 @@ use crate::{
         IntoApiResult,
     },
     query::{
+        self,
         SimpleBlockData,
         SimpleTransactionData,
         TransactionQueryData,
     },
     schema::{
         block::Block,
-        contract::Contract,
+        coins::Coin,
+        contract::{
+            Contract,
+            ContractBalance,
+        },
         scalars::{
             AssetId,
             Bytes32,
+            ContractId,
             HexString,
             Salt,
             Tai64Timestamp,
             TransactionId,
             TxPointer,
+            UtxoId,
             U64,
         },
     },
@@ -35,6 +42,7 @@ use async_graphql::{
     Context,
     Enum,
     Object,
+    SimpleObject,
     Union,
 };
 use fuel_core_storage::Error as StorageError;
@@ -63,11 +71,14 @@ use fuel_core_types::{
     fuel_types::bytes::SerializableVec,
     fuel_vm::ProgramState as VmProgramState,
     services::{
+        executor,
+        graphql_api,
         txpool,
         txpool::TransactionStatus as TxStatus,
     },
     tai64::Tai64,
 };
+use std::sync::Arc;
 
 pub struct ProgramState {
     return_type: ReturnType,
@@ -144,7 +155,8 @@ pub struct SuccessStatus {
 impl SuccessStatus {
     async fn block(&self, ctx: &Context<'_>) -> async_graphql::Result<Block> {
         let query: &Database = ctx.data_unchecked();
-        let block = query.block(&self.block_id)?;
+        let height = query.block_height(&self.block_id)?;
+        let block = query.block(&height)?;
         Ok(block.into())
     }
 
@@ -169,7 +181,8 @@ pub struct FailureStatus {
 impl FailureStatus {
     async fn block(&self, ctx: &Context<'_>) -> async_graphql::Result<Block> {
         let query: &Database = ctx.data_unchecked();
-        let block = query.block(&self.block_id)?;
+        let height = query.block_height(&self.block_id)?;
+        let block = query.block(&height)?;
         Ok(block.into())
     }
 
@@ -405,11 +418,17 @@ impl Transaction {
         get_tx_status(id, query, txpool).await.map_err(Into::into)
     }
 
+    #[graphql(complexity = "crate::schema::list_complexity(child_complexity)")]
     async fn receipts(
         &self,
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Option<Vec<Receipt>>> {
         let query: &Database = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        // The pending transaction has no receipts, even if the node pruned its history.
+        if txpool.transaction(self.0.id()).is_some() {
+            return Ok(None)
+        }
         let receipts = query
             .receipts(&self.0.id())
             .into_api_result::<Vec<_>, async_graphql::Error>()?;
@@ -493,6 +512,261 @@ impl Transaction {
     }
 }
 
+/// The fields of the page of the transaction history.
+#[derive(SimpleObject)]
+pub struct TransactionHistoryFields {
+    /// The cursor of the last scanned transaction of the owner if the page stopped at
+    /// the limit of the scanned transactions before it was filled. The next page in the
+    /// same direction should continue from it instead of the `endCursor`.
+    pub scan_cursor: Option<String>,
+}
+
+/// The transaction of the owner with the changes of the owner's balances.
+pub struct OwnerTransaction {
+    pub(crate) tx: fuel_tx::Transaction,
+    pub(crate) owned: graphql_api::OwnedTransaction,
+}
+
+#[Object]
+impl OwnerTransaction {
+    async fn transaction(&self) -> Transaction {
+        Transaction(self.tx.clone())
+    }
+
+    /// `true` if the transaction was included into the block but reverted.
+    async fn reverted(&self) -> bool {
+        self.owned.reverted
+    }
+
+    /// The changes of the owner's balances per asset made by the transaction.
+    async fn deltas(&self) -> Vec<BalanceDelta> {
+        self.owned
+            .deltas
+            .iter()
+            .cloned()
+            .map(BalanceDelta)
+            .collect()
+    }
+}
+
+pub struct BalanceDelta(graphql_api::BalanceDelta);
+
+#[Object]
+impl BalanceDelta {
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    /// The sum of the outputs to the owner.
+    async fn received(&self) -> U64 {
+        self.0.received.into()
+    }
+
+    /// The sum of the owner's spent inputs.
+    async fn spent(&self) -> U64 {
+        self.0.spent.into()
+    }
+
+    /// The absolute value of the net change of the balance.
+    async fn amount(&self) -> U64 {
+        self.0
+            .net()
+            .unsigned_abs()
+            .try_into()
+            .unwrap_or(u64::MAX)
+            .into()
+    }
+
+    /// The direction of the net change of the balance, `null` if the balance is unchanged.
+    async fn direction(&self) -> Option<TransferDirection> {
+        match self.0.net() {
+            net if net > 0 => Some(TransferDirection::In),
+            net if net < 0 => Some(TransferDirection::Out),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum TransferDirection {
+    In,
+    Out,
+}
+
+impl From<TransferDirection> for query::TransferDirection {
+    fn from(direction: TransferDirection) -> Self {
+        match direction {
+            TransferDirection::In => query::TransferDirection::In,
+            TransferDirection::Out => query::TransferDirection::Out,
+        }
+    }
+}
+
+/// The result of the dry run of one transaction from the batch.
+pub struct DryRunResult(pub(crate) executor::DryRunResult);
+
+#[Object]
+impl DryRunResult {
+    async fn transaction_id(&self) -> TransactionId {
+        self.0.id.into()
+    }
+
+    async fn receipts(&self) -> Vec<Receipt> {
+        self.0.receipts.iter().map(Into::into).collect()
+    }
+
+    /// The gas used by the script, zero for other transactions.
+    async fn gas_used(&self) -> U64 {
+        self.0.gas_used.into()
+    }
+
+    /// The changes of the state made by the transaction.
+    async fn state_diff(&self) -> StateDiff {
+        StateDiff(self.0.state_diff.clone())
+    }
+}
+
+pub struct StateDiff(executor::StateDiff);
+
+#[Object]
+impl StateDiff {
+    async fn created_coins(&self) -> Vec<Coin> {
+        self.0.created_coins.iter().cloned().map(Coin).collect()
+    }
+
+    async fn spent_coins(&self) -> Vec<UtxoId> {
+        self.0.spent_coins.iter().cloned().map(Into::into).collect()
+    }
+
+    async fn created_contracts(&self) -> Vec<ContractId> {
+        self.0
+            .created_contracts
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// The new values of the storage slots modified by the transaction.
+    async fn contract_state(&self) -> Vec<ContractStateSlot> {
+        self.0
+            .contract_state
+            .iter()
+            .cloned()
+            .map(ContractStateSlot)
+            .collect()
+    }
+
+    /// The new balances of the contracts modified by the transaction.
+    async fn contract_balances(&self) -> Vec<ContractBalance> {
+        self.0
+            .contract_balances
+            .iter()
+            .map(|balance| {
+                graphql_api::ContractBalance {
+                    owner: balance.contract_id,
+                    amount: balance.amount,
+                    asset_id: balance.asset_id,
+                }
+                .into()
+            })
+            .collect()
+    }
+}
+
+pub struct ContractStateSlot(executor::ContractStateSlot);
+
+#[Object]
+impl ContractStateSlot {
+    async fn contract(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+
+    /// The value of the slot, `null` if the slot is empty.
+    async fn value(&self) -> Option<Bytes32> {
+        self.0.value.map(Into::into)
+    }
+}
+
+pub struct TransactionTrace {
+    trace: executor::TransactionTrace,
+    /// The receipts of the transaction referenced by the frames.
+    receipts: Arc<Vec<fuel_tx::Receipt>>,
+}
+
+impl TransactionTrace {
+    pub(crate) fn new(
+        trace: executor::TransactionTrace,
+        receipts: Vec<fuel_tx::Receipt>,
+    ) -> Self {
+        Self {
+            trace,
+            receipts: Arc::new(receipts),
+        }
+    }
+}
+
+#[Object]
+impl TransactionTrace {
+    /// The gas used by the script, zero for other transactions.
+    async fn gas_used(&self) -> U64 {
+        self.trace.gas_used.into()
+    }
+
+    /// The call frames in the order of the calls. The first frame is the script.
+    async fn frames(&self) -> Vec<CallFrameTrace> {
+        self.trace
+            .frames
+            .iter()
+            .cloned()
+            .map(|frame| CallFrameTrace {
+                frame,
+                receipts: self.receipts.clone(),
+            })
+            .collect()
+    }
+}
+
+pub struct CallFrameTrace {
+    frame: executor::CallFrameTrace,
+    receipts: Arc<Vec<fuel_tx::Receipt>>,
+}
+
+#[Object]
+impl CallFrameTrace {
+    /// The index of the calling frame in the `frames`, `null` for the script.
+    async fn parent(&self) -> Option<u32> {
+        self.frame.parent
+    }
+
+    /// The called contract, `null` for the script.
+    async fn contract(&self) -> Option<ContractId> {
+        self.frame.contract_id.map(Into::into)
+    }
+
+    /// The gas forwarded to the call, the gas limit of the transaction for the script.
+    /// The gas used by the frame isn't available: the receipts don't contain the gas
+    /// left at the end of the call.
+    async fn gas_forwarded(&self) -> U64 {
+        self.frame.gas_forwarded.into()
+    }
+
+    /// The receipts produced by the frame. The `CALL` receipt of the nested call
+    /// belongs to the calling frame.
+    async fn receipts(&self) -> Vec<Receipt> {
+        self.frame
+            .receipts
+            .iter()
+            .filter_map(|index| self.receipts.get(*index as usize))
+            .map(Into::into)
+            .collect()
+    }
+}
+
 #[tracing::instrument(level = "debug", skip(query, txpool), ret, err)]
 pub(super) async fn get_tx_status(
     id: fuel_core_types::fuel_types::Bytes32,
@@ -501,14 +775,16 @@ pub(super) async fn get_tx_status(
 ) -> Result<Option<TransactionStatus>, StorageError> {
     match query
         .status(&id)
-        .into_api_result::<txpool::TransactionStatus, StorageError>()?
+        .into_api_result::<txpool::TransactionStatus, StorageError>()
     {
-        Some(status) => Ok(Some(status.into())),
-        None => match txpool.submission_time(id) {
+        Ok(Some(status)) => Ok(Some(status.into())),
+        // The pending transaction is unknown to the database, which reports it as
+        // pruned if the node pruned its history.
+        result => match txpool.submission_time(id) {
             Some(submitted_time) => Ok(Some(TransactionStatus::Submitted(
                 SubmittedStatus(submitted_time),
             ))),
-            _ => Ok(None),
+            _ => result.map(|_| None),
         },
     }
 }
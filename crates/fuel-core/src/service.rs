@@ -15,10 +15,20 @@ use tracing::log::warn;
 pub use config::{
     Config,
     DbType,
+    PruningConfig,
+    QueryLimits,
+    RateLimitConfig,
     VMConfig,
+    WebSocketConfig,
 };
 pub use fuel_core_services::Service as ServiceTrait;
 
+#[cfg(feature = "rocksdb")]
+pub use crate::state::rocks_db::{
+    CompactionStyle,
+    RocksDbConfig,
+};
+
 pub use fuel_core_consensus_module::RelayerVerifierConfig;
 
 use self::adapters::BlockImporterAdapter;
@@ -27,6 +37,7 @@ pub mod adapters;
 pub mod config;
 pub mod genesis;
 pub mod metrics;
+pub mod pruner;
 pub mod sub_services;
 
 #[derive(Clone)]
@@ -91,7 +102,7 @@ impl FuelService {
                     );
                     Database::default()
                 } else {
-                    Database::open(&config.database_path)?
+                    Database::open_with_config(&config.database_path, &config.rocksdb)?
                 }
             }
             DbType::InMemory => Database::in_memory(),
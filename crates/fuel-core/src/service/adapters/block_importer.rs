@@ -11,6 +11,7 @@ use fuel_core_importer::{
         BlockVerifier,
         Executor,
         ExecutorDatabase,
+        HeaderDatabase,
         ImporterDatabase,
     },
     Config,
@@ -18,14 +19,20 @@ use fuel_core_importer::{
 };
 use fuel_core_poa::ports::RelayerPort;
 use fuel_core_storage::{
-    tables::SealedBlockConsensus,
+    tables::{
+        FuelBlocks,
+        SealedBlockConsensus,
+    },
     transactional::StorageTransaction,
     Result as StorageResult,
     StorageAsMut,
 };
 use fuel_core_types::{
     blockchain::{
-        block::Block,
+        block::{
+            Block,
+            CompressedBlock,
+        },
         consensus::Consensus,
         primitives::{
             BlockHeight,
@@ -33,6 +40,7 @@ use fuel_core_types::{
             DaBlockHeight,
         },
         SealedBlock,
+        SealedBlockHeader,
     },
     services::executor::{
         ExecutionBlock,
@@ -67,6 +75,18 @@ impl BlockImporterAdapter {
         .await??;
         Ok(())
     }
+
+    pub async fn commit_header(
+        &self,
+        sealed_header: SealedBlockHeader,
+    ) -> anyhow::Result<()> {
+        tokio::task::spawn_blocking({
+            let importer = self.block_importer.clone();
+            move || importer.commit_header(sealed_header)
+        })
+        .await??;
+        Ok(())
+    }
 }
 
 impl BlockVerifier for VerifierAdapter {
@@ -116,6 +136,21 @@ impl ImporterDatabase for Database {
     }
 }
 
+impl HeaderDatabase for Database {
+    fn store_sealed_header(
+        &mut self,
+        header: &SealedBlockHeader,
+    ) -> StorageResult<Option<Consensus>> {
+        // The block without transactions keeps the block merkle data up to date, while
+        // the marker tells that its transactions are unknown rather than empty.
+        let block = CompressedBlock::from_header(header.entity.clone());
+        self.storage::<FuelBlocks>()
+            .insert(header.entity.height(), &block)?;
+        self.mark_header_only(header.entity.height())?;
+        self.seal_block(&block.id(), &header.consensus)
+    }
+}
+
 impl ExecutorDatabase for Database {
     fn seal_block(
         &mut self,
@@ -9,13 +9,18 @@ use fuel_core_storage::{
     Error as StorageError,
 };
 use fuel_core_types::{
-    blockchain::primitives::DaBlockHeight,
+    blockchain::{
+        block::PartialFuelBlock,
+        primitives::DaBlockHeight,
+    },
     entities::message::Message,
     fuel_tx::Receipt,
     fuel_types::Nonce,
     services::executor::{
+        DryRunResult,
         ExecutionBlock,
         Result as ExecutorResult,
+        StateOverrides,
         UncommittedResult,
     },
 };
@@ -47,6 +52,20 @@ impl ExecutorAdapter {
         };
         executor.dry_run(block, utxo_validation)
     }
+
+    pub(crate) fn _dry_run_batch(
+        &self,
+        block: PartialFuelBlock,
+        overrides: StateOverrides,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<DryRunResult>> {
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+        };
+        executor.dry_run_batch(block, overrides, utxo_validation)
+    }
 }
 
 /// Implemented to satisfy: `GenesisCommitment for ContractRef<&'a mut Database>`
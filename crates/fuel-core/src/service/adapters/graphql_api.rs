@@ -4,7 +4,10 @@ use crate::{
         Database,
     },
     fuel_core_graphql_api::ports::{
+        BlockImporterPort,
         BlockProducerPort,
+        DatabaseBackup,
+        DatabaseBalances,
         DatabaseBlocks,
         DatabaseChain,
         DatabaseCoins,
@@ -15,7 +18,10 @@ use crate::{
         DryRunExecution,
         TxPoolPort,
     },
-    service::adapters::TxPoolAdapter,
+    service::adapters::{
+        BlockImporterAdapter,
+        TxPoolAdapter,
+    },
 };
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
@@ -37,10 +43,13 @@ use fuel_core_txpool::{
     },
 };
 use fuel_core_types::{
-    blockchain::primitives::{
-        BlockHeight,
-        BlockId,
-        DaBlockHeight,
+    blockchain::{
+        block::CompressedBlock,
+        primitives::{
+            BlockHeight,
+            BlockId,
+            DaBlockHeight,
+        },
     },
     entities::message::Message,
     fuel_tx::{
@@ -53,7 +62,16 @@ use fuel_core_types::{
     },
     fuel_types::Nonce,
     services::{
-        graphql_api::ContractBalance,
+        block_importer::ImportResult,
+        executor::{
+            DryRunResult,
+            StateOverrides,
+            TransactionTrace,
+        },
+        graphql_api::{
+            ContractBalance,
+            OwnedTransaction,
+        },
         txpool::{
             InsertionResult,
             TransactionStatus,
@@ -63,6 +81,7 @@ use fuel_core_types::{
 };
 use std::{
     ops::Deref,
+    path::PathBuf,
     sync::Arc,
 };
 use tokio_stream::wrappers::{
@@ -71,26 +90,39 @@ use tokio_stream::wrappers::{
 };
 
 impl DatabaseBlocks for Database {
-    fn block_id(&self, height: &BlockHeight) -> StorageResult<BlockId> {
-        self.get_block_id(height)
-            .and_then(|height| height.ok_or(not_found!("BlockId")))
+    fn block_height(&self, id: &BlockId) -> StorageResult<BlockHeight> {
+        self.get_block_height(id)
+            .and_then(|height| height.ok_or(not_found!("BlockHeight")))
     }
 
-    fn blocks_ids(
+    fn blocks(
         &self,
         start: Option<BlockHeight>,
         direction: IterDirection,
-    ) -> BoxedIter<'_, StorageResult<(BlockHeight, BlockId)>> {
-        self.all_block_ids(start, direction)
+    ) -> BoxedIter<'_, StorageResult<CompressedBlock>> {
+        self.all_blocks(start, direction)
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
 
-    fn ids_of_latest_block(&self) -> StorageResult<(BlockHeight, BlockId)> {
-        Ok(self
-            .ids_of_latest_block()
-            .transpose()
-            .ok_or(not_found!("BlockId"))??)
+    fn latest_height(&self) -> StorageResult<BlockHeight> {
+        self.latest_height()
+    }
+
+    fn is_pruned(&self, height: &BlockHeight) -> StorageResult<bool> {
+        Database::is_pruned(self, height).map_err(Into::into)
+    }
+
+    fn pruned_transactions_height(&self) -> StorageResult<Option<BlockHeight>> {
+        Database::pruned_transactions_height(self).map_err(Into::into)
+    }
+
+    fn is_header_only(&self, height: &BlockHeight) -> StorageResult<bool> {
+        Database::is_header_only(self, height)
+    }
+
+    fn has_header_only_blocks(&self) -> StorageResult<bool> {
+        Database::has_header_only_blocks(self)
     }
 }
 
@@ -102,12 +134,16 @@ impl DatabaseTransactions for Database {
             .ok_or(not_found!("TransactionId"))??)
     }
 
-    fn owned_transactions_ids(
+    fn tx_trace(&self, tx_id: &TxId) -> StorageResult<Option<TransactionTrace>> {
+        Ok(self.get_tx_trace(tx_id)?)
+    }
+
+    fn owned_transaction_entries(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
-    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+    ) -> BoxedIter<StorageResult<(TxPointer, OwnedTransaction)>> {
         let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
             block_height: tx_pointer.block_height().into(),
             tx_idx: tx_pointer.tx_index(),
@@ -154,6 +190,22 @@ impl DatabaseCoins for Database {
     }
 }
 
+impl DatabaseBalances for Database {
+    fn owned_balance(&self, owner: &Address, asset_id: &AssetId) -> StorageResult<u128> {
+        self.owned_balance(owner, asset_id).map_err(Into::into)
+    }
+
+    fn owned_balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u128)>> {
+        self.owned_balances(owner, Some(direction))
+            .map(|res| res.map_err(StorageError::from))
+            .into_boxed()
+    }
+}
+
 impl DatabaseContracts for Database {
     fn contract_balances(
         &self,
@@ -197,6 +249,19 @@ impl DatabaseChain for Database {
     }
 }
 
+#[async_trait]
+impl DatabaseBackup for Database {
+    async fn backup(&self, path: PathBuf) -> StorageResult<()> {
+        let database = self.clone();
+        // use the blocking threadpool for the copy of the files to avoid clogging up
+        // the main async runtime
+        tokio::task::spawn_blocking(move || Database::backup(&database, &path))
+            .await
+            .map_err(|e| StorageError::Other(e.into()))?
+            .map_err(Into::into)
+    }
+}
+
 impl DatabasePort for Database {}
 
 impl TxPoolPort for TxPoolAdapter {
@@ -235,8 +300,30 @@ impl DryRunExecution for BlockProducerAdapter {
             .dry_run(transaction, height, utxo_validation)
             .await
     }
+
+    async fn dry_run_batch(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        overrides: StateOverrides,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<DryRunResult>> {
+        self.block_producer
+            .dry_run_batch(transactions, height, overrides, utxo_validation)
+            .await
+    }
 }
 
 impl BlockProducerPort for BlockProducerAdapter {}
 
+impl BlockImporterPort for BlockImporterAdapter {
+    fn block_events(&self) -> BoxStream<Arc<ImportResult>> {
+        use tokio_stream::StreamExt;
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok()),
+        )
+    }
+}
+
 use super::BlockProducerAdapter;
@@ -16,28 +16,54 @@ use fuel_core_types::{
         SealedBlockHeader,
     },
     fuel_tx::Transaction,
+    services::p2p::BannedPeer,
 };
+use std::ops::Range;
 
 impl P2pDb for Database {
     fn get_sealed_block(
         &self,
         height: &BlockHeight,
     ) -> StorageResult<Option<SealedBlock>> {
+        // The pruned blocks can't be served in full, so the peer should ask another node.
+        if self.is_pruned(height)? {
+            return Ok(None)
+        }
         self.get_sealed_block_by_height(height)
     }
 
-    fn get_sealed_header(
+    fn get_sealed_headers(
         &self,
-        height: &BlockHeight,
-    ) -> StorageResult<Option<SealedBlockHeader>> {
-        self.get_sealed_block_header_by_height(height)
+        block_height_range: Range<BlockHeight>,
+    ) -> StorageResult<Vec<SealedBlockHeader>> {
+        let mut headers = vec![];
+        for height in *block_height_range.start..*block_height_range.end {
+            match self.get_sealed_block_header_by_height(&height.into())? {
+                Some(header) => headers.push(header),
+                None => break,
+            }
+        }
+        Ok(headers)
     }
 
     fn get_transactions(
         &self,
         block_id: &BlockId,
     ) -> StorageResult<Option<Vec<Transaction>>> {
-        self.get_transactions_on_block(block_id)
+        match self.get_block_height(block_id)? {
+            Some(height) if !self.is_pruned(&height)? => {
+                self.get_transactions_on_block(block_id)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn get_banned_peers(&self) -> StorageResult<Vec<BannedPeer>> {
+        Ok(Database::get_banned_peers(self)?)
+    }
+
+    fn set_banned_peers(&self, peers: Vec<BannedPeer>) -> StorageResult<()> {
+        Ok(Database::set_banned_peers(self, peers)?)
     }
 }
 
@@ -17,7 +17,10 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
-        block::CompressedBlock,
+        block::{
+            CompressedBlock,
+            PartialFuelBlock,
+        },
         primitives::{
             self,
             BlockHeight,
@@ -27,8 +30,10 @@ use fuel_core_types::{
     fuel_types::Bytes32,
     services::{
         executor::{
+            DryRunResult,
             ExecutionBlock,
             Result as ExecutorResult,
+            StateOverrides,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
@@ -74,6 +79,15 @@ impl fuel_core_producer::ports::Executor<Database> for ExecutorAdapter {
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         self._dry_run(block, utxo_validation)
     }
+
+    fn dry_run_batch(
+        &self,
+        block: PartialFuelBlock,
+        overrides: StateOverrides,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<DryRunResult>> {
+        self._dry_run_batch(block, overrides, utxo_validation)
+    }
 }
 
 #[async_trait::async_trait]
@@ -105,9 +119,8 @@ impl fuel_core_producer::ports::Relayer for MaybeRelayerAdapter {
 
 impl fuel_core_producer::ports::BlockProducerDatabase for Database {
     fn get_block(&self, height: &BlockHeight) -> StorageResult<Cow<CompressedBlock>> {
-        let id = self.get_block_id(height)?.ok_or(not_found!("BlockId"))?;
         self.storage::<FuelBlocks>()
-            .get(&id)?
+            .get(height)?
             .ok_or(not_found!(FuelBlocks))
     }
 
@@ -3,10 +3,12 @@ use super::{
     P2PAdapter,
     VerifierAdapter,
 };
+use fuel_core_importer::importer::Error as ImporterError;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_sync::ports::{
     BlockImporterPort,
     ConsensusPort,
+    InvalidBlock,
     PeerToPeerPort,
 };
 use fuel_core_types::{
@@ -20,8 +22,13 @@ use fuel_core_types::{
         SealedBlockHeader,
     },
     fuel_tx::Transaction,
-    services::p2p::SourcePeer,
+    services::p2p::{
+        PeerId,
+        PeerReportReason,
+        SourcePeer,
+    },
 };
+use std::ops::Range;
 
 #[async_trait::async_trait]
 impl PeerToPeerPort for P2PAdapter {
@@ -39,18 +46,37 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
-    async fn get_sealed_block_header(
+    async fn get_peers_with_height(
         &self,
         height: BlockHeight,
-    ) -> anyhow::Result<Option<SourcePeer<SealedBlockHeader>>> {
+    ) -> anyhow::Result<Vec<PeerId>> {
         if let Some(service) = &self.service {
             Ok(service
-                .get_sealed_block_header(height)
+                .get_peer_ids_with_height(height)
                 .await?
-                .map(|(peer_id, header)| SourcePeer {
-                    peer_id: peer_id.into(),
-                    data: header,
-                }))
+                .into_iter()
+                .map(PeerId::from)
+                .collect())
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn get_sealed_block_headers(
+        &self,
+        block_height_range: SourcePeer<Range<u32>>,
+    ) -> anyhow::Result<Option<Vec<SealedBlockHeader>>> {
+        let SourcePeer {
+            peer_id,
+            data: range,
+        } = block_height_range;
+        if let Some(service) = &self.service {
+            service
+                .get_sealed_block_headers(
+                    peer_id.into(),
+                    range.start.into()..range.end.into(),
+                )
+                .await
         } else {
             Ok(None)
         }
@@ -58,20 +84,28 @@ impl PeerToPeerPort for P2PAdapter {
 
     async fn get_transactions(
         &self,
-        block: SourcePeer<BlockId>,
-    ) -> anyhow::Result<Option<Vec<Transaction>>> {
+        block_ids: SourcePeer<Vec<BlockId>>,
+    ) -> anyhow::Result<Option<Vec<Vec<Transaction>>>> {
         let SourcePeer {
             peer_id,
-            data: block,
-        } = block;
+            data: block_ids,
+        } = block_ids;
         if let Some(service) = &self.service {
             service
-                .get_transactions_from_peer(peer_id.into(), block)
+                .get_transactions_from_peer(peer_id.into(), block_ids)
                 .await
         } else {
             Ok(None)
         }
     }
+
+    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.report_peer(peer, report)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -88,7 +122,24 @@ impl BlockImporterPort for BlockImporterAdapter {
         )
     }
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
-        self.execute_and_commit(block).await
+        self.execute_and_commit(block)
+            .await
+            .map_err(with_invalid_block_context)
+    }
+    async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()> {
+        self.commit_header(header)
+            .await
+            .map_err(with_invalid_block_context)
+    }
+}
+
+/// Marks the errors of the importer caused by the invalid block for the sync.
+fn with_invalid_block_context(error: anyhow::Error) -> anyhow::Error {
+    match error.downcast_ref::<ImporterError>() {
+        Some(importer_error) if importer_error.is_invalid_block() => {
+            error.context(InvalidBlock)
+        }
+        _ => error,
     }
 }
 
@@ -143,4 +143,16 @@ impl fuel_core_txpool::ports::TxPoolDb for Database {
     fn current_block_height(&self) -> StorageResult<BlockHeight> {
         self.latest_height()
     }
+
+    fn save_pending_transactions(&self, txs: Vec<Transaction>) -> StorageResult<()> {
+        Ok(Database::save_pending_transactions(self, txs)?)
+    }
+
+    fn pending_transactions(&self) -> StorageResult<Vec<Transaction>> {
+        Ok(Database::pending_transactions(self)?)
+    }
+
+    fn clear_pending_transactions(&self) -> StorageResult<()> {
+        Ok(Database::clear_pending_transactions(self)?)
+    }
 }
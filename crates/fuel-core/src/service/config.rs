@@ -2,6 +2,7 @@ use clap::ValueEnum;
 use fuel_core_chain_config::{
     default_consensus_dev_key,
     ChainConfig,
+    SnapshotReader,
 };
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
@@ -21,12 +22,19 @@ use strum_macros::{
     EnumVariantNames,
 };
 
+#[cfg(feature = "rocksdb")]
+use crate::state::rocks_db::RocksDbConfig;
 #[cfg(feature = "p2p")]
 use fuel_core_p2p::config::{
     Config as P2PConfig,
     NotInitialized,
 };
 
+pub use crate::graphql_api::{
+    QueryLimits,
+    RateLimitConfig,
+    WebSocketConfig,
+};
 pub use fuel_core_poa::Trigger;
 
 #[derive(Clone, Debug)]
@@ -34,6 +42,8 @@ pub struct Config {
     pub addr: SocketAddr,
     pub database_path: PathBuf,
     pub database_type: DbType,
+    #[cfg(feature = "rocksdb")]
+    pub rocksdb: RocksDbConfig,
     pub chain_conf: ChainConfig,
     // default to false until downstream consumers stabilize
     pub utxo_validation: bool,
@@ -53,6 +63,25 @@ pub struct Config {
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
     pub name: String,
     pub verifier: fuel_core_consensus_module::RelayerVerifierConfig,
+    /// The pruning of the historical data. `None` means that the node keeps
+    /// the full history.
+    pub pruning: Option<PruningConfig>,
+    /// The directory for the backups of the database created via GraphQL API.
+    /// `None` disables the backups.
+    pub backup_dir: Option<PathBuf>,
+    /// The streaming snapshot of the state imported by the genesis instead of the
+    /// `initial_state` of the `chain_conf`. The `chain_conf` should be loaded
+    /// from the same snapshot.
+    pub genesis_snapshot: Option<SnapshotReader>,
+    /// The streaming snapshot of the state at the `checkpoint` of the `chain_conf`.
+    /// The empty database is initialized with the genesis block and this state,
+    /// so the blocks before the checkpoint are not executed.
+    pub checkpoint_snapshot: Option<SnapshotReader>,
+    pub websocket: WebSocketConfig,
+    pub query_limits: QueryLimits,
+    /// The bearer token of the privileged GraphQL operations, like the block production,
+    /// the debugger and dry runs. `None` leaves them open to everyone.
+    pub admin_token: Option<Secret<String>>,
 }
 
 impl Config {
@@ -67,6 +96,8 @@ impl Config {
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            #[cfg(feature = "rocksdb")]
+            rocksdb: Default::default(),
             chain_conf: chain_conf.clone(),
             manual_blocks_enabled: false,
             block_production: Trigger::Instant,
@@ -91,6 +122,13 @@ impl Config {
             consensus_key: Some(Secret::new(default_consensus_dev_key().into())),
             name: String::default(),
             verifier: Default::default(),
+            pruning: None,
+            backup_dir: None,
+            genesis_snapshot: None,
+            checkpoint_snapshot: None,
+            websocket: Default::default(),
+            query_limits: Default::default(),
+            admin_token: None,
         }
     }
 }
@@ -121,6 +159,15 @@ impl TryFrom<&Config> for fuel_core_poa::Config {
 #[derive(Clone, Debug, Default)]
 pub struct VMConfig {
     pub backtrace: bool,
+    /// Records the call traces of the executed transactions into the database.
+    pub trace: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct PruningConfig {
+    /// The number of the latest blocks for which the transactions, receipts,
+    /// statuses and the owners index are kept. At least the latest block is kept.
+    pub retained_blocks: u32,
 }
 
 #[derive(
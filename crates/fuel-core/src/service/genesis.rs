@@ -4,8 +4,14 @@ use crate::{
 };
 use anyhow::anyhow;
 use fuel_core_chain_config::{
+    CoinConfig,
+    ContractBalanceConfig,
     ContractConfig,
+    ContractStateConfig,
     GenesisCommitment,
+    MessageConfig,
+    SnapshotReader,
+    SpentMessageConfig,
     StateConfig,
 };
 use fuel_core_executor::refs::ContractRef;
@@ -20,8 +26,13 @@ use fuel_core_storage::{
         ContractsState,
         FuelBlocks,
         Messages,
+        SpentMessages,
+    },
+    transactional::{
+        StorageTransaction,
+        Transaction as _,
+        Transactional,
     },
-    transactional::Transactional,
     MerkleRoot,
     StorageAsMut,
 };
@@ -37,7 +48,11 @@ use fuel_core_types::{
             ConsensusHeader,
             PartialBlockHeader,
         },
-        primitives::Empty,
+        primitives::{
+            BlockHeight,
+            BlockId,
+            Empty,
+        },
         SealedBlock,
     },
     entities::{
@@ -55,6 +70,7 @@ use fuel_core_types::{
         bytes::WORD_SIZE,
         Bytes32,
         ContractId,
+        Nonce,
     },
     services::block_importer::{
         ImportResult,
@@ -70,34 +86,161 @@ pub fn maybe_initialize_state(
 ) -> anyhow::Result<()> {
     // check if chain is initialized
     if database.ids_of_latest_block()?.is_none() {
-        import_genesis_block(config, database)?;
+        match &config.checkpoint_snapshot {
+            Some(snapshot) => import_checkpoint(config, database, snapshot)?,
+            None => import_genesis_block(config, database)?,
+        }
     }
 
     Ok(())
 }
 
+/// The roots of the initial state committed by the genesis block.
+struct GenesisRoots {
+    coins_root: MerkleRoot,
+    contracts_root: MerkleRoot,
+    messages_root: MerkleRoot,
+}
+
 fn import_genesis_block(
     config: &Config,
     original_database: &Database,
 ) -> anyhow::Result<()> {
+    let initial_state = config.chain_conf.initial_state.as_ref();
+    let height = genesis_height(config)?;
+
+    // The snapshot is imported chunk by chunk before the genesis block,
+    // while the state from the chain config is committed along with the block.
+    let snapshot_roots = match &config.genesis_snapshot {
+        Some(snapshot) => Some(import_snapshot(original_database, snapshot, height)?),
+        None => None,
+    };
+
     // start a db transaction for bulk-writing
     let mut database_transaction = Transactional::transaction(original_database);
 
     let database = database_transaction.as_mut();
-    // Initialize the chain id and height.
+    let roots = match snapshot_roots {
+        Some(roots) => roots,
+        None => init_chain_config_state(database, &config.chain_conf.initial_state)?,
+    };
+    if let Some(spent_messages) =
+        initial_state.and_then(|state| state.spent_messages.as_ref())
+    {
+        for nonce in spent_messages {
+            init_spent_message(database, nonce)?;
+        }
+    }
+
+    commit_genesis_block(config, original_database, database_transaction, roots)
+}
 
+/// Initializes the database of the node that starts from the checkpoint.
+/// The node has the genesis block of the network and the state from the snapshot
+/// taken at the checkpoint, while the headers between them are imported by the sync.
+///
+/// The state at the checkpoint is trusted, because the headers don't commit to it.
+fn import_checkpoint(
+    config: &Config,
+    original_database: &Database,
+    snapshot: &SnapshotReader,
+) -> anyhow::Result<()> {
+    let checkpoint = config
+        .chain_conf
+        .checkpoint
+        .ok_or_else(|| anyhow!("The checkpoint snapshot requires the checkpoint"))?;
+    if config.genesis_snapshot.is_some() {
+        return Err(anyhow!(
+            "The genesis snapshot can't be used along with the checkpoint snapshot"
+        ))
+    }
+    let snapshot_config = snapshot.chain_config()?;
+    if snapshot_config.root()? != config.chain_conf.root()? {
+        return Err(anyhow!(
+            "The checkpoint snapshot is taken from another network"
+        ))
+    }
+    let snapshot_state = snapshot_config.initial_state.unwrap_or_default();
+    if snapshot_state.height != Some(checkpoint.height)
+        || snapshot_state.last_block_id != Some(checkpoint.block_id)
+    {
+        return Err(anyhow!(
+            "The snapshot is not taken at the checkpoint {checkpoint:?}"
+        ))
+    }
+
+    // The genesis block commits to the state from the chain config, which is
+    // replaced by the state at the checkpoint. So the roots are calculated
+    // in the transaction that is never committed.
+    let roots = init_chain_config_state(
+        Transactional::transaction(original_database).as_mut(),
+        &config.chain_conf.initial_state,
+    )?;
+    import_snapshot(original_database, snapshot, checkpoint.height)?;
+
+    let database_transaction = Transactional::transaction(original_database);
+    database_transaction.as_ref().set_checkpoint(&checkpoint)?;
+    commit_genesis_block(config, original_database, database_transaction, roots)
+}
+
+/// The height of the genesis block.
+fn genesis_height(config: &Config) -> anyhow::Result<BlockHeight> {
+    let initial_state = config.chain_conf.initial_state.as_ref();
+    // The initial height is defined by the `ChainConfig`.
+    // If it is `None` then it will be zero.
+    let height = initial_state
+        .and_then(|state| state.height)
+        .unwrap_or_else(|| 0u32.into());
+    // The regenesis continues the previous chain, so the genesis block follows its last block.
+    if initial_state
+        .and_then(|state| state.last_block_id)
+        .is_some()
+    {
+        Ok(u32::from(height)
+            .checked_add(1)
+            .ok_or_else(|| anyhow!("The height of the snapshot is too big"))?
+            .into())
+    } else {
+        Ok(height)
+    }
+}
+
+/// Inserts the state of the chain config and returns its roots.
+fn init_chain_config_state(
+    database: &mut Database,
+    state: &Option<StateConfig>,
+) -> anyhow::Result<GenesisRoots> {
+    Ok(GenesisRoots {
+        coins_root: init_coin_state(database, state)?,
+        contracts_root: init_contracts(database, state)?,
+        messages_root: init_da_messages(database, state)?,
+    })
+}
+
+/// Creates the genesis block committing to the `roots` and commits it
+/// along with the `database_transaction`.
+fn commit_genesis_block(
+    config: &Config,
+    original_database: &Database,
+    mut database_transaction: StorageTransaction<Database>,
+    roots: GenesisRoots,
+) -> anyhow::Result<()> {
+    let height = genesis_height(config)?;
+    // The id of the last block of the chain from which the state was taken.
+    let previous_chain_block_id = config
+        .chain_conf
+        .initial_state
+        .as_ref()
+        .and_then(|state| state.last_block_id)
+        .map(BlockId::from);
     let chain_config_hash = config.chain_conf.root()?.into();
-    let coins_root = init_coin_state(database, &config.chain_conf.initial_state)?.into();
-    let contracts_root =
-        init_contracts(database, &config.chain_conf.initial_state)?.into();
-    let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
-    let messages_root = messages_root.into();
 
     let genesis = Genesis {
         chain_config_hash,
-        coins_root,
-        contracts_root,
-        messages_root,
+        coins_root: roots.coins_root.into(),
+        contracts_root: roots.contracts_root.into(),
+        messages_root: roots.messages_root.into(),
+        previous_chain_block_id,
     };
 
     let block = Block::new(
@@ -110,14 +253,7 @@ fn import_genesis_block(
             consensus: ConsensusHeader::<Empty> {
                 // The genesis is a first block, so previous root is zero.
                 prev_root: Bytes32::zeroed(),
-                // The initial height is defined by the `ChainConfig`.
-                // If it is `None` then it will be zero.
-                height: config
-                    .chain_conf
-                    .initial_state
-                    .as_ref()
-                    .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
-                    .unwrap_or_else(|| 0u32.into()),
+                height,
                 time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
                 generated: Empty,
             },
@@ -127,10 +263,10 @@ fn import_genesis_block(
         &[],
     );
 
-    let block_id = block.id();
-    database
+    database_transaction
+        .as_mut()
         .storage::<FuelBlocks>()
-        .insert(&block_id, &block.compress())?;
+        .insert(block.header().height(), &block.compress())?;
     let consensus = Consensus::Genesis(genesis);
     let block = SealedBlock {
         entity: block,
@@ -153,6 +289,118 @@ fn import_genesis_block(
     Ok(())
 }
 
+/// Imports the state from the `snapshot` into the `database`. Each chunk is
+/// committed in a separate database transaction, so only one chunk is kept in memory.
+fn import_snapshot(
+    database: &Database,
+    snapshot: &SnapshotReader,
+    height: BlockHeight,
+) -> anyhow::Result<GenesisRoots> {
+    // The genesis block is committed after the state. If the node was stopped
+    // in the middle of the import, the state is partially in the database.
+    let is_empty = database.iter_coin_configs().next().is_none()
+        && database.iter_contract_configs().next().is_none()
+        && database.iter_message_configs().next().is_none()
+        && database.iter_spent_messages().next().is_none();
+    if !is_empty {
+        return Err(anyhow!(
+            "The database contains a partially imported snapshot, it should be removed"
+        ))
+    }
+
+    let mut coins_tree = binary::in_memory::MerkleTree::new();
+    let mut generated_output_index: u64 = 0;
+    for chunk in snapshot.chunks::<CoinConfig>() {
+        let mut transaction = database.transaction();
+        for coin in chunk? {
+            let root = init_coin(
+                transaction.as_mut(),
+                &coin,
+                &mut generated_output_index,
+                height,
+            )?;
+            coins_tree.push(root.as_slice());
+        }
+        transaction.commit()?;
+    }
+
+    let mut contract_ids = vec![];
+    for chunk in snapshot.chunks::<ContractConfig>() {
+        let mut transaction = database.transaction();
+        for contract in chunk? {
+            let contract_id = init_contract(
+                transaction.as_mut(),
+                &contract,
+                contract_ids.len(),
+                height,
+            )?;
+            contract_ids.push(contract_id);
+        }
+        transaction.commit()?;
+    }
+
+    for chunk in snapshot.chunks::<ContractStateConfig>() {
+        let mut transaction = database.transaction();
+        for entry in chunk? {
+            if transaction
+                .as_mut()
+                .storage::<ContractsState>()
+                .insert(&(&entry.contract_id, &entry.key).into(), &entry.value)?
+                .is_some()
+            {
+                return Err(anyhow!("Contract state should not exist"))
+            }
+        }
+        transaction.commit()?;
+    }
+
+    for chunk in snapshot.chunks::<ContractBalanceConfig>() {
+        let mut transaction = database.transaction();
+        for entry in chunk? {
+            if transaction
+                .as_mut()
+                .storage::<ContractsAssets>()
+                .insert(&(&entry.contract_id, &entry.asset_id).into(), &entry.amount)?
+                .is_some()
+            {
+                return Err(anyhow!("Contract balance should not exist"))
+            }
+        }
+        transaction.commit()?;
+    }
+
+    // The roots of contracts are known only when the state and balances are inserted.
+    let mut contracts_tree = binary::in_memory::MerkleTree::new();
+    let mut db = database.clone();
+    for contract_id in contract_ids {
+        contracts_tree.push(ContractRef::new(&mut db, contract_id).root()?.as_slice());
+    }
+
+    let mut message_tree = binary::in_memory::MerkleTree::new();
+    for chunk in snapshot.chunks::<MessageConfig>() {
+        let mut transaction = database.transaction();
+        for message in chunk? {
+            let root = init_da_message(transaction.as_mut(), &message)?;
+            message_tree.push(root.as_slice());
+        }
+        transaction.commit()?;
+    }
+
+    for chunk in snapshot.chunks::<SpentMessageConfig>() {
+        let mut transaction = database.transaction();
+        for entry in chunk? {
+            init_spent_message(transaction.as_mut(), &entry.nonce)?;
+        }
+        transaction.commit()?;
+    }
+
+    Ok(GenesisRoots {
+        coins_root: coins_tree.root(),
+        contracts_root: contracts_tree.root(),
+        messages_root: message_tree.root(),
+    })
+}
+
 fn init_coin_state(
     db: &mut Database,
     state: &Option<StateConfig>,
@@ -163,60 +411,69 @@ fn init_coin_state(
     if let Some(state) = &state {
         if let Some(coins) = &state.coins {
             for coin in coins {
-                let utxo_id = UtxoId::new(
-                    // generated transaction id([0..[out_index/255]])
-                    coin.tx_id.unwrap_or_else(|| {
-                        Bytes32::try_from(
-                            (0..(Bytes32::LEN - WORD_SIZE))
-                                .map(|_| 0u8)
-                                .chain(
-                                    (generated_output_index / 255)
-                                        .to_be_bytes()
-                                        .into_iter(),
-                                )
-                                .collect_vec()
-                                .as_slice(),
-                        )
-                        .expect("Incorrect genesis transaction id byte length")
-                    }),
-                    coin.output_index.unwrap_or_else(|| {
-                        generated_output_index += 1;
-                        (generated_output_index % 255) as u8
-                    }),
-                );
-
-                let coin = CompressedCoin {
-                    owner: coin.owner,
-                    amount: coin.amount,
-                    asset_id: coin.asset_id,
-                    maturity: coin.maturity.unwrap_or_default(),
-                    tx_pointer: TxPointer::new(
-                        coin.tx_pointer_block_height
-                            .map(|b| b.into())
-                            .unwrap_or_default(),
-                        coin.tx_pointer_tx_idx.unwrap_or_default(),
-                    ),
-                };
-
-                // ensure coin can't point to blocks in the future
-                if coin.tx_pointer.block_height()
-                    > state.height.unwrap_or_default().into()
-                {
-                    return Err(anyhow!(
-                        "coin tx_pointer height cannot be greater than genesis block"
-                    ))
-                }
-
-                if db.storage::<Coins>().insert(&utxo_id, &coin)?.is_some() {
-                    return Err(anyhow!("Coin should not exist"))
-                }
-                coins_tree.push(coin.root()?.as_slice())
+                let root = init_coin(
+                    db,
+                    coin,
+                    &mut generated_output_index,
+                    state.height.unwrap_or_default(),
+                )?;
+                coins_tree.push(root.as_slice())
             }
         }
     }
     Ok(coins_tree.root())
 }
 
+fn init_coin(
+    db: &mut Database,
+    coin: &CoinConfig,
+    generated_output_index: &mut u64,
+    height: BlockHeight,
+) -> anyhow::Result<MerkleRoot> {
+    let utxo_id = UtxoId::new(
+        // generated transaction id([0..[out_index/255]])
+        coin.tx_id.unwrap_or_else(|| {
+            Bytes32::try_from(
+                (0..(Bytes32::LEN - WORD_SIZE))
+                    .map(|_| 0u8)
+                    .chain((*generated_output_index / 255).to_be_bytes().into_iter())
+                    .collect_vec()
+                    .as_slice(),
+            )
+            .expect("Incorrect genesis transaction id byte length")
+        }),
+        coin.output_index.unwrap_or_else(|| {
+            *generated_output_index += 1;
+            (*generated_output_index % 255) as u8
+        }),
+    );
+
+    let coin = CompressedCoin {
+        owner: coin.owner,
+        amount: coin.amount,
+        asset_id: coin.asset_id,
+        maturity: coin.maturity.unwrap_or_default(),
+        tx_pointer: TxPointer::new(
+            coin.tx_pointer_block_height
+                .map(|b| b.into())
+                .unwrap_or_default(),
+            coin.tx_pointer_tx_idx.unwrap_or_default(),
+        ),
+    };
+
+    // ensure coin can't point to blocks in the future
+    if coin.tx_pointer.block_height() > height.into() {
+        return Err(anyhow!(
+            "coin tx_pointer height cannot be greater than genesis block"
+        ))
+    }
+
+    if db.storage::<Coins>().insert(&utxo_id, &coin)?.is_some() {
+        return Err(anyhow!("Coin should not exist"))
+    }
+    coin.root()
+}
+
 fn init_contracts(
     db: &mut Database,
     state: &Option<StateConfig>,
@@ -227,78 +484,12 @@ fn init_contracts(
         if let Some(contracts) = &state.contracts {
             for (generated_output_index, contract_config) in contracts.iter().enumerate()
             {
-                let contract = Contract::from(contract_config.code.as_slice());
-                let salt = contract_config.salt;
-                let root = contract.root();
-                let contract_id =
-                    contract.id(&salt, &root, &Contract::default_state_root());
-                let utxo_id = if let (Some(tx_id), Some(output_idx)) =
-                    (contract_config.tx_id, contract_config.output_index)
-                {
-                    UtxoId::new(tx_id, output_idx)
-                } else {
-                    UtxoId::new(
-                        // generated transaction id([0..[out_index/255]])
-                        Bytes32::try_from(
-                            (0..(Bytes32::LEN - WORD_SIZE))
-                                .map(|_| 0u8)
-                                .chain(
-                                    (generated_output_index as u64 / 255)
-                                        .to_be_bytes()
-                                        .into_iter(),
-                                )
-                                .collect_vec()
-                                .as_slice(),
-                        )
-                        .expect("Incorrect genesis transaction id byte length"),
-                        generated_output_index as u8,
-                    )
-                };
-                let tx_pointer = if let (Some(block_height), Some(tx_idx)) = (
-                    contract_config.tx_pointer_block_height,
-                    contract_config.tx_pointer_tx_idx,
-                ) {
-                    TxPointer::new(block_height.into(), tx_idx)
-                } else {
-                    TxPointer::default()
-                };
-
-                if tx_pointer.block_height() > state.height.unwrap_or_default().into() {
-                    return Err(anyhow!(
-                        "contract tx_pointer cannot be greater than genesis block"
-                    ))
-                }
-
-                // insert contract code
-                if db
-                    .storage::<ContractsRawCode>()
-                    .insert(&contract_id, contract.as_ref())?
-                    .is_some()
-                {
-                    return Err(anyhow!("Contract code should not exist"))
-                }
-
-                // insert contract root
-                if db
-                    .storage::<ContractsInfo>()
-                    .insert(&contract_id, &(salt, root))?
-                    .is_some()
-                {
-                    return Err(anyhow!("Contract info should not exist"))
-                }
-                if db
-                    .storage::<ContractsLatestUtxo>()
-                    .insert(
-                        &contract_id,
-                        &ContractUtxoInfo {
-                            utxo_id,
-                            tx_pointer,
-                        },
-                    )?
-                    .is_some()
-                {
-                    return Err(anyhow!("Contract utxo should not exist"))
-                }
+                let contract_id = init_contract(
+                    db,
+                    contract_config,
+                    generated_output_index,
+                    state.height.unwrap_or_default(),
+                )?;
                 init_contract_state(db, &contract_id, contract_config)?;
                 init_contract_balance(db, &contract_id, contract_config)?;
                 contracts_tree
@@ -309,6 +500,87 @@ fn init_contracts(
     Ok(contracts_tree.root())
 }
 
+/// Inserts the code, info and the latest UTXO of the contract.
+fn init_contract(
+    db: &mut Database,
+    contract_config: &ContractConfig,
+    generated_output_index: usize,
+    height: BlockHeight,
+) -> anyhow::Result<ContractId> {
+    let contract = Contract::from(contract_config.code.as_slice());
+    let salt = contract_config.salt;
+    let root = contract.root();
+    let contract_id = contract.id(&salt, &root, &Contract::default_state_root());
+    let utxo_id = if let (Some(tx_id), Some(output_idx)) =
+        (contract_config.tx_id, contract_config.output_index)
+    {
+        UtxoId::new(tx_id, output_idx)
+    } else {
+        UtxoId::new(
+            // generated transaction id([0..[out_index/255]])
+            Bytes32::try_from(
+                (0..(Bytes32::LEN - WORD_SIZE))
+                    .map(|_| 0u8)
+                    .chain(
+                        (generated_output_index as u64 / 255)
+                            .to_be_bytes()
+                            .into_iter(),
+                    )
+                    .collect_vec()
+                    .as_slice(),
+            )
+            .expect("Incorrect genesis transaction id byte length"),
+            generated_output_index as u8,
+        )
+    };
+    let tx_pointer = if let (Some(block_height), Some(tx_idx)) = (
+        contract_config.tx_pointer_block_height,
+        contract_config.tx_pointer_tx_idx,
+    ) {
+        TxPointer::new(block_height.into(), tx_idx)
+    } else {
+        TxPointer::default()
+    };
+
+    if tx_pointer.block_height() > height.into() {
+        return Err(anyhow!(
+            "contract tx_pointer cannot be greater than genesis block"
+        ))
+    }
+
+    // insert contract code
+    if db
+        .storage::<ContractsRawCode>()
+        .insert(&contract_id, contract.as_ref())?
+        .is_some()
+    {
+        return Err(anyhow!("Contract code should not exist"))
+    }
+
+    // insert contract root
+    if db
+        .storage::<ContractsInfo>()
+        .insert(&contract_id, &(salt, root))?
+        .is_some()
+    {
+        return Err(anyhow!("Contract info should not exist"))
+    }
+    if db
+        .storage::<ContractsLatestUtxo>()
+        .insert(
+            &contract_id,
+            &ContractUtxoInfo {
+                utxo_id,
+                tx_pointer,
+            },
+        )?
+        .is_some()
+    {
+        return Err(anyhow!("Contract utxo should not exist"))
+    }
+    Ok(contract_id)
+}
+
 fn init_contract_state(
     db: &mut Database,
     contract_id: &ContractId,
@@ -337,23 +609,8 @@ fn init_da_messages(
     if let Some(state) = &state {
         if let Some(message_state) = &state.messages {
             for msg in message_state {
-                let message = Message {
-                    sender: msg.sender,
-                    recipient: msg.recipient,
-                    nonce: msg.nonce,
-                    amount: msg.amount,
-                    data: msg.data.clone(),
-                    da_height: msg.da_height,
-                };
-
-                if db
-                    .storage::<Messages>()
-                    .insert(message.id(), &message)?
-                    .is_some()
-                {
-                    return Err(anyhow!("Message should not exist"))
-                }
-                message_tree.push(message.root()?.as_slice());
+                let root = init_da_message(db, msg)?;
+                message_tree.push(root.as_slice());
             }
         }
     }
@@ -361,6 +618,40 @@ fn init_da_messages(
     Ok(message_tree.root())
 }
 
+fn init_da_message(db: &mut Database, msg: &MessageConfig) -> anyhow::Result<MerkleRoot> {
+    let message = Message {
+        sender: msg.sender,
+        recipient: msg.recipient,
+        nonce: msg.nonce,
+        amount: msg.amount,
+        data: msg.data.clone(),
+        da_height: msg.da_height,
+    };
+
+    if db
+        .storage::<Messages>()
+        .insert(message.id(), &message)?
+        .is_some()
+    {
+        return Err(anyhow!("Message should not exist"))
+    }
+    message.root()
+}
+
+/// Marks the message processed by the previous chain as spent, so it can't be
+/// spent one more time when the relayer imports it from the DA layer.
+fn init_spent_message(db: &mut Database, nonce: &Nonce) -> anyhow::Result<()> {
+    if db.storage::<Messages>().contains_key(nonce)? {
+        return Err(anyhow!(
+            "Spent message {nonce} can't be a part of the state"
+        ))
+    }
+    if db.storage::<SpentMessages>().insert(nonce, &())?.is_some() {
+        return Err(anyhow!("Spent message should not exist"))
+    }
+    Ok(())
+}
+
 fn init_contract_balance(
     db: &mut Database,
     contract_id: &ContractId,
@@ -391,10 +682,15 @@ mod tests {
     };
     use fuel_core_chain_config::{
         ChainConfig,
+        CheckpointConfig,
         CoinConfig,
         MessageConfig,
+        SnapshotEncoding,
+    };
+    use fuel_core_storage::{
+        Result as StorageResult,
+        StorageAsRef,
     };
-    use fuel_core_storage::StorageAsRef;
     use fuel_core_types::{
         blockchain::primitives::{
             BlockHeight,
@@ -693,6 +989,251 @@ mod tests {
         assert_eq!(test_balance, ret)
     }
 
+    fn state_for_snapshot(rng: &mut StdRng) -> StateConfig {
+        let contract = Contract::from(op::ret(0x10).to_bytes().to_vec());
+        StateConfig {
+            coins: Some(
+                (0..3)
+                    .map(|_| CoinConfig {
+                        tx_id: None,
+                        output_index: None,
+                        tx_pointer_block_height: None,
+                        tx_pointer_tx_idx: None,
+                        maturity: None,
+                        owner: rng.gen(),
+                        amount: rng.gen(),
+                        asset_id: rng.gen(),
+                    })
+                    .collect(),
+            ),
+            contracts: Some(vec![ContractConfig {
+                code: contract.into(),
+                salt: rng.gen(),
+                state: Some(vec![(rng.gen(), rng.gen()), (rng.gen(), rng.gen())]),
+                balances: Some(vec![(rng.gen(), rng.gen()), (rng.gen(), rng.gen())]),
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+            }]),
+            messages: Some(vec![MessageConfig {
+                sender: rng.gen(),
+                recipient: rng.gen(),
+                nonce: rng.gen(),
+                amount: rng.gen(),
+                data: vec![rng.gen()],
+                da_height: DaBlockHeight(0),
+            }]),
+            height: Some(BlockHeight::from(5u32)),
+            last_block_id: None,
+            spent_messages: Some(vec![rng.gen()]),
+        }
+    }
+
+    async fn genesis_from_snapshot_imports_the_same_state(encoding: SnapshotEncoding) {
+        let mut rng = StdRng::seed_from_u64(10);
+        let mut config = Config::local_node();
+        config.chain_conf.initial_state = Some(state_for_snapshot(&mut rng));
+        let original_db = Database::default();
+        maybe_initialize_state(&config, &original_db).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        fuel_core_chain_config::write_snapshot(
+            &original_db,
+            config.chain_conf.clone(),
+            dir.path(),
+            1,
+            encoding,
+        )
+        .unwrap();
+        let snapshot = SnapshotReader::open(dir.path()).unwrap();
+        let config = Config {
+            chain_conf: snapshot.chain_config().unwrap(),
+            genesis_snapshot: Some(snapshot),
+            ..Config::local_node()
+        };
+        let db = Database::default();
+        maybe_initialize_state(&config, &db).unwrap();
+
+        // The genesis block continues the chain of the snapshot.
+        assert_eq!(db.latest_height().unwrap(), BlockHeight::from(6u32));
+        assert_eq!(
+            db.get_genesis().unwrap().previous_chain_block_id,
+            Some(original_db.latest_block_id().unwrap())
+        );
+        assert_eq!(
+            db.get_coin_config().unwrap(),
+            original_db.get_coin_config().unwrap()
+        );
+        assert_eq!(
+            db.get_contract_config().unwrap(),
+            original_db.get_contract_config().unwrap()
+        );
+        assert_eq!(
+            db.get_message_config().unwrap(),
+            original_db.get_message_config().unwrap()
+        );
+        assert_eq!(
+            db.iter_spent_messages()
+                .collect::<StorageResult<Vec<_>>>()
+                .unwrap(),
+            original_db
+                .iter_spent_messages()
+                .collect::<StorageResult<Vec<_>>>()
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn genesis_from_json_snapshot_imports_the_same_state() {
+        genesis_from_snapshot_imports_the_same_state(SnapshotEncoding::Json).await
+    }
+
+    #[tokio::test]
+    async fn genesis_from_binary_snapshot_imports_the_same_state() {
+        genesis_from_snapshot_imports_the_same_state(SnapshotEncoding::Binary).await
+    }
+
+    #[tokio::test]
+    async fn regenesis_from_state_config_continues_the_previous_chain() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let mut config = Config::local_node();
+        config.chain_conf.initial_state = Some(state_for_snapshot(&mut rng));
+        let original_db = Database::default();
+        maybe_initialize_state(&config, &original_db).unwrap();
+
+        let state = StateConfig::generate_state_config(original_db.clone()).unwrap();
+        let spent_messages = state.spent_messages.clone().unwrap();
+        let mut config = Config::local_node();
+        config.chain_conf.initial_state = Some(state);
+        let db = Database::default();
+        maybe_initialize_state(&config, &db).unwrap();
+
+        let genesis = db.get_genesis().unwrap();
+        assert_eq!(db.latest_height().unwrap(), BlockHeight::from(6u32));
+        assert_eq!(
+            genesis.previous_chain_block_id,
+            Some(original_db.latest_block_id().unwrap())
+        );
+        // The previous chain is a part of the genesis commitment.
+        assert_ne!(
+            genesis.root().unwrap(),
+            Genesis {
+                previous_chain_block_id: None,
+                ..genesis.clone()
+            }
+            .root()
+            .unwrap()
+        );
+        for nonce in spent_messages {
+            assert!(db.is_message_spent(&nonce).unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn spent_message_can_not_be_a_part_of_the_state() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let mut state = state_for_snapshot(&mut rng);
+        let nonce = state.messages.as_ref().unwrap()[0].nonce;
+        state.spent_messages = Some(vec![nonce]);
+        let mut config = Config::local_node();
+        config.chain_conf.initial_state = Some(state);
+
+        assert!(maybe_initialize_state(&config, &Database::default()).is_err());
+    }
+
+    #[tokio::test]
+    async fn genesis_from_snapshot_fails_on_partially_imported_state() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let mut config = Config::local_node();
+        config.chain_conf.initial_state = Some(state_for_snapshot(&mut rng));
+        let original_db = Database::default();
+        maybe_initialize_state(&config, &original_db).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        fuel_core_chain_config::write_snapshot(
+            &original_db,
+            config.chain_conf.clone(),
+            dir.path(),
+            1,
+            SnapshotEncoding::Json,
+        )
+        .unwrap();
+        let snapshot = SnapshotReader::open(dir.path()).unwrap();
+        let config = Config {
+            chain_conf: snapshot.chain_config().unwrap(),
+            genesis_snapshot: Some(snapshot),
+            ..Config::local_node()
+        };
+
+        // The state without the genesis block.
+        let mut db = Database::default();
+        let coins = original_db.get_coin_config().unwrap().unwrap();
+        init_coin(&mut db, &coins[0], &mut 0, BlockHeight::from(5u32)).unwrap();
+
+        assert!(maybe_initialize_state(&config, &db).is_err());
+    }
+
+    /// Creates the snapshot of the chain that has more coins than its genesis state.
+    fn snapshot_after_genesis(config: &Config, dir: &std::path::Path) -> Database {
+        let mut rng = StdRng::seed_from_u64(10);
+        let original_db = Database::default();
+        maybe_initialize_state(config, &original_db).unwrap();
+        let coin = state_for_snapshot(&mut rng).coins.unwrap().remove(0);
+        init_coin(&mut original_db.clone(), &coin, &mut 100, 0u32.into()).unwrap();
+        fuel_core_chain_config::write_snapshot(
+            &original_db,
+            config.chain_conf.clone(),
+            dir,
+            1,
+            SnapshotEncoding::Json,
+        )
+        .unwrap();
+        original_db
+    }
+
+    #[tokio::test]
+    async fn checkpoint_snapshot_is_imported_with_the_genesis_block() {
+        let dir = tempfile::tempdir().unwrap();
+        let original_db = snapshot_after_genesis(&Config::local_node(), dir.path());
+        let checkpoint = CheckpointConfig {
+            height: original_db.latest_height().unwrap(),
+            block_id: original_db.latest_block_id().unwrap().into(),
+        };
+        let mut config = Config::local_node();
+        config.chain_conf.checkpoint = Some(checkpoint);
+        config.checkpoint_snapshot = Some(SnapshotReader::open(dir.path()).unwrap());
+
+        let db = Database::default();
+        maybe_initialize_state(&config, &db).unwrap();
+
+        // The genesis block of the network along with the state at the checkpoint.
+        assert_eq!(
+            db.latest_block_id().unwrap(),
+            original_db.latest_block_id().unwrap()
+        );
+        assert_eq!(
+            db.get_coin_config().unwrap(),
+            original_db.get_coin_config().unwrap()
+        );
+        assert_eq!(db.get_checkpoint().unwrap(), Some(checkpoint));
+    }
+
+    #[tokio::test]
+    async fn checkpoint_snapshot_should_be_taken_at_the_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let original_db = snapshot_after_genesis(&Config::local_node(), dir.path());
+        let mut config = Config::local_node();
+        config.chain_conf.checkpoint = Some(CheckpointConfig {
+            height: original_db.latest_height().unwrap(),
+            block_id: Default::default(),
+        });
+        config.checkpoint_snapshot = Some(SnapshotReader::open(dir.path()).unwrap());
+
+        let db = Database::default();
+        assert!(maybe_initialize_state(&config, &db).is_err());
+        assert!(db.ids_of_latest_block().unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn coin_tx_pointer_cant_exceed_genesis_height() {
         let service_config = Config {
@@ -0,0 +1,225 @@
+//! The service prunes the transactions, receipts, statuses and the owners index
+//! of the blocks outside of the retention window after each imported block.
+
+use crate::{
+    database::Database,
+    service::config::PruningConfig,
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    services::block_importer::ImportResult,
+};
+use std::sync::Arc;
+use tokio_stream::StreamExt;
+
+pub type Service = ServiceRunner<Task>;
+
+pub struct Task {
+    config: PruningConfig,
+    database: Database,
+    block_events: BoxStream<Arc<ImportResult>>,
+}
+
+impl Task {
+    /// Prunes the history of all blocks outside of the retention window ending
+    /// at the `latest` height. The latest block is always retained.
+    async fn prune(&self, latest: BlockHeight) -> anyhow::Result<()> {
+        let retained = self.config.retained_blocks.max(1);
+        let prune_up_to = match u32::from(latest).checked_sub(retained) {
+            Some(height) => BlockHeight::from(height),
+            None => return Ok(()),
+        };
+
+        let database = self.database.clone();
+        let pruned =
+            tokio::task::spawn_blocking(move || database.prune_up_to(&prune_up_to))
+                .await??;
+        if pruned > 0 {
+            tracing::debug!(
+                "Pruned the history of {} blocks up to the height {}",
+                pruned,
+                prune_up_to
+            );
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "Pruner";
+
+    type SharedData = ();
+    type Task = Task;
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(self, _: &StateWatcher) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.block_events.next() => {
+                if let Some(result) = result {
+                    let height = *result.sealed_block.entity.header().height();
+                    self.prune(height).await?;
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+        }
+
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    config: PruningConfig,
+    database: Database,
+    block_events: BoxStream<Arc<ImportResult>>,
+) -> Service {
+    Service::new(Task {
+        config,
+        database,
+        block_events,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_services::Service as ServiceTrait;
+    use fuel_core_storage::{
+        tables::{
+            FuelBlocks,
+            Transactions,
+        },
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::{
+                Block,
+                PartialFuelBlock,
+            },
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+            SealedBlock,
+        },
+        fuel_tx::{
+            Transaction,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+    };
+
+    fn block(height: u32) -> Block {
+        let transaction: Transaction =
+            TransactionBuilder::script(vec![height as u8], vec![])
+                .finalize_as_transaction();
+        PartialFuelBlock::new(
+            PartialBlockHeader {
+                application: Default::default(),
+                consensus: ConsensusHeader::<Empty> {
+                    height: height.into(),
+                    ..Default::default()
+                },
+            },
+            vec![transaction],
+        )
+        .generate(&[])
+    }
+
+    async fn prune_blocks(retained_blocks: u32) -> (Database, Vec<Block>) {
+        let mut database = Database::default();
+        let blocks = (0..5).map(block).collect::<Vec<_>>();
+        for block in &blocks {
+            database
+                .storage_as_mut::<FuelBlocks>()
+                .insert(block.header().height(), &block.compress())
+                .unwrap();
+            for transaction in block.transactions() {
+                database
+                    .storage_as_mut::<Transactions>()
+                    .insert(&transaction.id(), transaction)
+                    .unwrap();
+            }
+        }
+        let latest = blocks.last().cloned().unwrap();
+        let block_events: BoxStream<Arc<ImportResult>> =
+            Box::pin(tokio_stream::iter(vec![Arc::new(ImportResult {
+                sealed_block: SealedBlock {
+                    entity: latest,
+                    consensus: Default::default(),
+                },
+                tx_status: vec![],
+            })]));
+
+        let service = new_service(
+            PruningConfig { retained_blocks },
+            database.clone(),
+            block_events,
+        );
+        service.start_and_await().await.unwrap();
+        service.await_stop().await.unwrap();
+        (database, blocks)
+    }
+
+    #[tokio::test]
+    async fn prunes_blocks_outside_of_retention_window() {
+        let (database, blocks) = prune_blocks(2).await;
+
+        assert_eq!(database.pruned_height().unwrap(), Some(2u32.into()));
+        for block in &blocks {
+            let is_kept = u32::from(*block.header().height()) > 2;
+            let tx_id = block.transactions()[0].id();
+            assert_eq!(
+                database
+                    .storage::<Transactions>()
+                    .contains_key(&tx_id)
+                    .unwrap(),
+                is_kept
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn zero_retained_blocks_keeps_latest_block() {
+        let (database, blocks) = prune_blocks(0).await;
+
+        assert_eq!(database.pruned_height().unwrap(), Some(3u32.into()));
+        let latest = blocks.last().unwrap().transactions()[0].id();
+        assert!(database
+            .storage::<Transactions>()
+            .contains_key(&latest)
+            .unwrap());
+    }
+}
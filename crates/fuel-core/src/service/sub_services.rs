@@ -24,6 +24,7 @@ use crate::{
     },
 };
 use fuel_core_poa::Trigger;
+use fuel_core_txpool::ports::BlockImporter;
 use std::sync::Arc;
 use tokio::sync::{
     Mutex,
@@ -140,6 +141,21 @@ pub fn init_sub_services(
     });
     let poa_adapter = PoAAdapter::new(poa.as_ref().map(|service| service.shared.clone()));
 
+    #[cfg(feature = "p2p")]
+    anyhow::ensure!(
+        !(production_enabled && config.sync.headers_only),
+        "The block production requires the state, so it can't be enabled \
+        when the sync imports only the headers"
+    );
+    // The state of the database imported at the checkpoint can't be used
+    // before the headers up to the checkpoint are synced.
+    let checkpoint = database.get_checkpoint()?;
+    anyhow::ensure!(
+        !(production_enabled
+            && checkpoint.map_or(false, |c| c.height > *last_block.header().height())),
+        "The block production is not possible until the headers up to the checkpoint \
+        are synced"
+    );
     #[cfg(feature = "p2p")]
     let sync = (!production_enabled)
         .then(|| {
@@ -148,7 +164,13 @@ pub fn init_sub_services(
                 p2p_adapter,
                 importer_adapter.clone(),
                 verifier,
-                config.sync,
+                fuel_core_sync::Config {
+                    checkpoint: checkpoint.map(|c| fuel_core_sync::Checkpoint {
+                        height: c.height,
+                        block_id: c.block_id.into(),
+                    }),
+                    ..config.sync
+                },
             )
         })
         .transpose()?;
@@ -171,15 +193,29 @@ pub fn init_sub_services(
             max_tx: config.txpool.max_tx,
             max_depth: config.txpool.max_depth,
             transaction_parameters: config.chain_conf.transaction_parameters,
+            gas_costs: config.chain_conf.gas_costs.clone(),
             consensus_key: config.consensus_key.clone(),
+            backup_dir: config.backup_dir.clone(),
+            websocket: config.websocket.clone(),
+            query_limits: config.query_limits.clone(),
+            admin_token: config.admin_token.clone(),
         },
         schema,
         Box::new(database.clone()),
         Box::new(tx_pool_adapter),
         Box::new(producer_adapter),
         Box::new(poa_adapter),
+        Box::new(importer_adapter.clone()),
     )?;
 
+    let pruner = config.pruning.clone().map(|pruning| {
+        crate::service::pruner::new_service(
+            pruning,
+            database.clone(),
+            importer_adapter.block_events(),
+        )
+    });
+
     let shared = SharedState {
         txpool: txpool.shared.clone(),
         #[cfg(feature = "p2p")]
@@ -204,6 +240,10 @@ pub fn init_sub_services(
         services.push(Box::new(poa));
     }
 
+    if let Some(pruner) = pruner {
+        services.push(Box::new(pruner));
+    }
+
     #[cfg(feature = "relayer")]
     if let Some(relayer) = relayer_service {
         services.push(Box::new(relayer));
@@ -1,6 +1,7 @@
 use crate::{
     database::{
         Column,
+        Error as DatabaseError,
         Result as DatabaseResult,
     },
     state::in_memory::transaction::MemoryTransactionView,
@@ -11,6 +12,7 @@ use fuel_core_storage::iter::{
 };
 use std::{
     fmt::Debug,
+    path::Path,
     sync::Arc,
 };
 
@@ -97,7 +99,16 @@ pub trait Transaction {
 
 pub type TransactionResult<T> = core::result::Result<T, TransactionError>;
 
-pub trait TransactableStorage: BatchOperations + Debug + Send + Sync {}
+pub trait TransactableStorage: BatchOperations + Debug + Send + Sync {
+    /// Creates a consistent copy of the storage in the new directory at the `path`.
+    /// The storage stays available for reads and writes during the copying.
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        Err(DatabaseError::Other(anyhow::anyhow!(
+            "The storage doesn't support checkpoints, can't create one at {}",
+            path.display()
+        )))
+    }
+}
 
 #[derive(Clone, Debug)]
 pub enum TransactionError {
@@ -55,6 +55,19 @@ impl MemoryTransactionView {
         }
     }
 
+    /// Returns the keys modified by the transaction with their columns.
+    pub fn changed_keys(&self) -> Vec<(Vec<u8>, Column)> {
+        self.changes
+            .lock()
+            .expect("poisoned lock")
+            .values()
+            .map(|operation| match operation {
+                WriteOperation::Insert(key, column, _)
+                | WriteOperation::Remove(key, column) => (key.clone(), *column),
+            })
+            .collect()
+    }
+
     pub fn commit(&self) -> DatabaseResult<()> {
         self.data_source.batch_write(
             &mut self
@@ -14,6 +14,7 @@ use crate::{
         WriteOperation,
     },
 };
+use clap::ValueEnum;
 #[cfg(feature = "metrics")]
 use fuel_core_metrics::core_metrics::DATABASE_METRICS;
 use fuel_core_storage::iter::{
@@ -21,8 +22,12 @@ use fuel_core_storage::iter::{
     IntoBoxedIter,
 };
 use rocksdb::{
+    checkpoint::Checkpoint,
+    BlockBasedOptions,
     BoundColumnFamily,
+    Cache,
     ColumnFamilyDescriptor,
+    DBCompactionStyle,
     DBCompressionType,
     DBWithThreadMode,
     IteratorMode,
@@ -39,6 +44,74 @@ use std::{
 };
 
 type DB = DBWithThreadMode<MultiThreaded>;
+
+/// The tuning of the RocksDB. The defaults fit a regular node, while the memory
+/// consumption can be reduced by decreasing the cache and write buffer sizes.
+#[derive(Clone, Debug)]
+pub struct RocksDbConfig {
+    /// The capacity in bytes of the LRU block cache shared by all columns.
+    pub cache_capacity: usize,
+    /// The maximum number of files opened by the database. `-1` means no limit.
+    pub max_open_files: i32,
+    /// The size in bytes of a single memtable of each column.
+    pub write_buffer_size: usize,
+    /// The total size in bytes of memtables across all columns. `0` means no limit.
+    pub db_write_buffer_size: usize,
+    /// The number of bits per key of the bloom filters used by the columns with
+    /// point lookups. `0` disables the bloom filters.
+    pub bloom_filter_bits_per_key: f64,
+    /// The part of the memtable used by the prefix bloom filter of the columns
+    /// indexed by the owner. `0` disables the filter.
+    pub memtable_prefix_bloom_ratio: f64,
+    /// The compaction style of the columns of the history: the transactions, their
+    /// statuses, receipts and traces, and the blocks.
+    pub history_compaction_style: CompactionStyle,
+}
+
+/// The compaction style of the RocksDB column.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum CompactionStyle {
+    /// Sorts the data into levels of growing size, which favors the reads.
+    Level,
+    /// Merges the files of similar size, which reduces the write amplification
+    /// of the data that is written once and never updated.
+    Universal,
+}
+
+impl From<CompactionStyle> for DBCompactionStyle {
+    fn from(style: CompactionStyle) -> Self {
+        match style {
+            CompactionStyle::Level => DBCompactionStyle::Level,
+            CompactionStyle::Universal => DBCompactionStyle::Universal,
+        }
+    }
+}
+
+impl RocksDbConfig {
+    pub const DEFAULT_CACHE_CAPACITY: usize = 128 * 1024 * 1024;
+    pub const DEFAULT_MAX_OPEN_FILES: i32 = -1;
+    pub const DEFAULT_WRITE_BUFFER_SIZE: usize = 64 * 1024 * 1024;
+    pub const DEFAULT_DB_WRITE_BUFFER_SIZE: usize = 0;
+    pub const DEFAULT_BLOOM_FILTER_BITS_PER_KEY: f64 = 10.0;
+    pub const DEFAULT_MEMTABLE_PREFIX_BLOOM_RATIO: f64 = 0.1;
+    pub const DEFAULT_HISTORY_COMPACTION_STYLE: CompactionStyle =
+        CompactionStyle::Universal;
+}
+
+impl Default for RocksDbConfig {
+    fn default() -> Self {
+        Self {
+            cache_capacity: Self::DEFAULT_CACHE_CAPACITY,
+            max_open_files: Self::DEFAULT_MAX_OPEN_FILES,
+            write_buffer_size: Self::DEFAULT_WRITE_BUFFER_SIZE,
+            db_write_buffer_size: Self::DEFAULT_DB_WRITE_BUFFER_SIZE,
+            bloom_filter_bits_per_key: Self::DEFAULT_BLOOM_FILTER_BITS_PER_KEY,
+            memtable_prefix_bloom_ratio: Self::DEFAULT_MEMTABLE_PREFIX_BLOOM_RATIO,
+            history_compaction_style: Self::DEFAULT_HISTORY_COMPACTION_STYLE,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct RocksDb {
     db: DBWithThreadMode<MultiThreaded>,
@@ -46,29 +119,45 @@ pub struct RocksDb {
 
 impl RocksDb {
     pub fn default_open<P: AsRef<Path>>(path: P) -> DatabaseResult<RocksDb> {
-        Self::open(path, enum_iterator::all::<Column>().collect::<Vec<_>>())
+        Self::open(
+            path,
+            enum_iterator::all::<Column>().collect::<Vec<_>>(),
+            &RocksDbConfig::default(),
+        )
     }
 
     pub fn open<P: AsRef<Path>>(
         path: P,
         columns: Vec<Column>,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<RocksDb> {
+        let cache = Cache::new_lru_cache(config.cache_capacity)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
         let cf_descriptors: Vec<_> = columns
             .clone()
             .into_iter()
-            .map(|i| ColumnFamilyDescriptor::new(RocksDb::col_name(i), Self::cf_opts(i)))
+            .map(|i| {
+                ColumnFamilyDescriptor::new(
+                    RocksDb::col_name(i),
+                    Self::cf_opts(i, config, &cache),
+                )
+            })
             .collect();
 
         let mut opts = Options::default();
         opts.create_if_missing(true);
+        // The columns added by the newer versions are created in the existing database.
+        opts.create_missing_column_families(true);
         opts.set_compression_type(DBCompressionType::Lz4);
+        opts.set_max_open_files(config.max_open_files);
+        opts.set_db_write_buffer_size(config.db_write_buffer_size);
         let db = match DB::open_cf_descriptors(&opts, &path, cf_descriptors) {
             Err(_) => {
                 // setup cfs
                 match DB::open_cf(&opts, &path, &[] as &[&str]) {
                     Ok(db) => {
                         for i in columns {
-                            let opts = Self::cf_opts(i);
+                            let opts = Self::cf_opts(i, config, &cache);
                             db.create_cf(RocksDb::col_name(i), &opts)
                                 .map_err(|e| DatabaseError::Other(e.into()))?;
                         }
@@ -94,27 +183,80 @@ impl RocksDb {
         format!("column-{}", column as u32)
     }
 
-    fn cf_opts(column: Column) -> Options {
+    fn cf_opts(column: Column, config: &RocksDbConfig, cache: &Cache) -> Options {
         let mut opts = Options::default();
         opts.create_if_missing(true);
         opts.set_compression_type(DBCompressionType::Lz4);
+        opts.set_write_buffer_size(config.write_buffer_size);
+
+        let mut block_opts = BlockBasedOptions::default();
+        block_opts.set_block_cache(cache);
+        // The index and filter blocks are accounted in the shared cache,
+        // so the cache capacity limits the memory used by the reads.
+        block_opts.set_cache_index_and_filter_blocks(true);
+        block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
 
         // All double-keys should be configured here
         match column {
             Column::OwnedCoins
             | Column::TransactionsByOwnerBlockIdx
             | Column::OwnedMessageIds
+            | Column::OwnedBalances
             | Column::ContractsAssets
             | Column::ContractsState => {
                 // prefix is address length
-                opts.set_prefix_extractor(SliceTransform::create_fixed_prefix(32))
+                opts.set_prefix_extractor(SliceTransform::create_fixed_prefix(32));
+                if config.memtable_prefix_bloom_ratio > 0.0 {
+                    opts.set_memtable_prefix_bloom_ratio(
+                        config.memtable_prefix_bloom_ratio,
+                    );
+                }
             }
             _ => {}
         };
 
+        // Most of the reads of these columns are lookups of a single key, often a missing
+        // one (like a check of the spent coin), which the bloom filter answers without IO.
+        if Self::is_point_lookup(column) && config.bloom_filter_bits_per_key > 0.0 {
+            block_opts.set_bloom_filter(config.bloom_filter_bits_per_key, false);
+        }
+
+        // The history is written once and never updated, so it may use another
+        // compaction style than the state.
+        if matches!(
+            column,
+            Column::Transactions
+                | Column::TransactionStatus
+                | Column::Receipts
+                | Column::TransactionTraces
+                | Column::FuelBlocks
+                | Column::FuelBlockConsensus
+        ) {
+            opts.set_compaction_style(config.history_compaction_style.into());
+        }
+
+        opts.set_block_based_table_factory(&block_opts);
         opts
     }
 
+    fn is_point_lookup(column: Column) -> bool {
+        matches!(
+            column,
+            Column::Coins
+                | Column::Messages
+                | Column::SpentMessages
+                | Column::ContractsRawCode
+                | Column::ContractsInfo
+                | Column::ContractsLatestUtxo
+                | Column::ContractsAssets
+                | Column::ContractsState
+                | Column::OwnedBalances
+                | Column::Transactions
+                | Column::TransactionStatus
+                | Column::FuelBlockIdsToHeights
+        )
+    }
+
     fn _iter_all(
         &self,
         column: Column,
@@ -387,7 +529,18 @@ impl BatchOperations for RocksDb {
     }
 }
 
-impl TransactableStorage for RocksDb {}
+impl TransactableStorage for RocksDb {
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        Checkpoint::new(&self.db)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(path))
+            .map_err(|e| {
+                DatabaseError::Other(anyhow::anyhow!(
+                    "Failed to create the checkpoint at {}: {e}",
+                    path.display()
+                ))
+            })
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -399,6 +552,56 @@ mod tests {
         (RocksDb::default_open(tmp_dir.path()).unwrap(), tmp_dir)
     }
 
+    #[test]
+    fn can_reopen_with_custom_config() {
+        let tmp_dir = TempDir::new().unwrap();
+        let config = RocksDbConfig {
+            cache_capacity: 1024 * 1024,
+            max_open_files: 64,
+            write_buffer_size: 1024 * 1024,
+            db_write_buffer_size: 4 * 1024 * 1024,
+            bloom_filter_bits_per_key: 0.0,
+            memtable_prefix_bloom_ratio: 0.0,
+            history_compaction_style: CompactionStyle::Level,
+        };
+        let key = vec![0xA, 0xB, 0xC];
+        let columns = enum_iterator::all::<Column>().collect::<Vec<_>>();
+
+        let db = RocksDb::open(tmp_dir.path(), columns.clone(), &config).unwrap();
+        db.put(&key, Column::Coins, vec![1, 2, 3]).unwrap();
+        drop(db);
+
+        let db =
+            RocksDb::open(tmp_dir.path(), columns, &RocksDbConfig::default()).unwrap();
+        assert_eq!(db.get(&key, Column::Coins).unwrap().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn can_reopen_with_new_columns() {
+        let tmp_dir = TempDir::new().unwrap();
+        let key = vec![0xA, 0xB, 0xC];
+
+        let db = RocksDb::open(
+            tmp_dir.path(),
+            vec![Column::Metadata],
+            &RocksDbConfig::default(),
+        )
+        .unwrap();
+        db.put(&key, Column::Metadata, vec![1, 2, 3]).unwrap();
+        drop(db);
+
+        let db = RocksDb::default_open(tmp_dir.path()).unwrap();
+        assert_eq!(
+            db.get(&key, Column::Metadata).unwrap().unwrap(),
+            vec![1, 2, 3]
+        );
+        db.put(&key, Column::PendingTransactions, vec![4]).unwrap();
+        assert_eq!(
+            db.get(&key, Column::PendingTransactions).unwrap().unwrap(),
+            vec![4]
+        );
+    }
+
     #[test]
     fn can_put_and_read() {
         let key = vec![0xA, 0xB, 0xC];
@@ -563,4 +766,31 @@ mod tests {
 
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
+
+    #[test]
+    fn checkpoint_contains_data_of_the_storage() {
+        let key = vec![0xA, 0xB, 0xC];
+        let (db, tmp) = create_db();
+        db.put(&key, Column::Metadata, vec![1, 2, 3]).unwrap();
+
+        let path = tmp.path().join("checkpoint");
+        db.checkpoint(&path).unwrap();
+        // Writes after the checkpoint are not visible in it.
+        db.put(&key, Column::Metadata, vec![4, 5, 6]).unwrap();
+
+        let checkpoint = RocksDb::default_open(&path).unwrap();
+        assert_eq!(
+            checkpoint.get(&key, Column::Metadata).unwrap().unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn checkpoint_fails_if_path_exists() {
+        let (db, tmp) = create_db();
+
+        let result = db.checkpoint(tmp.path());
+
+        assert!(result.is_err());
+    }
 }
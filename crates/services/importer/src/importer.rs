@@ -3,17 +3,22 @@ use crate::{
     ports::{
         BlockVerifier,
         Executor,
+        HeaderDatabase,
         ImporterDatabase,
     },
     Config,
 };
 use fuel_core_storage::{
-    transactional::StorageTransaction,
+    transactional::{
+        StorageTransaction,
+        Transactional,
+    },
     Error as StorageError,
     IsNotFound,
 };
 use fuel_core_types::{
     blockchain::{
+        block::Block,
         consensus::{
             Consensus,
             Sealed,
@@ -23,6 +28,7 @@ use fuel_core_types::{
             BlockId,
         },
         SealedBlock,
+        SealedBlockHeader,
     },
     services::{
         block_importer::{
@@ -75,12 +81,40 @@ pub enum Error {
     SkippedTransactionsNotEmpty,
     #[error("It is not possible to execute the genesis block.")]
     ExecuteGenesis,
+    #[error("It is not possible to import the header of the genesis block.")]
+    ImportGenesisHeader,
     #[error("The database already contains the data at the height {0}.")]
     NotUnique(BlockHeight),
     #[error(transparent)]
     StorageError(#[from] StorageError),
 }
 
+impl Error {
+    /// Returns `true` if the error is caused by the invalid block. The errors of the
+    /// node itself, like the storage errors or the concurrent commit, return `false`.
+    pub fn is_invalid_block(&self) -> bool {
+        match self {
+            Error::ZeroNonGenericHeight
+            | Error::BlockIdMismatch(_, _)
+            | Error::FailedVerification(_)
+            | Error::SkippedTransactionsNotEmpty
+            | Error::ExecuteGenesis
+            | Error::ImportGenesisHeader => true,
+            Error::FailedExecution(error) => !matches!(
+                error,
+                executor::Error::StorageError(_) | executor::Error::RelayerError(_)
+            ),
+            Error::SemaphoreError(_)
+            | Error::InvalidUnderlyingDatabaseGenesisState
+            | Error::InvalidDatabaseStateAfterExecution(_, _)
+            | Error::Overflow
+            | Error::IncorrectBlockHeight(_, _)
+            | Error::NotUnique(_)
+            | Error::StorageError(_) => false,
+        }
+    }
+}
+
 #[cfg(test)]
 impl PartialEq for Error {
     fn eq(&self, other: &Self) -> bool {
@@ -326,6 +360,69 @@ where
     }
 }
 
+impl<IDatabase, E, V> Importer<IDatabase, E, V>
+where
+    IDatabase: ImporterDatabase + Transactional,
+    IDatabase::Storage: HeaderDatabase,
+    V: BlockVerifier,
+{
+    /// The method validates the header fields and commits the `SealedBlockHeader`
+    /// without the execution of the block. It is used by the nodes that sync
+    /// only the header chain. The consensus of the header is not the concern of
+    /// this function.
+    ///
+    /// The state of the blockchain is not updated, so the subscribers are not
+    /// notified about the committed header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            block_id = %sealed_header.entity.id(),
+            height = **sealed_header.entity.height(),
+        ),
+        err
+    )]
+    pub fn commit_header(&self, sealed_header: SealedBlockHeader) -> Result<(), Error> {
+        let _guard = self.lock()?;
+        let Sealed {
+            entity: header,
+            consensus,
+        } = &sealed_header;
+
+        if let Consensus::Genesis(_) = consensus {
+            return Err(Error::ImportGenesisHeader)
+        }
+
+        let actual_next_height = *header.height();
+        let expected_next_height: BlockHeight = self
+            .database
+            .latest_block_height()?
+            .checked_add(1u32)
+            .ok_or(Error::Overflow)?
+            .into();
+        if expected_next_height != actual_next_height {
+            return Err(Error::IncorrectBlockHeight(
+                expected_next_height,
+                actual_next_height,
+            ))
+        }
+
+        // The verification of the fields uses only the header of the block.
+        self.verifier
+            .verify_block_fields(consensus, &Block::from_header(header.clone()))
+            .map_err(Error::FailedVerification)?;
+
+        let mut db_tx = self.database.transaction();
+        db_tx
+            .as_mut()
+            .store_sealed_header(&sealed_header)?
+            .should_be_unique(&expected_next_height)?;
+        db_tx.commit()?;
+
+        tracing::info!("Committed header");
+        Ok(())
+    }
+}
+
 trait ShouldBeUnique {
     fn should_be_unique(&self, height: &BlockHeight) -> Result<(), Error>;
 }
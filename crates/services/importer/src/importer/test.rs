@@ -2,6 +2,7 @@ use crate::{
     importer::Error,
     ports::{
         ExecutorDatabase,
+        HeaderDatabase,
         ImporterDatabase,
         MockBlockVerifier,
         MockExecutor,
@@ -14,6 +15,7 @@ use fuel_core_storage::{
     transactional::{
         StorageTransaction,
         Transaction as TransactionTrait,
+        Transactional,
     },
     Error as StorageError,
     Result as StorageResult,
@@ -21,12 +23,16 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         block::Block,
-        consensus::Consensus,
+        consensus::{
+            Consensus,
+            Sealed,
+        },
         primitives::{
             BlockHeight,
             BlockId,
         },
         SealedBlock,
+        SealedBlockHeader,
     },
     fuel_tx::Transaction,
     services::{
@@ -63,9 +69,22 @@ mockall::mock! {
         ) -> StorageResult<Option<Consensus>>;
     }
 
+    impl HeaderDatabase for Database {
+        fn store_sealed_header(
+            &mut self,
+            header: &SealedBlockHeader,
+        ) -> StorageResult<Option<Consensus>>;
+    }
+
     impl TransactionTrait<MockDatabase> for Database {
         fn commit(&mut self) -> StorageResult<()>;
     }
+
+    impl Transactional for Database {
+        type Storage = MockDatabase;
+
+        fn transaction(&self) -> StorageTransaction<MockDatabase>;
+    }
 }
 
 impl AsMut<MockDatabase> for MockDatabase {
@@ -108,6 +127,14 @@ fn poa_block(height: u32) -> SealedBlock {
     }
 }
 
+fn header(block: SealedBlock) -> SealedBlockHeader {
+    let Sealed { entity, consensus } = block;
+    Sealed {
+        entity: entity.header().clone(),
+        consensus,
+    }
+}
+
 fn underlying_db<R>(result: R) -> impl Fn() -> MockDatabase
 where
     R: Fn() -> StorageResult<u32> + Send + Clone + 'static,
@@ -139,6 +166,20 @@ where
     }
 }
 
+fn header_db<S>(store: S, commits: usize) -> impl Fn() -> MockDatabase
+where
+    S: Fn() -> StorageResult<Option<Consensus>> + Send + Clone + 'static,
+{
+    move || {
+        let store = store.clone();
+        let mut db = MockDatabase::default();
+        db.expect_store_sealed_header().returning(move |_| store());
+        db.expect_commit().times(commits).returning(|| Ok(()));
+
+        db
+    }
+}
+
 fn ok<T: Clone, Err>(entity: T) -> impl Fn() -> Result<T, Err> + Clone {
     move || Ok(entity.clone())
 }
@@ -539,3 +580,105 @@ fn verify_and_execute_allowed_when_locked() {
     let _guard = importer.lock();
     assert!(importer.verify_and_execute_block(poa_block(13)).is_ok());
 }
+
+//////////////// SealedBlockHeader, UnderlyingDB, Verification result, HeaderDB ///////////////
+#[test_case(
+    header(poa_block(1)), underlying_db(ok(0)), ok(()), header_db(ok(None), 1)
+    => Ok(())
+)]
+#[test_case(
+    header(poa_block(113)), underlying_db(ok(112)), ok(()), header_db(ok(None), 1)
+    => Ok(())
+)]
+#[test_case(
+    header(genesis(0)), underlying_db(not_found), ok(()), header_db(ok(None), 0)
+    => Err(Error::ImportGenesisHeader)
+)]
+#[test_case(
+    header(poa_block(113)), underlying_db(ok(111)), ok(()), header_db(ok(None), 0)
+    => Err(Error::IncorrectBlockHeight(112u32.into(), 113u32.into()))
+)]
+#[test_case(
+    header(poa_block(113)), underlying_db(storage_failure), ok(()), header_db(ok(None), 0)
+    => Err(storage_failure_error())
+)]
+#[test_case(
+    header(poa_block(113)), underlying_db(ok(112)), verification_failure, header_db(ok(None), 0)
+    => Err(verification_failure_error())
+)]
+#[test_case(
+    header(poa_block(113)),
+    underlying_db(ok(112)),
+    ok(()),
+    header_db(ok(Some(Default::default())), 0)
+    => Err(Error::NotUnique(113u32.into()))
+)]
+#[test_case(
+    header(poa_block(113)), underlying_db(ok(112)), ok(()), header_db(storage_failure, 0)
+    => Err(storage_failure_error())
+)]
+fn commit_header_poa<V>(
+    sealed_header: SealedBlockHeader,
+    underlying_db: impl Fn() -> MockDatabase,
+    verifier_result: V,
+    header_db: impl Fn() -> MockDatabase + Send + 'static,
+) -> Result<(), Error>
+where
+    V: Fn() -> anyhow::Result<()> + Send + 'static,
+{
+    let mut underlying_db = underlying_db();
+    underlying_db
+        .expect_transaction()
+        .return_once(move || StorageTransaction::new(header_db()));
+    let importer = Importer::new(
+        Default::default(),
+        underlying_db,
+        MockExecutor::default(),
+        verifier(verifier_result),
+    );
+
+    let mut imported_blocks = importer.subscribe();
+    let result = importer.commit_header(sealed_header);
+
+    // Only the executed blocks are broadcasted.
+    assert_eq!(
+        imported_blocks
+            .try_recv()
+            .expect_err("We should not broadcast the header"),
+        TryRecvError::Empty
+    );
+    result
+}
+
+#[test]
+fn commit_header_fail_when_locked() {
+    let importer = Importer::new(
+        Default::default(),
+        MockDatabase::default(),
+        MockExecutor::default(),
+        MockBlockVerifier::default(),
+    );
+
+    let _guard = importer.lock();
+    assert_eq!(
+        importer.commit_header(header(poa_block(13))),
+        Err(Error::SemaphoreError(TryAcquireError::NoPermits))
+    );
+}
+
+#[test_case(Error::FailedVerification(anyhow!("Not verified")) => true; "verification fails")]
+#[test_case(Error::FailedExecution(ExecutorError::InvalidBlockId) => true; "execution fails")]
+#[test_case(
+    Error::BlockIdMismatch(Default::default(), Default::default()) => true;
+    "block id mismatch"
+)]
+#[test_case(
+    Error::FailedExecution(ExecutorError::StorageError(Box::new(not_found!("Coins"))))
+    => false; "storage error during execution"
+)]
+#[test_case(Error::StorageError(not_found!("Coins")) => false; "storage error")]
+#[test_case(Error::NotUnique(1u32.into()) => false; "not unique")]
+#[test_case(Error::SemaphoreError(TryAcquireError::NoPermits) => false; "locked")]
+fn is_invalid_block(error: Error) -> bool {
+    error.is_invalid_block()
+}
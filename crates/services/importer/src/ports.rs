@@ -10,6 +10,7 @@ use fuel_core_types::{
             BlockHeight,
             BlockId,
         },
+        SealedBlockHeader,
     },
     services::executor::{
         ExecutionBlock,
@@ -49,6 +50,17 @@ pub trait ExecutorDatabase: ImporterDatabase {
     ) -> StorageResult<Option<Consensus>>;
 }
 
+/// The port for the database that stores the headers of the blocks without
+/// the execution, used by the nodes that sync only the header chain.
+pub trait HeaderDatabase: ImporterDatabase {
+    /// Stores the header as a block without transactions and assigns the `Consensus`
+    /// data to it. Return the previous `Consensus` at the header's height, if any.
+    fn store_sealed_header(
+        &mut self,
+        header: &SealedBlockHeader,
+    ) -> StorageResult<Option<Consensus>>;
+}
+
 #[cfg_attr(test, mockall::automock)]
 /// The verifier of the block.
 pub trait BlockVerifier {
@@ -26,7 +26,11 @@ use crate::{
         RequestMessage,
     },
 };
-use fuel_core_types::blockchain::primitives::BlockHeight;
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    services::p2p::PeerReportReason,
+    tai64::Tai64,
+};
 use libp2p::{
     gossipsub::{
         error::{
@@ -203,6 +207,14 @@ impl<Codec: NetworkCodec> FuelBehaviour<Codec> {
         self.peer_manager.update_block_height(block_height);
     }
 
+    pub fn report_peer(&mut self, peer_id: &PeerId, reason: PeerReportReason) {
+        self.peer_manager.report_peer(peer_id, reason);
+    }
+
+    pub fn ban_peer(&mut self, peer_id: PeerId, banned_until: Tai64) {
+        self.peer_manager.ban_peer(peer_id, banned_until);
+    }
+
     // Currently only used in testing, but should be useful for the P2P Service API
     #[allow(dead_code)]
     pub fn get_peer_info(&self, peer_id: &PeerId) -> Option<&PeerInfo> {
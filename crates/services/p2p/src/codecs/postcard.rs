@@ -68,6 +68,27 @@ impl PostcardCodec {
         postcard::to_stdvec(&data)
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
     }
+
+    /// Serializes the longest prefix of the `items` that fits into the max response size.
+    /// The requesting peer asks for the rest of the items in the next request.
+    fn serialize_prefix<D: Serialize>(&self, items: &[D]) -> Result<Vec<u8>, io::Error> {
+        // Reserved for the `NetworkResponse` variant and the length prefixes.
+        const RESPONSE_OVERHEAD: usize = 32;
+
+        let mut response_size = RESPONSE_OVERHEAD;
+        let mut count = 0;
+        for item in items {
+            response_size += self.serialize(item)?.len();
+            // The first item is always sent, otherwise the requesting
+            // peer can't make any progress.
+            if response_size > self.max_response_size && count > 0 {
+                break
+            }
+            count += 1;
+        }
+
+        self.serialize(&&items[..count])
+    }
 }
 
 /// Since Postcard does not support async reads or writes out of the box
@@ -204,14 +225,14 @@ impl RequestResponseConverter for PostcardCodec {
 
                 Ok(ResponseMessage::SealedBlock(response))
             }
-            NetworkResponse::Header(header_bytes) => {
-                let response = if let Some(header_bytes) = header_bytes {
-                    Some(self.deserialize(header_bytes)?)
+            NetworkResponse::Headers(headers_bytes) => {
+                let response = if let Some(headers_bytes) = headers_bytes {
+                    Some(self.deserialize(headers_bytes)?)
                 } else {
                     None
                 };
 
-                Ok(ResponseMessage::SealedHeader(response))
+                Ok(ResponseMessage::SealedHeaders(response))
             }
             NetworkResponse::Transactions(tx_bytes) => {
                 let response = if let Some(tx_bytes) = tx_bytes {
@@ -239,18 +260,18 @@ impl RequestResponseConverter for PostcardCodec {
 
                 Ok(NetworkResponse::Block(response))
             }
-            OutboundResponse::SealedHeader(sealed_header) => {
-                let response = if let Some(sealed_header) = sealed_header {
-                    Some(self.serialize(sealed_header.as_ref())?)
+            OutboundResponse::SealedHeaders(sealed_headers) => {
+                let response = if let Some(sealed_headers) = sealed_headers {
+                    Some(self.serialize_prefix(sealed_headers)?)
                 } else {
                     None
                 };
 
-                Ok(NetworkResponse::Header(response))
+                Ok(NetworkResponse::Headers(response))
             }
             OutboundResponse::Transactions(transactions) => {
                 let response = if let Some(transactions) = transactions {
-                    Some(self.serialize(transactions.as_ref())?)
+                    Some(self.serialize_prefix(transactions)?)
                 } else {
                     None
                 };
@@ -278,13 +299,68 @@ impl ProtocolName for MessageExchangePostcardProtocol {
 
 #[cfg(test)]
 mod tests {
-    use fuel_core_types::blockchain::primitives::BlockId;
+    use fuel_core_types::{
+        blockchain::{
+            primitives::BlockId,
+            SealedBlockHeader,
+        },
+        fuel_tx::Transaction,
+    };
+    use std::sync::Arc;
 
     use super::*;
+    use crate::request_response::messages::MAX_REQUEST_BATCH_SIZE;
 
     #[test]
     fn test_request_size_fits() {
-        let m = RequestMessage::Transactions(BlockId::default());
+        let m = RequestMessage::Transactions(vec![
+            BlockId::default();
+            MAX_REQUEST_BATCH_SIZE
+        ]);
+        assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
+
+        let m = RequestMessage::SealedHeaders(u32::MAX.into()..u32::MAX.into());
         assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
     }
+
+    #[test]
+    fn batch_response_is_truncated_to_max_response_size() {
+        let header_size = postcard::to_stdvec(&SealedBlockHeader::default())
+            .unwrap()
+            .len();
+        let codec = PostcardCodec::new(header_size * 10);
+        let headers = Arc::new(vec![SealedBlockHeader::default(); 20]);
+
+        let response = codec
+            .convert_to_network_response(&OutboundResponse::SealedHeaders(Some(headers)))
+            .unwrap();
+        let encoded_size = postcard::to_stdvec(&response).unwrap().len();
+        let ResponseMessage::SealedHeaders(Some(headers)) =
+            codec.convert_to_response(&response).unwrap()
+        else {
+            panic!("Expected the headers response");
+        };
+
+        assert!(!headers.is_empty() && headers.len() < 10);
+        assert!(encoded_size <= header_size * 10);
+    }
+
+    #[test]
+    fn batch_response_contains_at_least_one_entry() {
+        let codec = PostcardCodec::new(1);
+        let transactions = Arc::new(vec![vec![Transaction::default()]; 2]);
+
+        let response = codec
+            .convert_to_network_response(&OutboundResponse::Transactions(Some(
+                transactions,
+            )))
+            .unwrap();
+        let ResponseMessage::Transactions(Some(transactions)) =
+            codec.convert_to_response(&response).unwrap()
+        else {
+            panic!("Expected the transactions response");
+        };
+
+        assert_eq!(transactions.len(), 1);
+    }
 }
@@ -8,7 +8,10 @@ use crate::{
         },
     },
     heartbeat::HeartbeatConfig,
-    peer_manager::ConnectionState,
+    peer_manager::{
+        ConnectionState,
+        ReputationConfig,
+    },
 };
 use fuel_core_types::blockchain::consensus::Genesis;
 
@@ -122,6 +125,8 @@ pub struct Config<State = Initialized> {
     /// The duration between the last successful outbound or inbound ping
     /// and the next outbound ping
     pub info_interval: Option<Duration>,
+    /// Scores of the peers' behaviour reported by the services
+    pub reputation_config: ReputationConfig,
 
     // `Gossipsub` config and topics
     pub gossipsub_config: GossipsubConfig,
@@ -175,6 +180,7 @@ impl Config<NotInitialized> {
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
             identify_interval: self.identify_interval,
             info_interval: self.info_interval,
+            reputation_config: self.reputation_config,
             gossipsub_config: self.gossipsub_config,
             topics: self.topics,
             heartbeat_config: self.heartbeat_config,
@@ -228,6 +234,7 @@ impl Config<NotInitialized> {
             set_connection_keep_alive: REQ_RES_TIMEOUT,
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
+            reputation_config: ReputationConfig::default(),
             metrics: false,
             state: NotInitialized,
         }
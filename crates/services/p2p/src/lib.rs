@@ -12,6 +12,7 @@ pub mod service;
 
 pub use gossipsub::config as gossipsub_config;
 pub use heartbeat::HeartbeatConfig;
+pub use peer_manager::ReputationConfig;
 
 pub use libp2p::{
     multiaddr::Protocol,
@@ -31,7 +31,11 @@ use crate::{
     },
 };
 use fuel_core_metrics::p2p_metrics::P2P_METRICS;
-use fuel_core_types::blockchain::primitives::BlockHeight;
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    services::p2p::PeerReportReason,
+    tai64::Tai64,
+};
 use futures::prelude::*;
 use libp2p::{
     gossipsub::{
@@ -127,6 +131,8 @@ pub enum FuelP2PEvent {
         peer_id: PeerId,
         block_height: BlockHeight,
     },
+    PeerBanned(PeerId),
+    PeerUnbanned(PeerId),
 }
 
 impl<Codec: NetworkCodec> FuelP2PService<Codec> {
@@ -328,6 +334,17 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         self.swarm.behaviour_mut().update_block_height(block_height)
     }
 
+    /// Adjusts the reputation of the peer according to the reported behaviour.
+    pub fn report_peer(&mut self, peer_id: &PeerId, reason: PeerReportReason) {
+        self.swarm.behaviour_mut().report_peer(peer_id, reason)
+    }
+
+    /// Bans the peer until the `banned_until` time.
+    /// Used to restore the bans from the previous run of the node.
+    pub fn ban_peer(&mut self, peer_id: PeerId, banned_until: Tai64) {
+        self.swarm.behaviour_mut().ban_peer(peer_id, banned_until)
+    }
+
     #[tracing::instrument(skip_all,
         level = "debug",
         fields(
@@ -465,6 +482,18 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                     // disconnect the surplus peer
                     let _ = self.swarm.disconnect_peer_id(peer_to_disconnect);
                 }
+                PeerInfoEvent::DisconnectPeer(peer_id) => {
+                    let _ = self.swarm.disconnect_peer_id(peer_id);
+                }
+                PeerInfoEvent::BanPeer(peer_id) => {
+                    // closes the existing connections and denies the new ones
+                    self.swarm.ban_peer_id(peer_id);
+                    return Some(FuelP2PEvent::PeerBanned(peer_id))
+                }
+                PeerInfoEvent::UnbanPeer(peer_id) => {
+                    self.swarm.unban_peer_id(peer_id);
+                    return Some(FuelP2PEvent::PeerUnbanned(peer_id))
+                }
             },
             FuelBehaviourEvent::RequestResponse(req_res_event) => match req_res_event {
                 RequestResponseEvent::Message { peer, message } => match message {
@@ -511,10 +540,10 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                                 }
                             }
                             (
-                                Some(ResponseChannelItem::SealedHeader(channel)),
-                                Ok(ResponseMessage::SealedHeader(header)),
+                                Some(ResponseChannelItem::SealedHeaders(channel)),
+                                Ok(ResponseMessage::SealedHeaders(headers)),
                             ) => {
-                                if channel.send(header.map(|h| (peer, h))).is_err() {
+                                if channel.send(headers.map(|h| (peer, h))).is_err() {
                                     debug!(
                                         "Failed to send through the channel for {:?}",
                                         request_id
@@ -591,8 +620,14 @@ mod tests {
                 Consensus,
                 ConsensusVote,
             },
-            header::PartialBlockHeader,
-            primitives::BlockId,
+            header::{
+                BlockHeader,
+                PartialBlockHeader,
+            },
+            primitives::{
+                BlockHeight,
+                BlockId,
+            },
             SealedBlock,
             SealedBlockHeader,
         },
@@ -1393,10 +1428,10 @@ mod tests {
                             if !peer_addresses.is_empty() && !request_sent {
                                 request_sent = true;
 
-                                match request_msg {
+                                match request_msg.clone() {
                                     RequestMessage::Block(_) => {
                                         let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
-                                        assert!(node_a.send_request_msg(None, request_msg, ResponseChannelItem::Block(tx_orchestrator)).is_ok());
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseChannelItem::Block(tx_orchestrator)).is_ok());
                                         let tx_test_end = tx_test_end.clone();
 
                                         tokio::spawn(async move {
@@ -1411,32 +1446,34 @@ mod tests {
                                         });
 
                                     }
-                                    RequestMessage::SealedHeader(_) => {
+                                    RequestMessage::SealedHeaders(range) => {
                                         let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
-                                        assert!(node_a.send_request_msg(None, request_msg, ResponseChannelItem::SealedHeader(tx_orchestrator)).is_ok());
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseChannelItem::SealedHeaders(tx_orchestrator)).is_ok());
                                         let tx_test_end = tx_test_end.clone();
 
                                         tokio::spawn(async move {
                                             let response_message = rx_orchestrator.await;
 
-                                            if let Ok(Some(_)) = response_message {
-                                                let _ = tx_test_end.send(true).await;
+                                            if let Ok(Some((_, headers))) = response_message {
+                                                let expected_heights: Vec<_> = (*range.start..*range.end).map(BlockHeight::from).collect();
+                                                let heights: Vec<_> = headers.iter().map(|h| *h.entity.height()).collect();
+                                                let _ = tx_test_end.send(heights == expected_heights).await;
                                             } else {
                                                 tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
                                                 let _ = tx_test_end.send(false).await;
                                             }
                                         });
                                     }
-                                    RequestMessage::Transactions(_) => {
+                                    RequestMessage::Transactions(block_ids) => {
                                         let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
-                                        assert!(node_a.send_request_msg(None, request_msg, ResponseChannelItem::Transactions(tx_orchestrator)).is_ok());
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseChannelItem::Transactions(tx_orchestrator)).is_ok());
                                         let tx_test_end = tx_test_end.clone();
 
                                         tokio::spawn(async move {
                                             let response_message = rx_orchestrator.await;
 
                                             if let Ok(Some(transactions)) = response_message {
-                                                let _ = tx_test_end.send(transactions.len() == 5).await;
+                                                let _ = tx_test_end.send(transactions.len() == block_ids.len() && transactions.iter().all(|txs| txs.len() == 5)).await;
                                             } else {
                                                 tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
                                                 let _ = tx_test_end.send(false).await;
@@ -1452,7 +1489,8 @@ mod tests {
                 },
                 node_b_event = node_b.next_event() => {
                     // 2. Node B receives the RequestMessage from Node A initiated by the NetworkOrchestrator
-                    if let Some(FuelP2PEvent::RequestMessage{ request_id, request_message: received_request_message }) = node_b_event {
+                    if let Some(FuelP2PEvent::RequestMessage{ request_id, request_message: received_request_message }) = &node_b_event {
+                        let request_id = *request_id;
                         match received_request_message {
                             RequestMessage::Block(_) => {
                                 let block = Block::new(PartialBlockHeader::default(), vec![Transaction::default(), Transaction::default(), Transaction::default(), Transaction::default(), Transaction::default()], &[]);
@@ -1464,18 +1502,22 @@ mod tests {
 
                                 let _ = node_b.send_response_msg(request_id, OutboundResponse::Block(Some(Arc::new(sealed_block))));
                             }
-                            RequestMessage::SealedHeader(_) => {
-                                let header = Default::default();
-
-                                let sealed_header = SealedBlockHeader {
-                                    entity: header,
-                                    consensus: Consensus::PoA(PoAConsensus::new(Default::default())),
-                                };
+                            RequestMessage::SealedHeaders(range) => {
+                                let sealed_headers: Vec<_> = (*range.start..*range.end).map(|height| {
+                                    let mut header = BlockHeader::default();
+                                    header.consensus.height = height.into();
+
+                                    SealedBlockHeader {
+                                        entity: header,
+                                        consensus: Consensus::PoA(PoAConsensus::new(Default::default())),
+                                    }
+                                }).collect();
 
-                                let _ = node_b.send_response_msg(request_id, OutboundResponse::SealedHeader(Some(Arc::new(sealed_header))));
+                                let _ = node_b.send_response_msg(request_id, OutboundResponse::SealedHeaders(Some(Arc::new(sealed_headers))));
                             }
-                            RequestMessage::Transactions(_) => {
+                            RequestMessage::Transactions(block_ids) => {
                                 let transactions = vec![Transaction::default(), Transaction::default(), Transaction::default(), Transaction::default(), Transaction::default()];
+                                let transactions = vec![transactions; block_ids.len()];
                                 let _ = node_b.send_response_msg(request_id, OutboundResponse::Transactions(Some(Arc::new(transactions))));
                             }
                         }
@@ -1491,8 +1533,11 @@ mod tests {
     #[tokio::test]
     #[instrument]
     async fn request_response_works_with_transactions() {
-        request_response_works_with(RequestMessage::Transactions(BlockId::default()))
-            .await
+        request_response_works_with(RequestMessage::Transactions(vec![
+            BlockId::default();
+            3
+        ]))
+        .await
     }
 
     #[tokio::test]
@@ -1503,8 +1548,11 @@ mod tests {
 
     #[tokio::test]
     #[instrument]
-    async fn request_response_works_with_sealed_header() {
-        request_response_works_with(RequestMessage::SealedHeader(0_u64.into())).await
+    async fn request_response_works_with_sealed_headers() {
+        request_response_works_with(RequestMessage::SealedHeaders(
+            2_u32.into()..5_u32.into(),
+        ))
+        .await
     }
 
     #[tokio::test]
@@ -5,7 +5,11 @@ use crate::{
         HeartbeatEvent,
     },
 };
-use fuel_core_types::blockchain::primitives::BlockHeight;
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    services::p2p::PeerReportReason,
+    tai64::Tai64,
+};
 use libp2p::{
     core::{
         connection::ConnectionId,
@@ -57,12 +61,59 @@ use std::{
     },
 };
 use tokio::time::Interval;
-use tracing::debug;
+use tracing::{
+    debug,
+    warn,
+};
 
 /// Maximum amount of peer's addresses that we are ready to store per peer
 const MAX_IDENTIFY_ADDRESSES: usize = 10;
 const HEALTH_CHECK_INTERVAL_IN_SECONDS: u64 = 10;
 
+/// The bounds of the peer's reputation score. The upper bound limits how much
+/// good behaviour can compensate for the following misbehaviour.
+const MIN_PEER_SCORE: f64 = -100.0;
+const MAX_PEER_SCORE: f64 = 50.0;
+/// The remembered score of a disconnected peer is forgotten
+/// once it decays closer to zero than this value.
+const FORGOTTEN_PEER_SCORE: f64 = 1.0;
+
+/// The configuration of the application-level reputation of peers.
+#[derive(Debug, Clone)]
+pub struct ReputationConfig {
+    /// The fraction of the score that remains after every health check interval,
+    /// moving the scores of all peers towards zero over time.
+    pub decay_factor: f64,
+    /// Non-reserved peers with a score below the threshold are disconnected.
+    pub disconnect_threshold: f64,
+    /// Non-reserved peers with a score at or below the threshold are disconnected
+    /// and banned for the `ban_duration`.
+    pub ban_threshold: f64,
+    /// How long the banned peer is not allowed to connect.
+    pub ban_duration: Duration,
+}
+
+impl Default for ReputationConfig {
+    fn default() -> Self {
+        Self {
+            decay_factor: 0.98,
+            disconnect_threshold: -50.0,
+            ban_threshold: -100.0,
+            ban_duration: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+/// The change of the peer's score caused by the reported behaviour.
+fn score_change(reason: PeerReportReason) -> f64 {
+    match reason {
+        PeerReportReason::SuccessfulBlockImport => 1.0,
+        PeerReportReason::InvalidHeader => -50.0,
+        PeerReportReason::InvalidBlock => -50.0,
+        PeerReportReason::RejectedGossip => -10.0,
+    }
+}
+
 /// Events emitted by PeerInfoBehaviour
 #[derive(Debug, Clone)]
 pub enum PeerInfoEvent {
@@ -83,6 +134,12 @@ pub enum PeerInfoEvent {
         peer_id: PeerId,
         block_height: BlockHeight,
     },
+    /// The reputation of the peer dropped below the disconnect threshold.
+    DisconnectPeer(PeerId),
+    /// The peer is banned, its connections should be closed and denied.
+    BanPeer(PeerId),
+    /// The ban of the peer expired.
+    UnbanPeer(PeerId),
 }
 
 // `Behaviour` that holds info about peers
@@ -122,6 +179,7 @@ impl PeerManagerBehaviour {
             reserved_peers,
             connection_state,
             config.max_peers_connected as usize,
+            config.reputation_config.clone(),
         );
 
         Self {
@@ -160,6 +218,29 @@ impl PeerManagerBehaviour {
     pub fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
         self.peer_manager.get_peer_id_with_height(height)
     }
+
+    /// Find all the peers that are holding the given block height.
+    pub fn get_peer_ids_with_height(
+        &self,
+        height: &BlockHeight,
+    ) -> impl Iterator<Item = &PeerId> {
+        self.peer_manager.get_peer_ids_with_height(height)
+    }
+
+    /// Adjusts the reputation of the peer according to the reported behaviour.
+    pub fn report_peer(&mut self, peer_id: &PeerId, reason: PeerReportReason) {
+        self.peer_manager.report_peer(peer_id, reason)
+    }
+
+    /// Bans the peer until the `banned_until` time.
+    pub fn ban_peer(&mut self, peer_id: PeerId, banned_until: Tai64) {
+        self.peer_manager.ban_peer(peer_id, banned_until)
+    }
+
+    /// returns an iterator over the banned peers and the expiration times of their bans
+    pub fn banned_peers(&self) -> impl Iterator<Item = (&PeerId, &Tai64)> {
+        self.peer_manager.banned_peers.iter()
+    }
 }
 
 impl NetworkBehaviour for PeerManagerBehaviour {
@@ -336,6 +417,9 @@ impl NetworkBehaviour for PeerManagerBehaviour {
                     .pending_events
                     .push_back(PeerInfoEvent::ReconnectToPeer(peer_id));
             }
+
+            self.peer_manager.decay_scores();
+            self.peer_manager.unban_expired_peers(Tai64::now());
         }
 
         if let Some(event) = self.peer_manager.pending_events.pop_front() {
@@ -518,6 +602,8 @@ pub struct PeerInfo {
     pub peer_addresses: HashSet<Multiaddr>,
     pub client_version: Option<String>,
     pub heartbeat_data: HeartbeatData,
+    /// The application-level reputation of the peer
+    pub score: f64,
 }
 
 enum PeerInfoInsert {
@@ -535,6 +621,10 @@ struct PeerManager {
     reserved_peers: HashSet<PeerId>,
     connection_state: Arc<RwLock<ConnectionState>>,
     max_non_reserved_peers: usize,
+    reputation_config: ReputationConfig,
+    // negative scores of disconnected peers, restored when they connect again
+    disconnected_peers_scores: HashMap<PeerId, f64>,
+    banned_peers: HashMap<PeerId, Tai64>,
 }
 
 impl PeerManager {
@@ -542,6 +632,7 @@ impl PeerManager {
         reserved_peers: HashSet<PeerId>,
         connection_state: Arc<RwLock<ConnectionState>>,
         max_non_reserved_peers: usize,
+        reputation_config: ReputationConfig,
     ) -> Self {
         Self {
             pending_events: VecDeque::default(),
@@ -550,6 +641,9 @@ impl PeerManager {
             reserved_peers,
             connection_state,
             max_non_reserved_peers,
+            reputation_config,
+            disconnected_peers_scores: HashMap::default(),
+            banned_peers: HashMap::default(),
         }
     }
 
@@ -601,6 +695,14 @@ impl PeerManager {
 
         // if the connected Peer is not from the reserved peers
         if !self.reserved_peers.contains(&peer_id) {
+            // the swarm denies connections of the banned peers,
+            // but the ban could happen while the connection was being established
+            if self.banned_peers.contains_key(&peer_id) {
+                self.pending_events
+                    .push_front(PeerInfoEvent::DisconnectPeer(peer_id));
+                return
+            }
+
             // check if all the slots are already taken
             if non_reserved_peers_connected >= self.max_non_reserved_peers {
                 // Too many peers already connected, disconnect the Peer with the first priority.
@@ -621,25 +723,37 @@ impl PeerManager {
                 }
             }
 
-            self.non_reserved_connected_peers
-                .insert(peer_id, PeerInfo::default());
+            let peer_info = self.new_peer_info(&peer_id);
+            self.non_reserved_connected_peers.insert(peer_id, peer_info);
         } else {
-            self.reserved_connected_peers
-                .insert(peer_id, PeerInfo::default());
+            let peer_info = self.new_peer_info(&peer_id);
+            self.reserved_connected_peers.insert(peer_id, peer_info);
         }
 
         self.pending_events
             .push_back(PeerInfoEvent::PeerConnected(peer_id));
     }
 
+    /// Creates the info of the connected peer with the remembered score
+    fn new_peer_info(&mut self, peer_id: &PeerId) -> PeerInfo {
+        PeerInfo {
+            score: self
+                .disconnected_peers_scores
+                .remove(peer_id)
+                .unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
     /// Handles on peer's last connection getting disconnected
     fn handle_peer_disconnect(&mut self, peer_id: PeerId) {
         // try immediate reconnect if it's a reserved peer
         let is_reserved = self.reserved_peers.contains(&peer_id);
-        let is_removed;
+        let removed_peer;
 
         if !is_reserved {
-            is_removed = self.non_reserved_connected_peers.remove(&peer_id).is_some();
+            removed_peer = self.non_reserved_connected_peers.remove(&peer_id);
+            let is_removed = removed_peer.is_some();
 
             // check were all the slots full prior to this disconnect
             if is_removed
@@ -653,10 +767,15 @@ impl PeerManager {
                 }
             }
         } else {
-            is_removed = self.reserved_connected_peers.remove(&peer_id).is_some();
+            removed_peer = self.reserved_connected_peers.remove(&peer_id);
         }
 
-        if is_removed {
+        if let Some(peer_info) = removed_peer {
+            // remember the misbehaviour, so reconnecting doesn't reset the reputation
+            if peer_info.score < 0.0 {
+                self.disconnected_peers_scores
+                    .insert(peer_id, peer_info.score);
+            }
             self.pending_events
                 .push_back(PeerInfoEvent::PeerDisconnected {
                     peer_id,
@@ -668,17 +787,118 @@ impl PeerManager {
     /// Find a peer that is holding the given block height.
     pub fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
         let mut range = rand::thread_rng();
+        self.get_peer_ids_with_height(height)
+            .copied()
+            .choose(&mut range)
+    }
+
+    pub fn get_peer_ids_with_height(
+        &self,
+        height: &BlockHeight,
+    ) -> impl Iterator<Item = &PeerId> {
+        let height = *height;
         // TODO: Optimize the selection of the peer.
         //  We can store pair `(peer id, height)` for all nodes(reserved and not) in the
         //  https://docs.rs/sorted-vec/latest/sorted_vec/struct.SortedVec.html
         self.non_reserved_connected_peers
             .iter()
             .chain(self.reserved_connected_peers.iter())
-            .filter(|(_, peer_info)| {
-                peer_info.heartbeat_data.block_height >= Some(*height)
+            .filter(move |(_, peer_info)| {
+                peer_info.heartbeat_data.block_height >= Some(height)
             })
+            .map(|(peer_id, _)| peer_id)
+    }
+
+    fn get_peer_score_mut(&mut self, peer_id: &PeerId) -> Option<&mut f64> {
+        let connected_peer = if self.reserved_peers.contains(peer_id) {
+            self.reserved_connected_peers.get_mut(peer_id)
+        } else {
+            self.non_reserved_connected_peers.get_mut(peer_id)
+        };
+
+        match connected_peer {
+            Some(peer_info) => Some(&mut peer_info.score),
+            None => self.disconnected_peers_scores.get_mut(peer_id),
+        }
+    }
+
+    /// Adjusts the score of the peer and disconnects or bans it
+    /// if the score drops below the thresholds.
+    fn report_peer(&mut self, peer_id: &PeerId, reason: PeerReportReason) {
+        let change = score_change(reason);
+        let score = match self.get_peer_score_mut(peer_id) {
+            Some(score) => {
+                *score = (*score + change).clamp(MIN_PEER_SCORE, MAX_PEER_SCORE);
+                *score
+            }
+            // the score of the disconnected peer is only remembered when it is negative
+            None if change < 0.0 => {
+                self.disconnected_peers_scores.insert(*peer_id, change);
+                change
+            }
+            None => return,
+        };
+        debug!(target: "fuel-libp2p", "Peer {:?} reported for {:?}, the score is {}", peer_id, reason, score);
+
+        if self.reserved_peers.contains(peer_id) {
+            if score < self.reputation_config.disconnect_threshold {
+                warn!(target: "fuel-libp2p", "Reserved peer {:?} has a low reputation score {}", peer_id, score);
+            }
+            return
+        }
+
+        if score <= self.reputation_config.ban_threshold {
+            let banned_until =
+                Tai64::now() + self.reputation_config.ban_duration.as_secs();
+            self.ban_peer(*peer_id, banned_until);
+        } else if score < self.reputation_config.disconnect_threshold
+            && self.non_reserved_connected_peers.contains_key(peer_id)
+        {
+            self.pending_events
+                .push_back(PeerInfoEvent::DisconnectPeer(*peer_id));
+        }
+    }
+
+    fn ban_peer(&mut self, peer_id: PeerId, banned_until: Tai64) {
+        if self.reserved_peers.contains(&peer_id) {
+            return
+        }
+        debug!(target: "fuel-libp2p", "Banning peer {:?} until {:?}", peer_id, banned_until);
+        self.banned_peers.insert(peer_id, banned_until);
+        self.pending_events
+            .push_back(PeerInfoEvent::BanPeer(peer_id));
+    }
+
+    fn unban_expired_peers(&mut self, now: Tai64) {
+        let expired: Vec<_> = self
+            .banned_peers
+            .iter()
+            .filter(|(_, banned_until)| **banned_until <= now)
             .map(|(peer_id, _)| *peer_id)
-            .choose(&mut range)
+            .collect();
+
+        for peer_id in expired {
+            self.banned_peers.remove(&peer_id);
+            self.pending_events
+                .push_back(PeerInfoEvent::UnbanPeer(peer_id));
+        }
+    }
+
+    /// Moves the scores of all peers towards zero.
+    fn decay_scores(&mut self) {
+        let decay_factor = self.reputation_config.decay_factor;
+        for peer_info in self
+            .non_reserved_connected_peers
+            .values_mut()
+            .chain(self.reserved_connected_peers.values_mut())
+        {
+            peer_info.score *= decay_factor;
+        }
+
+        self.disconnected_peers_scores.retain(|_, score| {
+            *score *= decay_factor;
+            score.abs() >= FORGOTTEN_PEER_SCORE
+        });
     }
 }
 
@@ -786,6 +1006,7 @@ mod tests {
             reserved_peers.into_iter().collect(),
             connection_state,
             max_non_reserved_peers,
+            ReputationConfig::default(),
         )
     }
 
@@ -864,4 +1085,93 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    fn banned_peer_events(peer_manager: &PeerManager) -> Vec<PeerId> {
+        peer_manager
+            .pending_events
+            .iter()
+            .filter_map(|event| match event {
+                PeerInfoEvent::BanPeer(peer_id) => Some(*peer_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn peer_is_disconnected_and_then_banned_for_bad_reports() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(peer_id);
+
+        // the score drops to the disconnect threshold, but not below it
+        peer_manager.report_peer(&peer_id, PeerReportReason::InvalidHeader);
+        assert!(!peer_manager
+            .pending_events
+            .iter()
+            .any(|event| matches!(event, PeerInfoEvent::DisconnectPeer(_))));
+
+        peer_manager.report_peer(&peer_id, PeerReportReason::RejectedGossip);
+        assert!(peer_manager.pending_events.iter().any(
+            |event| matches!(event, PeerInfoEvent::DisconnectPeer(id) if *id == peer_id)
+        ));
+        assert!(banned_peer_events(&peer_manager).is_empty());
+
+        peer_manager.report_peer(&peer_id, PeerReportReason::InvalidBlock);
+        assert_eq!(banned_peer_events(&peer_manager), vec![peer_id]);
+        assert!(peer_manager.banned_peers.contains_key(&peer_id));
+    }
+
+    #[test]
+    fn reserved_peer_is_never_banned() {
+        let reserved_peers = get_random_peers(1);
+        let mut peer_manager = initialize_peer_manager(reserved_peers.clone(), 5);
+        peer_manager.handle_initial_connection(reserved_peers[0]);
+
+        for _ in 0..10 {
+            peer_manager.report_peer(&reserved_peers[0], PeerReportReason::InvalidBlock);
+        }
+
+        assert!(banned_peer_events(&peer_manager).is_empty());
+        assert!(peer_manager.banned_peers.is_empty());
+    }
+
+    #[test]
+    fn score_is_remembered_after_reconnect_and_decays() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(peer_id);
+        peer_manager.report_peer(&peer_id, PeerReportReason::InvalidHeader);
+
+        peer_manager.handle_peer_disconnect(peer_id);
+        peer_manager.handle_initial_connection(peer_id);
+        let score = peer_manager.get_peer_info(&peer_id).unwrap().score;
+        assert_eq!(score, -50.0);
+
+        peer_manager.decay_scores();
+        let decayed_score = peer_manager.get_peer_info(&peer_id).unwrap().score;
+        assert!(score < decayed_score && decayed_score < 0.0);
+    }
+
+    #[test]
+    fn expired_bans_are_lifted() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let expired_peer = PeerId::random();
+        let banned_peer = PeerId::random();
+        peer_manager.ban_peer(expired_peer, Tai64(10));
+        peer_manager.ban_peer(banned_peer, Tai64(20));
+
+        peer_manager.unban_expired_peers(Tai64(15));
+
+        assert!(peer_manager.pending_events.iter().any(
+            |event| matches!(event, PeerInfoEvent::UnbanPeer(id) if *id == expired_peer)
+        ));
+        assert_eq!(
+            peer_manager.banned_peers.keys().collect::<Vec<_>>(),
+            vec![&banned_peer]
+        );
+
+        // banned peer is disconnected on the connection
+        peer_manager.handle_initial_connection(banned_peer);
+        assert_eq!(peer_manager.total_peers_connected(), 0);
+    }
 }
@@ -10,7 +10,9 @@ use fuel_core_types::{
         SealedBlockHeader,
     },
     fuel_tx::Transaction,
+    services::p2p::BannedPeer,
 };
+use std::ops::Range;
 
 pub trait P2pDb: Send + Sync {
     fn get_sealed_block(
@@ -18,15 +20,23 @@ pub trait P2pDb: Send + Sync {
         height: &BlockHeight,
     ) -> StorageResult<Option<SealedBlock>>;
 
-    fn get_sealed_header(
+    /// Returns the headers of the consecutive blocks starting from the beginning
+    /// of the range, until the end of the range or the first missing block.
+    fn get_sealed_headers(
         &self,
-        height: &BlockHeight,
-    ) -> StorageResult<Option<SealedBlockHeader>>;
+        block_height_range: Range<BlockHeight>,
+    ) -> StorageResult<Vec<SealedBlockHeader>>;
 
     fn get_transactions(
         &self,
         block_id: &BlockId,
     ) -> StorageResult<Option<Vec<Transaction>>>;
+
+    /// Returns the peers banned by the reputation system.
+    fn get_banned_peers(&self) -> StorageResult<Vec<BannedPeer>>;
+
+    /// Replaces the saved banned peers.
+    fn set_banned_peers(&self, peers: Vec<BannedPeer>) -> StorageResult<()>;
 }
 
 pub trait BlockHeightImporter: Send + Sync {
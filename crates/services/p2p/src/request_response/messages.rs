@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    ops::Range,
+    sync::Arc,
+};
 
 use fuel_core_types::{
     blockchain::{
@@ -22,10 +25,16 @@ use serde_with::{
 };
 use tokio::sync::oneshot;
 
-pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &[u8] = b"/fuel/req_res/0.0.1";
+pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &[u8] = b"/fuel/req_res/0.0.2";
+
+/// Max number of headers or blocks' transactions requested in a single `RequestMessage`
+pub const MAX_REQUEST_BATCH_SIZE: usize = 100;
 
 /// Max Size in Bytes of the Request Message
-pub(crate) const MAX_REQUEST_SIZE: usize = core::mem::size_of::<RequestMessage>();
+/// The largest request is the `Transactions` of `MAX_REQUEST_BATCH_SIZE` blocks,
+/// with a few bytes for the variant and the length of the list.
+pub(crate) const MAX_REQUEST_SIZE: usize =
+    MAX_REQUEST_BATCH_SIZE * core::mem::size_of::<BlockId>() + 16;
 
 pub type ChannelItem<T> = oneshot::Sender<Option<T>>;
 
@@ -39,28 +48,32 @@ pub type ChannelItem<T> = oneshot::Sender<Option<T>>;
 // Server Peer: `RequestMessage` (receive request) -> `OutboundResponse` -> `NetworkResponse` (send response)
 // Client Peer: `NetworkResponse` (receive response) -> `ResponseMessage(data)` -> `ResponseChannelItem(channel, data)` (handle response)
 
+// The responses to the batch requests contain the data for a prefix of the requested
+// heights or blocks. The responding peer stops at the first missing entry or when the
+// response would exceed the max response size, so the rest should be requested again.
+
 #[serde_as]
-#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone)]
 pub enum RequestMessage {
     Block(BlockHeight),
-    SealedHeader(BlockHeight),
-    Transactions(#[serde_as(as = "FromInto<[u8; 32]>")] BlockId),
+    SealedHeaders(Range<BlockHeight>),
+    Transactions(#[serde_as(as = "Vec<FromInto<[u8; 32]>>")] Vec<BlockId>),
 }
 
 /// Final Response Message that p2p service sends to the Orchestrator
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ResponseMessage {
     SealedBlock(Option<SealedBlock>),
-    SealedHeader(Option<SealedBlockHeader>),
-    Transactions(Option<Vec<Transaction>>),
+    SealedHeaders(Option<Vec<SealedBlockHeader>>),
+    Transactions(Option<Vec<Vec<Transaction>>>),
 }
 
 /// Holds oneshot channels for specific responses
 #[derive(Debug)]
 pub enum ResponseChannelItem {
     Block(ChannelItem<SealedBlock>),
-    SealedHeader(ChannelItem<(PeerId, SealedBlockHeader)>),
-    Transactions(ChannelItem<Vec<Transaction>>),
+    SealedHeaders(ChannelItem<(PeerId, Vec<SealedBlockHeader>)>),
+    Transactions(ChannelItem<Vec<Vec<Transaction>>>),
 }
 
 /// Response that is sent over the wire
@@ -68,7 +81,7 @@ pub enum ResponseChannelItem {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum NetworkResponse {
     Block(Option<Vec<u8>>),
-    Header(Option<Vec<u8>>),
+    Headers(Option<Vec<u8>>),
     Transactions(Option<Vec<u8>>),
 }
 
@@ -77,8 +90,8 @@ pub enum NetworkResponse {
 #[derive(Debug, Clone)]
 pub enum OutboundResponse {
     Block(Option<Arc<SealedBlock>>),
-    SealedHeader(Option<Arc<SealedBlockHeader>>),
-    Transactions(Option<Arc<Vec<Transaction>>>),
+    SealedHeaders(Option<Arc<Vec<SealedBlockHeader>>>),
+    Transactions(Option<Arc<Vec<Vec<Transaction>>>>),
 }
 
 #[derive(Debug)]
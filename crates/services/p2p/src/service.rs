@@ -20,6 +20,7 @@ use crate::{
         OutboundResponse,
         RequestMessage,
         ResponseChannelItem,
+        MAX_REQUEST_BATCH_SIZE,
     },
 };
 use anyhow::anyhow;
@@ -43,12 +44,16 @@ use fuel_core_types::{
     },
     fuel_tx::Transaction,
     services::p2p::{
+        BannedPeer,
         BlockHeightHeartbeatData,
         GossipData,
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
+        PeerId as FuelPeerId,
+        PeerReportReason,
         TransactionGossipData,
     },
+    tai64::Tai64,
 };
 use futures::StreamExt;
 use libp2p::{
@@ -57,6 +62,7 @@ use libp2p::{
 };
 use std::{
     fmt::Debug,
+    ops::Range,
     sync::Arc,
 };
 use tokio::sync::{
@@ -79,21 +85,31 @@ enum TaskRequest {
     BroadcastVote(Arc<ConsensusVote>),
     // Request to get one-off data from p2p network
     GetPeerIds(oneshot::Sender<Vec<PeerId>>),
+    GetPeerIdsWithHeight {
+        height: BlockHeight,
+        channel: oneshot::Sender<Vec<PeerId>>,
+    },
     GetBlock {
         height: BlockHeight,
         channel: oneshot::Sender<Option<SealedBlock>>,
     },
-    GetSealedHeader {
-        height: BlockHeight,
-        channel: oneshot::Sender<Option<(PeerId, SealedBlockHeader)>>,
+    GetSealedHeaders {
+        block_height_range: Range<BlockHeight>,
+        from_peer: PeerId,
+        channel: oneshot::Sender<Option<(PeerId, Vec<SealedBlockHeader>)>>,
     },
     GetTransactions {
-        block_id: BlockId,
+        block_ids: Vec<BlockId>,
         from_peer: PeerId,
-        channel: oneshot::Sender<Option<Vec<Transaction>>>,
+        channel: oneshot::Sender<Option<Vec<Vec<Transaction>>>>,
     },
     // Responds back to the p2p network
     RespondWithGossipsubMessageReport((GossipsubMessageInfo, GossipsubMessageAcceptance)),
+    // Adjusts the reputation of the peer
+    ReportPeer {
+        peer_id: PeerId,
+        reason: PeerReportReason,
+    },
 }
 
 impl Debug for TaskRequest {
@@ -140,10 +156,53 @@ impl<D> Task<D> {
     }
 }
 
+impl<D> Task<D>
+where
+    D: P2pDb,
+{
+    /// Restores the bans that haven't expired since the previous run of the node.
+    fn restore_banned_peers(&mut self) -> anyhow::Result<()> {
+        let now = Tai64::now();
+        for BannedPeer {
+            peer_id,
+            banned_until,
+        } in self.db.get_banned_peers()?
+        {
+            if banned_until <= now {
+                continue
+            }
+            match PeerId::from_bytes(peer_id.as_ref()) {
+                Ok(peer_id) => self.p2p_service.ban_peer(peer_id, banned_until),
+                Err(e) => {
+                    warn!(target: "fuel-libp2p", "Failed to read the banned PeerId: {:?}", e)
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn save_banned_peers(&self) {
+        let banned_peers = self
+            .p2p_service
+            .peer_manager()
+            .banned_peers()
+            .map(|(peer_id, banned_until)| BannedPeer {
+                peer_id: peer_id.to_bytes().into(),
+                banned_until: *banned_until,
+            })
+            .collect();
+
+        if let Err(e) = self.db.set_banned_peers(banned_peers) {
+            error!(target: "fuel-libp2p", "Failed to save the banned peers: {:?}", e);
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl<D> RunnableService for Task<D>
 where
     Self: RunnableTask,
+    D: P2pDb,
 {
     const NAME: &'static str = "P2P";
 
@@ -155,6 +214,7 @@ where
     }
 
     async fn into_task(mut self, _: &StateWatcher) -> anyhow::Result<Self::Task> {
+        self.restore_banned_peers()?;
         self.p2p_service.start()?;
         Ok(self)
     }
@@ -202,26 +262,32 @@ where
                         let peer_ids = self.p2p_service.get_peers_ids().into_iter().copied().collect();
                         let _ = channel.send(peer_ids);
                     }
+                    Some(TaskRequest::GetPeerIdsWithHeight { height, channel }) => {
+                        let peer_ids = self.p2p_service.peer_manager().get_peer_ids_with_height(&height).copied().collect();
+                        let _ = channel.send(peer_ids);
+                    }
                     Some(TaskRequest::GetBlock { height, channel }) => {
                         let request_msg = RequestMessage::Block(height);
                         let channel_item = ResponseChannelItem::Block(channel);
                         let peer = self.p2p_service.peer_manager().get_peer_id_with_height(&height);
                         let _ = self.p2p_service.send_request_msg(peer, request_msg, channel_item);
                     }
-                    Some(TaskRequest::GetSealedHeader{ height, channel: response }) => {
-                        let request_msg = RequestMessage::SealedHeader(height);
-                        let channel_item = ResponseChannelItem::SealedHeader(response);
-                        let peer = self.p2p_service.peer_manager().get_peer_id_with_height(&height);
-                        let _ = self.p2p_service.send_request_msg(peer, request_msg, channel_item);
+                    Some(TaskRequest::GetSealedHeaders { block_height_range, from_peer, channel: response }) => {
+                        let request_msg = RequestMessage::SealedHeaders(block_height_range);
+                        let channel_item = ResponseChannelItem::SealedHeaders(response);
+                        let _ = self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item);
                     }
-                    Some(TaskRequest::GetTransactions { block_id, from_peer, channel }) => {
-                        let request_msg = RequestMessage::Transactions(block_id);
+                    Some(TaskRequest::GetTransactions { block_ids, from_peer, channel }) => {
+                        let request_msg = RequestMessage::Transactions(block_ids);
                         let channel_item = ResponseChannelItem::Transactions(channel);
                         let _ = self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item);
                     }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
                         report_message(&mut self.p2p_service, message, acceptance);
                     }
+                    Some(TaskRequest::ReportPeer { peer_id, reason }) => {
+                        self.p2p_service.report_peer(&peer_id, reason);
+                    }
                     None => {
                         unreachable!("The `Task` is holder of the `Sender`, so it should not be possible");
                     }
@@ -239,6 +305,9 @@ where
 
                         let _ = self.shared.block_height_broadcast.send(block_height_data);
                     }
+                    Some(FuelP2PEvent::PeerBanned(_) | FuelP2PEvent::PeerUnbanned(_)) => {
+                        self.save_banned_peers();
+                    }
                     Some(FuelP2PEvent::GossipsubMessage { message, message_id, peer_id,.. }) => {
                         let message_id = message_id.0;
 
@@ -265,17 +334,27 @@ where
                                     .map(Arc::new);
                                 let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::Block(block_response));
                             }
-                            RequestMessage::Transactions(block_id) => {
-                                let transactions_response = self.db.get_transactions(&block_id)?
-                                    .map(Arc::new);
+                            RequestMessage::Transactions(block_ids) => {
+                                let mut transactions = Vec::with_capacity(block_ids.len());
+                                for block_id in block_ids.iter().take(MAX_REQUEST_BATCH_SIZE) {
+                                    match self.db.get_transactions(block_id)? {
+                                        Some(block_transactions) => transactions.push(block_transactions),
+                                        None => break,
+                                    }
+                                }
+                                let transactions_response = (!transactions.is_empty())
+                                    .then(|| Arc::new(transactions));
 
                                 let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::Transactions(transactions_response));
                             }
-                            RequestMessage::SealedHeader(block_height) => {
-                                let response = self.db.get_sealed_header(&block_height)?
-                                    .map(Arc::new);
-
-                                let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::SealedHeader(response));
+                            RequestMessage::SealedHeaders(block_height_range) => {
+                                let start = *block_height_range.start;
+                                let end = (*block_height_range.end)
+                                    .min(start.saturating_add(MAX_REQUEST_BATCH_SIZE as u32));
+                                let headers = self.db.get_sealed_headers(start.into()..end.into())?;
+                                let response = (!headers.is_empty()).then(|| Arc::new(headers));
+
+                                let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::SealedHeaders(response));
                             }
                         }
                     },
@@ -347,36 +426,43 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
-    pub async fn get_sealed_block_header(
+    /// Requests the headers of the range from the peer.
+    /// The response may contain only the beginning of the range.
+    pub async fn get_sealed_block_headers(
         &self,
-        height: BlockHeight,
-    ) -> anyhow::Result<Option<(Vec<u8>, SealedBlockHeader)>> {
+        peer_id: Vec<u8>,
+        block_height_range: Range<BlockHeight>,
+    ) -> anyhow::Result<Option<Vec<SealedBlockHeader>>> {
         let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id).expect("Valid PeerId");
 
         self.request_sender
-            .send(TaskRequest::GetSealedHeader {
-                height,
+            .send(TaskRequest::GetSealedHeaders {
+                block_height_range,
+                from_peer,
                 channel: sender,
             })
             .await?;
 
         receiver
             .await
-            .map(|o| o.map(|(peer_id, header)| (peer_id.to_bytes(), header)))
+            .map(|o| o.map(|(_, headers)| headers))
             .map_err(|e| anyhow!("{}", e))
     }
 
+    /// Requests the transactions of the blocks from the peer.
+    /// The response may contain only the transactions of the first blocks.
     pub async fn get_transactions_from_peer(
         &self,
         peer_id: Vec<u8>,
-        block_id: BlockId,
-    ) -> anyhow::Result<Option<Vec<Transaction>>> {
+        block_ids: Vec<BlockId>,
+    ) -> anyhow::Result<Option<Vec<Vec<Transaction>>>> {
         let (sender, receiver) = oneshot::channel();
         let from_peer = PeerId::from_bytes(&peer_id).expect("Valid PeerId");
 
         self.request_sender
             .send(TaskRequest::GetTransactions {
-                block_id,
+                block_ids,
                 from_peer,
                 channel: sender,
             })
@@ -385,6 +471,20 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Adjusts the reputation of the peer according to its behaviour.
+    /// The peer is disconnected or banned if its reputation drops too low.
+    pub fn report_peer(
+        &self,
+        peer_id: FuelPeerId,
+        reason: PeerReportReason,
+    ) -> anyhow::Result<()> {
+        let peer_id = PeerId::from_bytes(peer_id.as_ref())?;
+
+        self.request_sender
+            .try_send(TaskRequest::ReportPeer { peer_id, reason })?;
+        Ok(())
+    }
+
     pub fn broadcast_vote(&self, vote: Arc<ConsensusVote>) -> anyhow::Result<()> {
         self.request_sender
             .try_send(TaskRequest::BroadcastVote(vote))?;
@@ -418,6 +518,26 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Returns the connected peers that reported to have the block at the `height`.
+    pub async fn get_peer_ids_with_height(
+        &self,
+        height: BlockHeight,
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetPeerIdsWithHeight {
+                height,
+                channel: sender,
+            })
+            .await?;
+
+        receiver
+            .await
+            .map(|peer_ids| peer_ids.iter().map(PeerId::to_bytes).collect())
+            .map_err(|e| anyhow!("{}", e))
+    }
+
     pub fn subscribe_tx(&self) -> broadcast::Receiver<TransactionGossipData> {
         self.tx_broadcast.subscribe()
     }
@@ -465,6 +585,9 @@ fn report_message<T: NetworkCodec>(
     let peer_id: Vec<u8> = peer_id.into();
 
     if let Ok(peer_id) = peer_id.try_into() {
+        if acceptance == GossipsubMessageAcceptance::Reject {
+            p2p_service.report_peer(&peer_id, PeerReportReason::RejectedGossip);
+        }
         let acceptance = to_message_acceptance(&acceptance);
 
         match p2p_service.report_message_validation_result(&msg_id, &peer_id, acceptance)
@@ -517,16 +640,16 @@ pub mod tests {
             }))
         }
 
-        fn get_sealed_header(
+        fn get_sealed_headers(
             &self,
-            _height: &BlockHeight,
-        ) -> StorageResult<Option<SealedBlockHeader>> {
+            _block_height_range: Range<BlockHeight>,
+        ) -> StorageResult<Vec<SealedBlockHeader>> {
             let header = Default::default();
 
-            Ok(Some(SealedBlockHeader {
+            Ok(vec![SealedBlockHeader {
                 entity: header,
                 consensus: Consensus::PoA(PoAConsensus::new(Default::default())),
-            }))
+            }])
         }
 
         fn get_transactions(
@@ -535,6 +658,14 @@ pub mod tests {
         ) -> StorageResult<Option<Vec<Transaction>>> {
             Ok(Some(vec![]))
         }
+
+        fn get_banned_peers(&self) -> StorageResult<Vec<BannedPeer>> {
+            Ok(vec![])
+        }
+
+        fn set_banned_peers(&self, _peers: Vec<BannedPeer>) -> StorageResult<()> {
+            Ok(())
+        }
     }
 
     #[derive(Clone, Debug)]
@@ -28,7 +28,9 @@ use fuel_core_types::{
     },
     fuel_types::Bytes32,
     services::executor::{
+        DryRunResult,
         ExecutionBlock,
+        StateOverrides,
         UncommittedResult,
     },
     tai64::Tai64,
@@ -159,6 +161,39 @@ where
         }
         Ok(res)
     }
+
+    /// Simulates the sequence of transactions after the block at the `height` with the
+    /// state `overrides`. Each transaction sees the changes of the previous ones.
+    /// Nothing is committed into the database.
+    ///
+    /// Like [`Producer::dry_run`], the `height` only defines the block context of the
+    /// execution, while the state is the latest one: the database doesn't keep the
+    /// historical state.
+    pub async fn dry_run_batch(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        overrides: StateOverrides,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<DryRunResult>> {
+        let _permit = self.dry_run_semaphore.acquire().await;
+
+        let height = match height {
+            None => self.db.current_block_height()?,
+            Some(height) => height,
+        } + 1u64.into();
+
+        let header = self.new_header(height, Tai64::now()).await?;
+        let block = PartialFuelBlock::new(header, transactions);
+
+        let executor = self.executor.clone();
+        // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
+        let res = spawn_blocking(move || {
+            executor.dry_run_batch(block, overrides, utxo_validation)
+        })
+        .await??;
+        Ok(res)
+    }
 }
 
 impl<Database> Producer<Database>
@@ -14,7 +14,10 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
-        block::CompressedBlock,
+        block::{
+            CompressedBlock,
+            PartialFuelBlock,
+        },
         primitives::{
             BlockHeight,
             DaBlockHeight,
@@ -27,10 +30,12 @@ use fuel_core_types::{
     },
     services::{
         executor::{
+            DryRunResult,
             Error as ExecutorError,
             ExecutionBlock,
             ExecutionResult,
             Result as ExecutorResult,
+            StateOverrides,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
@@ -151,6 +156,15 @@ impl Executor<MockDb> for MockExecutor {
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         Ok(Default::default())
     }
+
+    fn dry_run_batch(
+        &self,
+        _block: PartialFuelBlock,
+        _overrides: StateOverrides,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<DryRunResult>> {
+        Ok(Default::default())
+    }
 }
 
 pub struct FailingMockExecutor(pub Mutex<Option<ExecutorError>>);
@@ -192,6 +206,20 @@ impl Executor<MockDb> for FailingMockExecutor {
             Ok(Default::default())
         }
     }
+
+    fn dry_run_batch(
+        &self,
+        _block: PartialFuelBlock,
+        _overrides: StateOverrides,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<DryRunResult>> {
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            Ok(Default::default())
+        }
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -5,7 +5,10 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
-        block::CompressedBlock,
+        block::{
+            CompressedBlock,
+            PartialFuelBlock,
+        },
         primitives::{
             BlockHeight,
             DaBlockHeight,
@@ -17,8 +20,10 @@ use fuel_core_types::{
     },
     services::{
         executor::{
+            DryRunResult,
             ExecutionBlock,
             Result as ExecutorResult,
+            StateOverrides,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
@@ -74,4 +79,14 @@ pub trait Executor<Database>: Send + Sync {
         block: ExecutionBlock,
         utxo_validation: Option<bool>,
     ) -> ExecutorResult<Vec<Vec<Receipt>>>;
+
+    /// Executes the transactions of the block one by one on top of the state `overrides`
+    /// without committing them to the database. Returns the receipts and the state diff
+    /// of each transaction.
+    fn dry_run_batch(
+        &self,
+        block: PartialFuelBlock,
+        overrides: StateOverrides,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<DryRunResult>>;
 }
@@ -3,8 +3,13 @@
 //! importing blocks from the network into the local blockchain.
 
 use std::{
-    ops::RangeInclusive,
+    collections::HashSet,
+    ops::{
+        Range,
+        RangeInclusive,
+    },
     sync::Arc,
+    time::Duration,
 };
 
 use fuel_core_services::{
@@ -22,7 +27,12 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
-    services::p2p::SourcePeer,
+    fuel_tx::Transaction,
+    services::p2p::{
+        PeerId,
+        PeerReportReason,
+        SourcePeer,
+    },
 };
 use futures::{
     stream::{
@@ -32,13 +42,21 @@ use futures::{
     Stream,
 };
 use std::future::Future;
-use tokio::sync::Notify;
+use tokio::{
+    sync::Notify,
+    time::Instant,
+};
 use tracing::Instrument;
 
+use self::scheduler::{
+    PeerRequest,
+    PeerScheduler,
+};
 use crate::{
     ports::{
         BlockImporterPort,
         ConsensusPort,
+        InvalidBlock,
         PeerToPeerPort,
     },
     state::State,
@@ -49,7 +67,12 @@ use crate::{
 };
 
 #[cfg(test)]
-pub(crate) use tests::empty_header;
+pub(crate) use tests::{
+    empty_headers,
+    empty_transactions,
+};
+
+mod scheduler;
 
 #[cfg(test)]
 mod tests;
@@ -57,6 +80,9 @@ mod tests;
 #[cfg(test)]
 mod back_pressure_tests;
 
+#[cfg(test)]
+mod scheduler_tests;
+
 #[derive(Clone, Copy, Debug)]
 /// Parameters for the import task.
 pub struct Config {
@@ -64,6 +90,28 @@ pub struct Config {
     pub max_get_header_requests: usize,
     /// The maximum number of get transaction requests to make in a single batch.
     pub max_get_txns_requests: usize,
+    /// The maximum number of headers to request from a peer in a single request.
+    /// The transactions of these blocks are requested together as well.
+    pub header_batch_size: usize,
+    /// The time to wait for the response of a peer before
+    /// requesting the data from another peer.
+    pub peer_request_timeout: Duration,
+    /// Import only the verified headers without the transactions. The blocks are
+    /// not executed, so the node has the header chain but not the state.
+    /// The message proofs are not supported for such blocks: they require the receipts.
+    pub headers_only: bool,
+    /// The trusted block at which the state of the node was imported.
+    /// The blocks up to the checkpoint are imported as headers only.
+    pub checkpoint: Option<Checkpoint>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The trusted block of the chain.
+pub struct Checkpoint {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The id of the block.
+    pub block_id: BlockId,
 }
 
 impl Default for Config {
@@ -71,10 +119,23 @@ impl Default for Config {
         Self {
             max_get_header_requests: 10,
             max_get_txns_requests: 10,
+            header_batch_size: 10,
+            peer_request_timeout: Duration::from_secs(10),
+            headers_only: false,
+            checkpoint: None,
         }
     }
 }
 
+/// The data imported for a block.
+enum ImportedBlock {
+    /// The block with the transactions to execute.
+    Full(SealedBlock),
+    /// Only the header of the block, if the import is [`Config::headers_only`]
+    /// or the block is not after the [`Config::checkpoint`].
+    Header(SealedBlockHeader),
+}
+
 pub(crate) struct Import<P, E, C> {
     /// Shared state between import and sync tasks.
     state: SharedMutex<State>,
@@ -88,6 +149,8 @@ pub(crate) struct Import<P, E, C> {
     executor: Arc<E>,
     /// Consensus port.
     consensus: Arc<C>,
+    /// Chooses the peers to request the blocks from.
+    scheduler: SharedMutex<PeerScheduler>,
 }
 
 impl<P, E, C> Import<P, E, C> {
@@ -106,6 +169,7 @@ impl<P, E, C> Import<P, E, C> {
             p2p,
             executor,
             consensus,
+            scheduler: SharedMutex::new(PeerScheduler::default()),
         }
     }
 }
@@ -127,26 +191,103 @@ where
 
     async fn import_inner(&self, shutdown: &StateWatcher) -> anyhow::Result<()> {
         // If there is a range to process, launch the stream.
-        if let Some(range) = self.state.apply(|s| s.process_range()) {
-            // Launch the stream to import the range.
-            let (count, result) = self.launch_stream(range.clone(), shutdown).await;
-
-            // Get the size of the range.
-            let range_len = range.size_hint().0 as u32;
-
-            // If we did not process the entire range, mark the failed heights as failed.
-            if (count as u32) < range_len {
-                let range = (*range.start() + count as u32)..=*range.end();
-                tracing::error!("Failed to import range of blocks: {:?}", range);
-                self.state.apply(|s| s.failed_to_process(range));
+        let Some(mut range) = self.state.apply(|s| s.process_range()) else {
+            return Ok(())
+        };
+
+        // The blocks up to the checkpoint are imported as headers only,
+        // and the rest of the range is imported after them.
+        if let Some(checkpoint) = self.params.checkpoint {
+            let checkpoint_height = *checkpoint.height;
+            if *range.start() <= checkpoint_height {
+                if !self.verify_checkpoint(&checkpoint).await? {
+                    tracing::error!("Failed to verify the checkpoint {:?}", checkpoint);
+                    self.state.apply(|s| s.failed_to_process(range));
+                    return Ok(())
+                }
+                let headers_range = *range.start()..=checkpoint_height.min(*range.end());
+                if !self.import_range(headers_range, true, shutdown).await? {
+                    return Ok(())
+                }
+                match self.state.apply(|s| s.process_range()) {
+                    Some(rest) => range = rest,
+                    None => return Ok(()),
+                }
             }
-            result?;
         }
+
+        self.import_range(range, self.params.headers_only, shutdown)
+            .await?;
         Ok(())
     }
 
+    /// Imports the range of blocks, or only their headers.
+    /// Returns true if the whole range was imported.
+    async fn import_range(
+        &self,
+        range: RangeInclusive<u32>,
+        headers_only: bool,
+        shutdown: &StateWatcher,
+    ) -> anyhow::Result<bool> {
+        // Launch the stream to import the range.
+        let (count, result) = self
+            .launch_stream(range.clone(), headers_only, shutdown)
+            .await;
+
+        // Get the size of the range.
+        let range_len = range.size_hint().0 as u32;
+
+        // If we did not process the entire range, mark the failed heights as failed.
+        if (count as u32) < range_len {
+            let range = (*range.start() + count as u32)..=*range.end();
+            tracing::error!("Failed to import range of blocks: {:?}", range);
+            self.state.apply(|s| s.failed_to_process(range));
+            result?;
+            return Ok(false)
+        }
+        result?;
+        Ok(true)
+    }
+
+    /// Requests the header of the checkpoint from the peers and checks
+    /// that it is the trusted block with the valid consensus.
+    ///
+    /// The headers before the checkpoint are verified against it, because
+    /// the `prev_root` of the checkpoint commits to the ids of all of them.
+    async fn verify_checkpoint(&self, checkpoint: &Checkpoint) -> anyhow::Result<bool> {
+        let height = *checkpoint.height;
+        let headers = request_from_peers(
+            self.p2p.as_ref(),
+            &self.scheduler,
+            self.params.peer_request_timeout,
+            checkpoint.height,
+            None,
+            |peer_id| {
+                get_headers_from_peer(self.p2p.as_ref(), peer_id, height..height + 1)
+            },
+        )
+        .await?;
+        let Some(SourcePeer {
+            peer_id,
+            data: headers,
+        }) = headers
+        else {
+            return Ok(false)
+        };
+        let Some(header) = headers.first() else {
+            return Ok(false)
+        };
+        if !matches_checkpoint(Some(checkpoint), header) {
+            tracing::warn!("Header {:?} doesn't match the checkpoint", header);
+            report_peer(self.p2p.as_ref(), peer_id, PeerReportReason::InvalidHeader);
+            return Ok(false)
+        }
+        check_consensus(self.p2p.as_ref(), self.consensus.as_ref(), &peer_id, header)
+    }
+
     #[tracing::instrument(skip(self, shutdown))]
-    /// Launches a stream to import and execute a range of blocks.
+    /// Launches a stream to import and execute a range of blocks,
+    /// or to import only their headers.
     ///
     /// This stream will process all blocks up to the given range or
     /// an error occurs.
@@ -155,6 +296,7 @@ where
     async fn launch_stream(
         &self,
         range: RangeInclusive<u32>,
+        headers_only: bool,
         shutdown: &StateWatcher,
     ) -> (usize, anyhow::Result<()>) {
         let Self {
@@ -163,49 +305,51 @@ where
             p2p,
             executor,
             consensus,
+            scheduler,
             ..
         } = &self;
-        // Request up to `max_get_header_requests` headers from the network.
-        get_header_range_buffered(range.clone(), params, p2p.clone())
+        // Request up to `max_get_header_requests` batches of headers from the network.
+        get_header_range_buffered(range.clone(), params, p2p.clone(), scheduler.clone())
         .map({
             let p2p = p2p.clone();
             let consensus_port = consensus.clone();
+            let scheduler = scheduler.clone();
+            let timeout = params.peer_request_timeout;
+            let checkpoint = params.checkpoint;
             move |result| {
                 let p2p = p2p.clone();
                 let consensus_port = consensus_port.clone();
+                let scheduler = scheduler.clone();
                 async move {
                     // Short circuit on error.
-                    let header = match result {
+                    let headers = match result {
                         Ok(h) => h,
-                        Err(e) => return Err(e),
+                        Err(e) => return vec![Err(e)],
                     };
-                    let SourcePeer {
-                        peer_id,
-                        data: header,
-                    } = header;
-                    let id = header.entity.id();
-                    let block_id = SourcePeer { peer_id, data: id };
-
-                    // Check the consensus is valid on this header.
-                    if !consensus_port
-                        .check_sealed_header(&header)
-                        .trace_err("Failed to check consensus on header")? 
-                    {
-                        tracing::warn!("Header {:?} failed consensus check", header);
-                        return Ok(None)
+                    if headers_only {
+                        return check_headers(
+                            p2p.as_ref(),
+                            consensus_port.as_ref(),
+                            checkpoint.as_ref(),
+                            headers,
+                        )
                     }
-
-                    // Wait for the da to be at least the da height on the header.
-                    consensus_port.await_da_height(&header.entity.da_height).await?;
-
-                    get_transactions_on_block(p2p.as_ref(), block_id, header).await
+                    get_blocks_for_headers(
+                        p2p.as_ref(),
+                        consensus_port.as_ref(),
+                        &scheduler,
+                        timeout,
+                        headers,
+                    )
+                    .await
                 }
             }
             .instrument(tracing::debug_span!("consensus_and_transactions"))
             .in_current_span()
         })
-        // Request up to `max_get_txns_requests` transactions from the network.
+        // Request up to `max_get_txns_requests` batches of transactions from the network.
         .buffered(params.max_get_txns_requests)
+        .flat_map(stream::iter)
         // Continue the stream unless an error or none occurs.
         // Note the error will be returned but the stream will close.
         .into_scan_none_or_err()
@@ -221,20 +365,38 @@ where
         .then({
             let state = state.clone();
             let executor = executor.clone();
+            let p2p = p2p.clone();
             move |block| {
                 let state = state.clone();
                 let executor = executor.clone();
+                let p2p = p2p.clone();
                 async move {
                     // Short circuit on error.
-                    let block = match block {
+                    let SourcePeer { peer_id, data: block } = match block {
                         Ok(b) => b,
                         Err(e) => return Err(e),
                     };
 
-                    execute_and_commit(executor.as_ref(), &state, block).await
+                    let invalid_report = match &block {
+                        ImportedBlock::Full(_) => PeerReportReason::InvalidBlock,
+                        ImportedBlock::Header(_) => PeerReportReason::InvalidHeader,
+                    };
+                    let result = commit(executor.as_ref(), &state, block).await;
+                    // The failures of the node itself are not the fault of the peer.
+                    let report = match &result {
+                        Ok(_) => Some(PeerReportReason::SuccessfulBlockImport),
+                        Err(e) if e.downcast_ref::<InvalidBlock>().is_some() => {
+                            Some(invalid_report)
+                        }
+                        Err(_) => None,
+                    };
+                    if let Some(report) = report {
+                        report_peer(p2p.as_ref(), peer_id, report);
+                    }
+                    result
                 }
             }
-            .instrument(tracing::debug_span!("execute_and_commit"))
+            .instrument(tracing::debug_span!("commit"))
             .in_current_span()
         })
         // Continue the stream unless an error occurs.
@@ -272,96 +434,488 @@ async fn wait_for_notify_or_shutdown(
     matches!(r, futures::future::Either::Left(_))
 }
 
-/// Returns a stream of headers processing concurrently up to `max_get_header_requests`.
+/// Returns a stream of header batches processing concurrently up to `max_get_header_requests`.
 /// The headers are returned in order.
 fn get_header_range_buffered(
     range: RangeInclusive<u32>,
     params: &Config,
     p2p: Arc<impl PeerToPeerPort + Send + Sync + 'static>,
-) -> impl Stream<Item = anyhow::Result<SourcePeer<SealedBlockHeader>>> {
-    get_header_range(range, p2p)
+    scheduler: SharedMutex<PeerScheduler>,
+) -> impl Stream<Item = anyhow::Result<SourcePeer<Vec<SealedBlockHeader>>>> {
+    get_header_range(range, params, p2p, scheduler)
         .buffered(params.max_get_header_requests)
+        .flat_map(stream::iter)
         // Continue the stream unless an error or none occurs.
         .into_scan_none_or_err()
         .scan_none_or_err()
 }
 
-#[tracing::instrument(skip(p2p))]
-/// Returns a stream of network requests for headers.
+#[tracing::instrument(skip(params, p2p, scheduler))]
+/// Returns a stream of network requests for batches of headers.
+/// The batches are spread across the peers by the scheduler.
 fn get_header_range(
     range: RangeInclusive<u32>,
+    params: &Config,
     p2p: Arc<impl PeerToPeerPort + 'static>,
+    scheduler: SharedMutex<PeerScheduler>,
 ) -> impl Stream<
-    Item = impl Future<Output = anyhow::Result<Option<SourcePeer<SealedBlockHeader>>>>,
+    Item = impl Future<
+        Output = Vec<anyhow::Result<Option<SourcePeer<Vec<SealedBlockHeader>>>>>,
+    >,
 > {
-    stream::iter(range).map(move |height| {
+    let end = range.end().saturating_add(1);
+    let batch_size = params.header_batch_size.max(1);
+    let timeout = params.peer_request_timeout;
+    stream::iter(range.step_by(batch_size)).map(move |start| {
         let p2p = p2p.clone();
-        let height: BlockHeight = height.into();
-        async move {
-            tracing::debug!("getting header height: {}", *height);
-            Ok(p2p
-                .get_sealed_block_header(height)
-                .await
-                .trace_err("Failed to get header")?
-                .and_then(|header| {
-                    // Check the header is the expected height.
-                    validate_header_height(height, &header.data)
-                        .then_some(header)
-                        .trace_none_error("Failed to validate header height")
-                })
-                .trace_none_warn("Failed to find header"))
-        }
-        .instrument(tracing::debug_span!(
-            "get_sealed_block_header",
-            height = *height
-        ))
-        .in_current_span()
+        let scheduler = scheduler.clone();
+        let batch = start..start.saturating_add(batch_size as u32).min(end);
+        let span = tracing::debug_span!(
+            "get_sealed_block_headers",
+            start = batch.start,
+            end = batch.end
+        );
+        async move { get_headers_batch(batch, p2p.as_ref(), &scheduler, timeout).await }
+            .instrument(span)
+            .in_current_span()
     })
 }
 
-/// Returns true if the header is the expected height.
-fn validate_header_height(
-    expected_height: BlockHeight,
+/// Requests the headers of the batch until all of them are received.
+/// Peers may respond with only the beginning of the requested range,
+/// so the rest of the batch is requested again.
+///
+/// If the batch is not complete, the last item is `None` or an error.
+async fn get_headers_batch<P>(
+    batch: Range<u32>,
+    p2p: &P,
+    scheduler: &SharedMutex<PeerScheduler>,
+    timeout: Duration,
+) -> Vec<anyhow::Result<Option<SourcePeer<Vec<SealedBlockHeader>>>>>
+where
+    P: PeerToPeerPort + 'static,
+{
+    let mut results = vec![];
+    let mut start = batch.start;
+    while start < batch.end {
+        tracing::debug!("getting headers from height: {}", start);
+        let range = start..batch.end;
+        // The peer should have all the headers of the range.
+        let last_height = (batch.end - 1).into();
+        let result =
+            request_from_peers(p2p, scheduler, timeout, last_height, None, |peer_id| {
+                get_headers_from_peer(p2p, peer_id, range.clone())
+            })
+            .await;
+        match result {
+            Ok(Some(headers)) => {
+                start += headers.data.len() as u32;
+                results.push(Ok(Some(headers)));
+            }
+            Ok(None) => {
+                results.push(Ok(None));
+                break
+            }
+            Err(e) => {
+                results.push(Err(e));
+                break
+            }
+        }
+    }
+    results
+}
+
+/// Requests the headers of the range from the peer and checks
+/// they are the expected heights.
+async fn get_headers_from_peer<P>(
+    p2p: &P,
+    peer_id: PeerId,
+    range: Range<u32>,
+) -> anyhow::Result<Option<Vec<SealedBlockHeader>>>
+where
+    P: PeerToPeerPort,
+{
+    let headers = p2p
+        .get_sealed_block_headers(SourcePeer {
+            peer_id: peer_id.clone(),
+            data: range.clone(),
+        })
+        .await
+        .trace_err("Failed to get headers")?
+        .trace_none_warn("Failed to find headers");
+
+    Ok(headers.and_then(|headers| {
+        // Check the headers are the expected heights.
+        if validate_header_heights(range, &headers) {
+            Some(headers)
+        } else {
+            tracing::error!("Failed to validate header heights");
+            report_peer(p2p, peer_id, PeerReportReason::InvalidHeader);
+            None
+        }
+    }))
+}
+
+/// Sends the request to the peers that have the block at the `height`
+/// until one of them responds with the data in time.
+/// The scheduler chooses the order of the peers, but the `first_peer`
+/// is asked before the others if it is set.
+///
+/// Returns the last error if no peer responded with the data.
+async fn request_from_peers<P, T, F, Fut>(
+    p2p: &P,
+    scheduler: &SharedMutex<PeerScheduler>,
+    timeout: Duration,
+    height: BlockHeight,
+    first_peer: Option<PeerId>,
+    request: F,
+) -> anyhow::Result<Option<SourcePeer<Vec<T>>>>
+where
+    P: PeerToPeerPort,
+    F: Fn(PeerId) -> Fut,
+    Fut: Future<Output = anyhow::Result<Option<Vec<T>>>>,
+{
+    let candidates = p2p
+        .get_peers_with_height(height)
+        .await
+        .trace_err("Failed to get peers")?;
+    let mut excluded = HashSet::new();
+    let mut last_error = None;
+    loop {
+        let peer_request = match first_peer.as_ref() {
+            Some(peer_id) if !excluded.contains(peer_id) => {
+                PeerRequest::select(scheduler, std::slice::from_ref(peer_id), &excluded)
+            }
+            _ => PeerRequest::select(scheduler, &candidates, &excluded),
+        };
+        let Some(peer_request) = peer_request else {
+            break
+        };
+        let peer_id = peer_request.peer_id().clone();
+        excluded.insert(peer_id.clone());
+
+        let started = Instant::now();
+        match tokio::time::timeout(timeout, request(peer_id.clone())).await {
+            Ok(Ok(Some(data))) if !data.is_empty() => {
+                peer_request.complete(started.elapsed(), data.len());
+                return Ok(Some(SourcePeer { peer_id, data }))
+            }
+            Ok(Ok(_)) => peer_request.fail(),
+            Ok(Err(e)) => {
+                peer_request.fail();
+                last_error = Some(e);
+            }
+            Err(_) => {
+                tracing::warn!("Peer {:?} did not respond in {:?}", peer_id, timeout);
+                peer_request.fail();
+            }
+        }
+    }
+    match last_error {
+        Some(e) => Err(e),
+        None => Ok(None),
+    }
+}
+
+/// Returns true if the headers are not empty and
+/// have consecutive heights from the beginning of the range.
+fn validate_header_heights(range: Range<u32>, headers: &[SealedBlockHeader]) -> bool {
+    !headers.is_empty()
+        && headers.len() <= range.len()
+        && headers
+            .iter()
+            .zip(range)
+            .all(|(header, height)| **header.entity.height() == height)
+}
+
+/// Reports the behaviour of the peer.
+/// The failure to report is only logged, because it doesn't affect the import.
+fn report_peer<P>(p2p: &P, peer_id: PeerId, report: PeerReportReason)
+where
+    P: PeerToPeerPort,
+{
+    let _ = p2p
+        .report_peer(peer_id, report)
+        .trace_err("Failed to report peer");
+}
+
+/// Checks the consensus of the headers and requests the transactions
+/// of the valid headers, preferably from the peer that provided them.
+///
+/// If not all the blocks are returned, the last item is `None` or an error.
+async fn get_blocks_for_headers<P, C>(
+    p2p: &P,
+    consensus_port: &C,
+    scheduler: &SharedMutex<PeerScheduler>,
+    timeout: Duration,
+    headers: SourcePeer<Vec<SealedBlockHeader>>,
+) -> Vec<anyhow::Result<Option<SourcePeer<ImportedBlock>>>>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    let SourcePeer {
+        peer_id,
+        data: headers,
+    } = headers;
+
+    let mut checked_headers = Vec::with_capacity(headers.len());
+    let mut failure = None;
+    for header in headers {
+        match check_sealed_header(p2p, consensus_port, &peer_id, &header).await {
+            Ok(true) => checked_headers.push(header),
+            Ok(false) => {
+                failure = Some(Ok(None));
+                break
+            }
+            Err(e) => {
+                failure = Some(Err(e));
+                break
+            }
+        }
+    }
+
+    let mut blocks =
+        get_transactions_on_blocks(p2p, scheduler, timeout, peer_id, checked_headers)
+            .await;
+    // The failure is only relevant if all the checked blocks were received.
+    if !matches!(blocks.last(), Some(Ok(None)) | Some(Err(_))) {
+        blocks.extend(failure);
+    }
+    blocks
+}
+
+/// Checks the consensus of the headers for the headers only import.
+/// The da height is not awaited, because the blocks are not executed.
+/// The header at the height of the `checkpoint` should be the trusted block.
+///
+/// If not all the headers are valid, the last item is `None` or an error.
+fn check_headers<P, C>(
+    p2p: &P,
+    consensus_port: &C,
+    checkpoint: Option<&Checkpoint>,
+    headers: SourcePeer<Vec<SealedBlockHeader>>,
+) -> Vec<anyhow::Result<Option<SourcePeer<ImportedBlock>>>>
+where
+    P: PeerToPeerPort,
+    C: ConsensusPort,
+{
+    let SourcePeer {
+        peer_id,
+        data: headers,
+    } = headers;
+
+    let mut results = Vec::with_capacity(headers.len());
+    for header in headers {
+        if !matches_checkpoint(checkpoint, &header) {
+            tracing::warn!("Header {:?} doesn't match the checkpoint", header);
+            report_peer(p2p, peer_id.clone(), PeerReportReason::InvalidHeader);
+            results.push(Ok(None));
+            break
+        }
+        match check_consensus(p2p, consensus_port, &peer_id, &header) {
+            Ok(true) => results.push(Ok(Some(SourcePeer {
+                peer_id: peer_id.clone(),
+                data: ImportedBlock::Header(header),
+            }))),
+            Ok(false) => {
+                results.push(Ok(None));
+                break
+            }
+            Err(e) => {
+                results.push(Err(e));
+                break
+            }
+        }
+    }
+    results
+}
+
+/// Returns false if the header is at the height of the `checkpoint`,
+/// but it is not the trusted block.
+fn matches_checkpoint(
+    checkpoint: Option<&Checkpoint>,
     header: &SealedBlockHeader,
 ) -> bool {
-    header.entity.consensus.height == expected_height
+    match checkpoint {
+        Some(checkpoint) if *header.entity.height() == checkpoint.height => {
+            header.entity.id() == checkpoint.block_id
+        }
+        _ => true,
+    }
+}
+
+/// Returns true if the consensus on the header is valid
+/// and waits for the da to be at least the da height on the header.
+async fn check_sealed_header<P, C>(
+    p2p: &P,
+    consensus_port: &C,
+    peer_id: &PeerId,
+    header: &SealedBlockHeader,
+) -> anyhow::Result<bool>
+where
+    P: PeerToPeerPort,
+    C: ConsensusPort,
+{
+    if !check_consensus(p2p, consensus_port, peer_id, header)? {
+        return Ok(false)
+    }
+
+    // Wait for the da to be at least the da height on the header.
+    consensus_port
+        .await_da_height(&header.entity.da_height)
+        .await?;
+    Ok(true)
+}
+
+/// Returns true if the consensus on the header is valid.
+/// The peer is reported if it is not.
+fn check_consensus<P, C>(
+    p2p: &P,
+    consensus_port: &C,
+    peer_id: &PeerId,
+    header: &SealedBlockHeader,
+) -> anyhow::Result<bool>
+where
+    P: PeerToPeerPort,
+    C: ConsensusPort,
+{
+    // Check the consensus is valid on this header.
+    if !consensus_port
+        .check_sealed_header(header)
+        .trace_err("Failed to check consensus on header")?
+    {
+        tracing::warn!("Header {:?} failed consensus check", header);
+        report_peer(p2p, peer_id.clone(), PeerReportReason::InvalidHeader);
+        return Ok(false)
+    }
+    Ok(true)
 }
 
 #[tracing::instrument(
-    skip(p2p, header),
+    skip(p2p, scheduler, timeout, headers),
     fields(
-        height = **header.entity.height(),
-        id = %header.entity.consensus.generated.application_hash
-    ),
-    err
+        start = headers.first().map(|h| **h.entity.height()),
+        len = headers.len()
+    )
 )]
-async fn get_transactions_on_block<P>(
+/// Requests the transactions of the blocks until all of them are received.
+/// Peers may respond with only the transactions of the first blocks,
+/// so the transactions of the rest of the blocks are requested again.
+///
+/// The blocks are attributed to the `peer_id` that provided the headers,
+/// because the transactions are already checked against the headers.
+async fn get_transactions_on_blocks<P>(
     p2p: &P,
-    block_id: SourcePeer<BlockId>,
-    header: SealedBlockHeader,
-) -> anyhow::Result<Option<SealedBlock>>
+    scheduler: &SharedMutex<PeerScheduler>,
+    timeout: Duration,
+    peer_id: PeerId,
+    headers: Vec<SealedBlockHeader>,
+) -> Vec<anyhow::Result<Option<SourcePeer<ImportedBlock>>>>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
+    let mut results = Vec::with_capacity(headers.len());
+    let mut remaining = &headers[..];
+    while let Some(last_header) = remaining.last() {
+        let last_height = *last_header.entity.height();
+        let result = request_from_peers(
+            p2p,
+            scheduler,
+            timeout,
+            last_height,
+            Some(peer_id.clone()),
+            |peer_id| get_blocks_from_peer(p2p, peer_id, remaining),
+        )
+        .await;
+        match result {
+            Ok(Some(SourcePeer { data: blocks, .. })) => {
+                remaining = &remaining[blocks.len()..];
+                results.extend(blocks.into_iter().map(|block| {
+                    Ok(Some(SourcePeer {
+                        peer_id: peer_id.clone(),
+                        data: ImportedBlock::Full(block),
+                    }))
+                }));
+            }
+            Ok(None) => {
+                results.push(Ok(None));
+                break
+            }
+            Err(e) => {
+                results.push(Err(e));
+                break
+            }
+        }
+    }
+    results
+}
+
+/// Requests the transactions of the blocks from the peer
+/// and creates the blocks from them.
+async fn get_blocks_from_peer<P>(
+    p2p: &P,
+    peer_id: PeerId,
+    headers: &[SealedBlockHeader],
+) -> anyhow::Result<Option<Vec<SealedBlock>>>
+where
+    P: PeerToPeerPort,
+{
+    let block_ids = headers.iter().map(|h| h.entity.id()).collect();
+    let Some(transactions) = p2p
+        .get_transactions(SourcePeer {
+            peer_id: peer_id.clone(),
+            data: block_ids,
+        })
+        .await
+        .trace_err("Failed to get transactions")?
+        .trace_none_warn("Could not find transactions for headers")
+    else {
+        return Ok(None)
+    };
+
+    let mut blocks = Vec::with_capacity(transactions.len());
+    for (transactions, header) in transactions.into_iter().zip(headers) {
+        let Some(block) = build_block(header.clone(), transactions) else {
+            report_peer(p2p, peer_id, PeerReportReason::InvalidBlock);
+            return Ok(None)
+        };
+        blocks.push(block);
+    }
+    Ok(Some(blocks))
+}
+
+/// Creates the block from the header and the transactions.
+/// Returns `None` if the transactions don't match the header.
+fn build_block(
+    header: SealedBlockHeader,
+    transactions: Vec<Transaction>,
+) -> Option<SealedBlock> {
     let Sealed {
         entity: header,
         consensus,
     } = header;
+    let block = Block::try_from_executed(header, transactions)
+        .trace_none_warn("Failed to created header from executed transactions")?;
+    Some(SealedBlock {
+        entity: block,
+        consensus,
+    })
+}
 
-    // Request the transactions for this block.
-    Ok(p2p
-        .get_transactions(block_id)
-        .await
-        .trace_err("Failed to get transactions")?
-        .trace_none_warn("Could not find transactions for header")
-        .and_then(|transactions| {
-            let block = Block::try_from_executed(header, transactions)
-                .trace_none_warn("Failed to created header from executed transactions")?;
-            Some(SealedBlock {
-                entity: block,
-                consensus,
-            })
-        }))
+/// Executes and commits the block or commits only its header.
+async fn commit<E>(
+    executor: &E,
+    state: &SharedMutex<State>,
+    block: ImportedBlock,
+) -> anyhow::Result<()>
+where
+    E: BlockImporterPort + Send + Sync + 'static,
+{
+    match block {
+        ImportedBlock::Full(block) => execute_and_commit(executor, state, block).await,
+        ImportedBlock::Header(header) => commit_header(executor, state, header).await,
+    }
 }
 
 #[tracing::instrument(
@@ -393,6 +947,34 @@ where
     r
 }
 
+#[tracing::instrument(
+    skip_all,
+    fields(
+        height = **header.entity.height(),
+        id = %header.entity.consensus.generated.application_hash
+    ),
+    err
+)]
+async fn commit_header<E>(
+    executor: &E,
+    state: &SharedMutex<State>,
+    header: SealedBlockHeader,
+) -> anyhow::Result<()>
+where
+    E: BlockImporterPort + Send + Sync + 'static,
+{
+    let height = *header.entity.height();
+    let r = executor.commit_header(header).await;
+
+    // If the header was committed, mark its height as committed.
+    if r.is_ok() {
+        state.apply(|s| s.commit(*height));
+    } else {
+        tracing::error!("Commit of the header at height {} failed: {:?}", *height, r);
+    }
+    r
+}
+
 /// Extra stream utilities.
 trait StreamUtil: Sized {
     /// Turn a stream of `Result<Option<T>>` into a stream of `Result<T>`.
@@ -3,6 +3,7 @@ use std::time::Duration;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
     blockchain::primitives::{
+        BlockHeight,
         BlockId,
         DaBlockHeight,
     },
@@ -17,7 +18,10 @@ use crate::ports::{
 };
 
 use super::{
-    tests::empty_header,
+    tests::{
+        empty_headers,
+        empty_transactions,
+    },
     *,
 };
 use test_case::test_case;
@@ -35,6 +39,10 @@ struct Input {
     Config{
         max_get_header_requests: 1,
         max_get_txns_requests: 1,
+        header_batch_size: 1,
+        peer_request_timeout: Duration::from_secs(10),
+        headers_only: false,
+        checkpoint: None,
     }
     => Count::default() ; "Empty sanity test"
 )]
@@ -47,6 +55,10 @@ struct Input {
     Config{
         max_get_header_requests: 1,
         max_get_txns_requests: 1,
+        header_batch_size: 1,
+        peer_request_timeout: Duration::from_secs(10),
+        headers_only: false,
+        checkpoint: None,
     }
     => is less_or_equal_than Count{ headers: 1, consensus: 1, transactions: 1, executes: 1, blocks: 1 }
     ; "Single with slow headers"
@@ -60,6 +72,10 @@ struct Input {
     Config{
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        header_batch_size: 1,
+        peer_request_timeout: Duration::from_secs(10),
+        headers_only: false,
+        checkpoint: None,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow headers"
@@ -73,6 +89,10 @@ struct Input {
     Config{
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        header_batch_size: 1,
+        peer_request_timeout: Duration::from_secs(10),
+        headers_only: false,
+        checkpoint: None,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow transactions"
@@ -86,6 +106,10 @@ struct Input {
     Config{
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        header_batch_size: 1,
+        peer_request_timeout: Duration::from_secs(10),
+        headers_only: false,
+        checkpoint: None,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 with slow executes"
@@ -106,14 +130,7 @@ async fn test_back_pressure(input: Input, state: State, params: Config) -> Count
     let consensus = Arc::new(PressureConsensusPort::new(counts.clone(), input.consensus));
     let notify = Arc::new(Notify::new());
 
-    let import = Import {
-        state,
-        notify,
-        params,
-        p2p,
-        executor,
-        consensus,
-    };
+    let import = Import::new(state, notify, params, p2p, executor, consensus);
 
     import.notify.notify_one();
     let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
@@ -149,26 +166,35 @@ impl PeerToPeerPort for PressurePeerToPeerPort {
     fn height_stream(&self) -> BoxStream<BlockHeight> {
         self.0.height_stream()
     }
-    async fn get_sealed_block_header(
+    async fn get_peers_with_height(
         &self,
         height: BlockHeight,
-    ) -> anyhow::Result<Option<SourcePeer<SealedBlockHeader>>> {
+    ) -> anyhow::Result<Vec<PeerId>> {
+        self.0.get_peers_with_height(height).await
+    }
+    async fn get_sealed_block_headers(
+        &self,
+        block_height_range: SourcePeer<Range<u32>>,
+    ) -> anyhow::Result<Option<Vec<SealedBlockHeader>>> {
         self.2.apply(|c| c.inc_headers());
         tokio::time::sleep(self.1[0]).await;
         self.2.apply(|c| {
             c.dec_headers();
             c.inc_blocks();
         });
-        self.0.get_sealed_block_header(height).await
+        self.0.get_sealed_block_headers(block_height_range).await
     }
     async fn get_transactions(
         &self,
-        block_id: SourcePeer<BlockId>,
-    ) -> anyhow::Result<Option<Vec<Transaction>>> {
+        block_ids: SourcePeer<Vec<BlockId>>,
+    ) -> anyhow::Result<Option<Vec<Vec<Transaction>>>> {
         self.2.apply(|c| c.inc_transactions());
         tokio::time::sleep(self.1[1]).await;
         self.2.apply(|c| c.dec_transactions());
-        self.0.get_transactions(block_id).await
+        self.0.get_transactions(block_ids).await
+    }
+    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()> {
+        self.0.report_peer(peer, report)
     }
 }
 
@@ -187,6 +213,10 @@ impl BlockImporterPort for PressureBlockImporterPort {
         });
         self.0.execute_and_commit(block).await
     }
+
+    async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()> {
+        self.0.commit_header(header).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -206,10 +236,13 @@ impl ConsensusPort for PressureConsensusPort {
 impl PressurePeerToPeerPort {
     fn new(counts: SharedCounts, delays: [Duration; 2]) -> Self {
         let mut mock = MockPeerToPeerPort::default();
-        mock.expect_get_sealed_block_header()
-            .returning(|h| Ok(Some(empty_header(h))));
+        mock.expect_get_sealed_block_headers()
+            .returning(|r| Ok(Some(empty_headers(r))));
         mock.expect_get_transactions()
-            .returning(|_| Ok(Some(vec![])));
+            .returning(|ids| Ok(Some(empty_transactions(ids))));
+        mock.expect_report_peer().returning(|_, _| Ok(()));
+        mock.expect_get_peers_with_height()
+            .returning(|_| Ok(vec![PeerId::from(vec![])]));
         Self(mock, delays, counts)
     }
 }
@@ -0,0 +1,166 @@
+//! # Peer Scheduler
+//! Chooses the peers to download the blocks from.
+//! The requests are spread across the peers and the peers that
+//! respond faster are preferred over the slow or failing ones.
+
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    time::Duration,
+};
+
+use fuel_core_services::SharedMutex;
+use fuel_core_types::services::p2p::PeerId;
+
+/// The weight of the latest measurement in the moving averages.
+const MEASUREMENT_WEIGHT: f64 = 0.3;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+/// The statistics of the requests made to the peer.
+pub(crate) struct PeerStats {
+    /// The number of requests in progress.
+    pub in_flight: usize,
+    /// The number of requests that failed since the last successful one.
+    pub failures: usize,
+    /// The moving average of the latency of the successful requests.
+    pub latency: Option<Duration>,
+    /// The moving average of the number of blocks received per second.
+    pub throughput: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+/// Tracks the statistics of the peers used by the import.
+pub(crate) struct PeerScheduler {
+    peers: HashMap<PeerId, PeerStats>,
+}
+
+impl PeerScheduler {
+    /// Chooses the best peer among the candidates which are not excluded
+    /// and marks the request to it as in progress.
+    ///
+    /// The peers with the fewest failures are preferred,
+    /// then the least busy ones and then the fastest ones.
+    /// The peers without measurements are tried before the measured ones.
+    pub fn select(
+        &mut self,
+        candidates: &[PeerId],
+        excluded: &HashSet<PeerId>,
+    ) -> Option<PeerId> {
+        let peer_id = candidates
+            .iter()
+            .filter(|peer_id| !excluded.contains(*peer_id))
+            .min_by(|a, b| {
+                let a = self.peers.get(*a).cloned().unwrap_or_default();
+                let b = self.peers.get(*b).cloned().unwrap_or_default();
+                a.failures
+                    .cmp(&b.failures)
+                    .then(a.in_flight.cmp(&b.in_flight))
+                    .then_with(|| {
+                        let a = a.throughput.unwrap_or(f64::INFINITY);
+                        let b = b.throughput.unwrap_or(f64::INFINITY);
+                        b.total_cmp(&a)
+                    })
+            })?
+            .clone();
+        self.peers.entry(peer_id.clone()).or_default().in_flight += 1;
+        Some(peer_id)
+    }
+
+    /// Records the successful request that returned the `blocks`.
+    pub fn complete(&mut self, peer_id: &PeerId, latency: Duration, blocks: usize) {
+        let Some(stats) = self.finish(peer_id) else {
+            return
+        };
+        stats.failures = 0;
+
+        let throughput = blocks as f64 / latency.as_secs_f64().max(f64::EPSILON);
+        stats.latency = Some(match stats.latency {
+            Some(average) => average
+                .mul_f64(1.0 - MEASUREMENT_WEIGHT)
+                .saturating_add(latency.mul_f64(MEASUREMENT_WEIGHT)),
+            None => latency,
+        });
+        stats.throughput = Some(match stats.throughput {
+            Some(average) => {
+                average * (1.0 - MEASUREMENT_WEIGHT) + throughput * MEASUREMENT_WEIGHT
+            }
+            None => throughput,
+        });
+    }
+
+    /// Records the request that failed, timed out or returned no data.
+    pub fn fail(&mut self, peer_id: &PeerId) {
+        if let Some(stats) = self.finish(peer_id) {
+            stats.failures = stats.failures.saturating_add(1);
+        }
+    }
+
+    /// Records the request that was dropped before it finished.
+    pub fn cancel(&mut self, peer_id: &PeerId) {
+        self.finish(peer_id);
+    }
+
+    #[cfg(test)]
+    /// Get the statistics of the peer.
+    pub fn stats(&self, peer_id: &PeerId) -> Option<&PeerStats> {
+        self.peers.get(peer_id)
+    }
+
+    fn finish(&mut self, peer_id: &PeerId) -> Option<&mut PeerStats> {
+        let stats = self.peers.get_mut(peer_id)?;
+        stats.in_flight = stats.in_flight.saturating_sub(1);
+        Some(stats)
+    }
+}
+
+/// The request in progress to the peer.
+/// If it is dropped without the outcome, the request is cancelled.
+pub(crate) struct PeerRequest<'a> {
+    scheduler: &'a SharedMutex<PeerScheduler>,
+    peer_id: PeerId,
+    finished: bool,
+}
+
+impl<'a> PeerRequest<'a> {
+    /// Chooses the peer for the request, see [`PeerScheduler::select`].
+    pub fn select(
+        scheduler: &'a SharedMutex<PeerScheduler>,
+        candidates: &[PeerId],
+        excluded: &HashSet<PeerId>,
+    ) -> Option<Self> {
+        let peer_id = scheduler.apply(|s| s.select(candidates, excluded))?;
+        Some(Self {
+            scheduler,
+            peer_id,
+            finished: false,
+        })
+    }
+
+    /// The peer the request is sent to.
+    pub fn peer_id(&self) -> &PeerId {
+        &self.peer_id
+    }
+
+    /// Records the successful request, see [`PeerScheduler::complete`].
+    pub fn complete(mut self, latency: Duration, blocks: usize) {
+        self.finished = true;
+        self.scheduler
+            .apply(|s| s.complete(&self.peer_id, latency, blocks));
+    }
+
+    /// Records the failed request, see [`PeerScheduler::fail`].
+    pub fn fail(mut self) {
+        self.finished = true;
+        self.scheduler.apply(|s| s.fail(&self.peer_id));
+    }
+}
+
+impl Drop for PeerRequest<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.scheduler.apply(|s| s.cancel(&self.peer_id));
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    collections::HashSet,
+    time::Duration,
+};
+
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_tx::Transaction,
+};
+
+use crate::ports::{
+    MockBlockImporterPort,
+    MockConsensusPort,
+    MockPeerToPeerPort,
+};
+
+use super::{
+    scheduler::PeerStats,
+    tests::{
+        empty_headers,
+        empty_transactions,
+    },
+    *,
+};
+
+fn peer(id: u8) -> PeerId {
+    vec![id].into()
+}
+
+type Requests = SharedMutex<Vec<(PeerId, u32)>>;
+
+/// Delays the header requests to the slow peer.
+struct SlowPeerToPeerPort {
+    mock: MockPeerToPeerPort,
+    slow_peer: PeerId,
+    delay: Duration,
+}
+
+#[async_trait::async_trait]
+impl PeerToPeerPort for SlowPeerToPeerPort {
+    fn height_stream(&self) -> BoxStream<BlockHeight> {
+        self.mock.height_stream()
+    }
+    async fn get_peers_with_height(
+        &self,
+        height: BlockHeight,
+    ) -> anyhow::Result<Vec<PeerId>> {
+        self.mock.get_peers_with_height(height).await
+    }
+    async fn get_sealed_block_headers(
+        &self,
+        block_height_range: SourcePeer<Range<u32>>,
+    ) -> anyhow::Result<Option<Vec<SealedBlockHeader>>> {
+        if block_height_range.peer_id == self.slow_peer {
+            tokio::time::sleep(self.delay).await;
+        }
+        self.mock.get_sealed_block_headers(block_height_range).await
+    }
+    async fn get_transactions(
+        &self,
+        block_ids: SourcePeer<Vec<BlockId>>,
+    ) -> anyhow::Result<Option<Vec<Vec<Transaction>>>> {
+        self.mock.get_transactions(block_ids).await
+    }
+    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()> {
+        self.mock.report_peer(peer, report)
+    }
+}
+
+/// The mock records the peer and the first height of each header request.
+fn p2p_with_peers(peers: Vec<PeerId>, header_requests: Requests) -> MockPeerToPeerPort {
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_peers_with_height()
+        .returning(move |_| Ok(peers.clone()));
+    p2p.expect_get_sealed_block_headers().returning(move |r| {
+        header_requests
+            .apply(|requests| requests.push((r.peer_id.clone(), r.data.start)));
+        Ok(Some(empty_headers(r)))
+    });
+    p2p.expect_get_transactions()
+        .returning(|ids| Ok(Some(empty_transactions(ids))));
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+    p2p
+}
+
+fn executor(blocks: usize) -> MockBlockImporterPort {
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_execute_and_commit()
+        .times(blocks)
+        .returning(|_| Ok(()));
+    executor
+}
+
+fn consensus() -> MockConsensusPort {
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .returning(|_| Ok(()));
+    consensus_port
+}
+
+fn params(peer_request_timeout: Duration) -> Config {
+    Config {
+        max_get_header_requests: 10,
+        max_get_txns_requests: 10,
+        header_batch_size: 2,
+        peer_request_timeout,
+        headers_only: false,
+        checkpoint: None,
+    }
+}
+
+async fn import<P>(
+    state: State,
+    params: Config,
+    p2p: P,
+    blocks: usize,
+) -> Import<P, MockBlockImporterPort, MockConsensusPort>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+{
+    let import = Import::new(
+        SharedMutex::new(state),
+        Arc::new(Notify::new()),
+        params,
+        Arc::new(p2p),
+        Arc::new(executor(blocks)),
+        Arc::new(consensus()),
+    );
+    let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
+    let mut watcher = shutdown.into();
+    import.notify.notify_one();
+    import.import(&mut watcher).await.unwrap();
+    import
+}
+
+#[tokio::test]
+async fn import_splits_range_across_peers() {
+    let requests = Requests::new(vec![]);
+    let p2p = p2p_with_peers(vec![peer(1), peer(2)], requests.clone());
+
+    let import =
+        import(State::new(None, 3), params(Duration::from_secs(10)), p2p, 4).await;
+
+    let mut requests = requests.apply(|r| r.clone());
+    requests.sort_by_key(|(_, start)| *start);
+    assert_eq!(requests, vec![(peer(1), 0), (peer(2), 2)]);
+    assert_eq!(import.state.apply(|s| s.clone()), State::new(3, None));
+}
+
+#[tokio::test]
+async fn import_retries_failed_headers_on_another_peer() {
+    let requests = Requests::new(vec![]);
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_peers_with_height()
+        .returning(|_| Ok(vec![peer(1), peer(2)]));
+    p2p.expect_get_sealed_block_headers().returning({
+        let requests = requests.clone();
+        move |r| {
+            requests.apply(|requests| requests.push((r.peer_id.clone(), r.data.start)));
+            if r.peer_id == peer(1) {
+                Err(anyhow::anyhow!("Some network error"))
+            } else {
+                Ok(Some(empty_headers(r)))
+            }
+        }
+    });
+    p2p.expect_get_transactions()
+        .returning(|ids| Ok(Some(empty_transactions(ids))));
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+
+    let import =
+        import(State::new(None, 3), params(Duration::from_secs(10)), p2p, 4).await;
+
+    let requests = requests.apply(|r| r.clone());
+    assert_eq!(
+        requests,
+        vec![(peer(1), 0), (peer(2), 0), (peer(2), 2)],
+        "The failing peer is only asked once"
+    );
+    assert_eq!(import.state.apply(|s| s.clone()), State::new(3, None));
+    let stats = import
+        .scheduler
+        .apply(|s| s.stats(&peer(1)).cloned().unwrap());
+    assert_eq!(stats.failures, 1);
+    assert_eq!(stats.in_flight, 0);
+}
+
+#[tokio::test]
+async fn import_retries_missing_transactions_on_another_peer() {
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_peers_with_height()
+        .returning(|_| Ok(vec![peer(1), peer(2)]));
+    p2p.expect_get_sealed_block_headers()
+        .returning(|r| Ok(Some(empty_headers(r))));
+    let transaction_requests = Requests::new(vec![]);
+    p2p.expect_get_transactions().returning({
+        let transaction_requests = transaction_requests.clone();
+        move |ids| {
+            transaction_requests
+                .apply(|requests| requests.push((ids.peer_id.clone(), 0)));
+            if ids.peer_id == peer(1) {
+                Ok(None)
+            } else {
+                Ok(Some(empty_transactions(ids)))
+            }
+        }
+    });
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+    let mut params = params(Duration::from_secs(10));
+    params.header_batch_size = 4;
+
+    let import = import(State::new(None, 3), params, p2p, 4).await;
+
+    let requests = transaction_requests.apply(|r| r.clone());
+    assert_eq!(requests, vec![(peer(1), 0), (peer(2), 0)]);
+    assert_eq!(import.state.apply(|s| s.clone()), State::new(3, None));
+}
+
+#[tokio::test]
+async fn import_times_out_slow_peer() {
+    let requests = Requests::new(vec![]);
+    let p2p = SlowPeerToPeerPort {
+        mock: p2p_with_peers(vec![peer(1), peer(2)], requests.clone()),
+        slow_peer: peer(1),
+        delay: Duration::from_secs(10),
+    };
+    let mut params = params(Duration::from_millis(100));
+    params.header_batch_size = 4;
+
+    let import = import(State::new(None, 3), params, p2p, 4).await;
+
+    // The request to the slow peer is dropped before it reaches the mock.
+    let requests = requests.apply(|r| r.clone());
+    assert_eq!(requests, vec![(peer(2), 0)]);
+    assert_eq!(import.state.apply(|s| s.clone()), State::new(3, None));
+    let stats = import
+        .scheduler
+        .apply(|s| s.stats(&peer(1)).cloned().unwrap());
+    assert_eq!(stats.failures, 1);
+    assert_eq!(stats.in_flight, 0);
+}
+
+#[tokio::test]
+async fn import_fails_when_no_peer_has_the_headers() {
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_peers_with_height()
+        .returning(|_| Ok(vec![peer(1), peer(2)]));
+    p2p.expect_get_sealed_block_headers()
+        .times(2)
+        .returning(|_| Ok(None));
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+    let mut params = params(Duration::from_secs(10));
+    params.header_batch_size = 4;
+
+    let import = import(State::new(None, 3), params, p2p, 0).await;
+
+    assert_eq!(import.state.apply(|s| s.clone()), State::new(None, None));
+}
+
+#[test]
+fn scheduler_prefers_peers_without_failures() {
+    let mut scheduler = PeerScheduler::default();
+    let candidates = [peer(1), peer(2)];
+    let excluded = HashSet::new();
+
+    let selected = scheduler.select(&candidates, &excluded).unwrap();
+    assert_eq!(selected, peer(1));
+    scheduler.fail(&selected);
+
+    assert_eq!(scheduler.select(&candidates, &excluded), Some(peer(2)));
+}
+
+#[test]
+fn scheduler_spreads_requests_in_progress() {
+    let mut scheduler = PeerScheduler::default();
+    let candidates = [peer(1), peer(2)];
+    let excluded = HashSet::new();
+
+    let selected: Vec<_> = (0..4)
+        .map(|_| scheduler.select(&candidates, &excluded).unwrap())
+        .collect();
+
+    assert_eq!(selected, vec![peer(1), peer(2), peer(1), peer(2)]);
+}
+
+#[test]
+fn scheduler_prefers_faster_peers() {
+    let mut scheduler = PeerScheduler::default();
+    let candidates = [peer(1), peer(2)];
+    let excluded = HashSet::new();
+    for (peer_id, latency) in [(peer(1), 1000), (peer(2), 500)] {
+        let selected = scheduler
+            .select(std::slice::from_ref(&peer_id), &excluded)
+            .unwrap();
+        scheduler.complete(&selected, Duration::from_millis(latency), 10);
+    }
+
+    assert_eq!(scheduler.select(&candidates, &excluded), Some(peer(2)));
+    let stats = scheduler.stats(&peer(2)).unwrap();
+    assert_eq!(stats.latency, Some(Duration::from_millis(500)));
+    assert_eq!(stats.throughput, Some(20.0));
+}
+
+#[test]
+fn scheduler_skips_excluded_peers() {
+    let mut scheduler = PeerScheduler::default();
+    let candidates = [peer(1), peer(2)];
+    let excluded = candidates.iter().cloned().collect();
+
+    assert_eq!(scheduler.select(&candidates, &excluded), None);
+}
+
+#[test]
+fn peer_request_is_cancelled_on_drop() {
+    let scheduler = SharedMutex::new(PeerScheduler::default());
+    let candidates = [peer(1)];
+
+    let request = PeerRequest::select(&scheduler, &candidates, &HashSet::new()).unwrap();
+    let in_flight = scheduler.apply(|s| s.stats(&peer(1)).unwrap().in_flight);
+    assert_eq!(in_flight, 1);
+    drop(request);
+
+    let stats = scheduler.apply(|s| s.stats(&peer(1)).cloned().unwrap());
+    assert_eq!(stats, PeerStats::default());
+}
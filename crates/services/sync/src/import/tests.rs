@@ -1,6 +1,10 @@
-use fuel_core_types::blockchain::{
-    consensus::Consensus,
-    header::BlockHeader,
+use fuel_core_types::{
+    blockchain::{
+        consensus::Consensus,
+        header::BlockHeader,
+        primitives::BlockId,
+    },
+    tai64::Tai64,
 };
 
 use crate::ports::{
@@ -66,7 +70,9 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(1)
             .returning(|_| Ok(None));
         Mocks{
@@ -81,12 +87,14 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok((*h != 5).then(|| empty_header(h))));
+            .returning(|r| Ok((r.data.start != 5).then(|| empty_headers(r))));
         p2p.expect_get_transactions()
             .times(1)
-            .returning(|_| Ok(Some(vec![])));
+            .returning(|ids| Ok(Some(empty_transactions(ids))));
         Mocks{
             p2p,
             consensus_port: DefaultMocks::times([1]),
@@ -99,9 +107,11 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(1)
-            .returning(|h| Ok((*h != 4).then(|| empty_header(h))));
+            .returning(|r| Ok((r.data.start != 4).then(|| empty_headers(r))));
         Mocks{
             p2p,
             consensus_port: DefaultMocks::times([0]),
@@ -114,9 +124,11 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         p2p.expect_get_transactions()
             .times(1)
             .returning(|_| Ok(None));
@@ -132,16 +144,18 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         let mut count = 0;
         p2p.expect_get_transactions()
             .times(1)
-            .returning(move|_| {
+            .returning(move |ids| {
                 count += 1;
                 if count > 1 {
-                    Ok(Some(vec![]))
+                    Ok(Some(empty_transactions(ids)))
                 } else {
                     Ok(None)
                 }
@@ -158,18 +172,20 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         let mut count = 0;
         p2p.expect_get_transactions()
             .times(2)
-            .returning(move|_| {
+            .returning(move |ids| {
                 count += 1;
                 if count > 1 {
                     Ok(None)
                 } else {
-                    Ok(Some(vec![]))
+                    Ok(Some(empty_transactions(ids)))
                 }
             });
         Mocks{
@@ -184,7 +200,9 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(1)
             .returning(|_| Err(anyhow::anyhow!("Some network error")));
         Mocks{
@@ -199,12 +217,14 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(1)
-            .returning(|h| if *h == 4 {
+            .returning(|r| if r.data.start == 4 {
                 Err(anyhow::anyhow!("Some network error"))
             } else {
-                Ok(Some(empty_header(h)))
+                Ok(Some(empty_headers(r)))
             });
         Mocks{
             p2p,
@@ -218,16 +238,18 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| if *h == 5 {
+            .returning(|r| if r.data.start == 5 {
                 Err(anyhow::anyhow!("Some network error"))
             } else {
-                Ok(Some(empty_header(h)))
+                Ok(Some(empty_headers(r)))
             });
         p2p.expect_get_transactions()
             .times(1)
-            .returning(|_| Ok(Some(vec![])));
+            .returning(|ids| Ok(Some(empty_transactions(ids))));
         Mocks{
             p2p,
             consensus_port: DefaultMocks::times([1]),
@@ -240,9 +262,11 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         p2p.expect_get_transactions()
             .times(1)
             .returning(|_| Err(anyhow::anyhow!("Some network error")));
@@ -258,16 +282,18 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         let mut count = 0;
         p2p.expect_get_transactions()
             .times(1)
-            .returning(move|_| {
+            .returning(move |ids| {
                 count += 1;
                 if count > 1 {
-                    Ok(Some(vec![]))
+                    Ok(Some(empty_transactions(ids)))
                 } else {
                     Err(anyhow::anyhow!("Some network error"))
                 }
@@ -284,18 +310,20 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         let mut count = 0;
         p2p.expect_get_transactions()
             .times(2)
-            .returning(move|_| {
+            .returning(move |ids| {
                 count += 1;
                 if count > 1 {
                     Err(anyhow::anyhow!("Some network error"))
                 } else {
-                    Ok(Some(vec![]))
+                    Ok(Some(empty_transactions(ids)))
                 }
             });
         Mocks{
@@ -433,15 +461,17 @@ async fn test_import(state: State, mocks: Mocks) -> (State, bool) {
         let s = SharedMutex::new(State::new(3, 5));
         let state = s.clone();
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
             .times(3)
-            .returning(move |h| {
+            .returning(move |r| {
                 state.apply(|s| s.observe(6));
-                Ok(Some(empty_header(h)))
+                Ok(Some(empty_headers(r)))
             });
         p2p.expect_get_transactions()
             .times(3)
-            .returning(move|_| Ok(Some(vec![])));
+            .returning(|ids| Ok(Some(empty_transactions(ids))));
         let c = DefaultMocks::times([2]);
         (s, c, Mocks{
             consensus_port: DefaultMocks::times([3]),
@@ -458,10 +488,377 @@ async fn test_import_loop(
     test_import_inner(state, mocks, Some(count)).await
 }
 
+#[test_case(
+    MockConsensusPort::times([2]),
+    DefaultMocks::times([2])
+    => vec![PeerReportReason::SuccessfulBlockImport; 2] ; "successful import"
+)]
+#[test_case(
+    {
+        let mut consensus_port = MockConsensusPort::default();
+        consensus_port.expect_check_sealed_header()
+            .times(1)
+            .returning(|_| Ok(false));
+        consensus_port
+    },
+    DefaultMocks::times([0])
+    => vec![PeerReportReason::InvalidHeader] ; "consensus check fails"
+)]
+#[test_case(
+    MockConsensusPort::times([1]),
+    {
+        let mut executor = MockBlockImporterPort::default();
+        executor
+            .expect_execute_and_commit()
+            .times(1)
+            .returning(|_| {
+                Err(anyhow::anyhow!("Some execution error").context(InvalidBlock))
+            });
+        executor
+    }
+    => vec![PeerReportReason::InvalidBlock] ; "execution fails"
+)]
+#[test_case(
+    MockConsensusPort::times([1]),
+    {
+        let mut executor = MockBlockImporterPort::default();
+        executor
+            .expect_execute_and_commit()
+            .times(1)
+            .returning(|_| Err(anyhow::anyhow!("Some storage error")));
+        executor
+    }
+    => Vec::<PeerReportReason>::new() ; "commit fails because of the node"
+)]
+#[tokio::test]
+async fn test_import_reports_peers(
+    consensus_port: MockConsensusPort,
+    executor: MockBlockImporterPort,
+) -> Vec<PeerReportReason> {
+    let reports = Arc::new(std::sync::Mutex::new(vec![]));
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .returning(|r| Ok(Some(empty_headers(r))));
+    p2p.expect_get_transactions()
+        .returning(|ids| Ok(Some(empty_transactions(ids))));
+    p2p.expect_get_peers_with_height()
+        .returning(|_| Ok(vec![PeerId::from(vec![])]));
+    p2p.expect_report_peer().returning({
+        let reports = reports.clone();
+        move |_, report| {
+            reports.lock().unwrap().push(report);
+            Ok(())
+        }
+    });
+    let state = SharedMutex::new(State::new(3, 5));
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+
+    test_import_inner(state, mocks, None).await;
+
+    let reports = reports.lock().unwrap().clone();
+    reports
+}
+
+#[test_case(
+    State::new(None, 9),
+    {
+        let mut p2p = MockPeerToPeerPort::default();
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
+            .times(2)
+            .returning(|r| Ok(Some(empty_headers(r))));
+        p2p.expect_get_transactions()
+            .times(2)
+            .returning(|ids| Ok(Some(empty_transactions(ids))));
+        Mocks{
+            p2p,
+            consensus_port: DefaultMocks::times([10]),
+            executor: DefaultMocks::times([10])
+        }
+    }
+    => (State::new(9, None), true) ; "requests 10 blocks in 2 batches"
+)]
+#[test_case(
+    State::new(None, 9),
+    {
+        let mut p2p = MockPeerToPeerPort::default();
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
+            .times(6)
+            .returning(|r| Ok(Some(empty_headers(r).into_iter().take(2).collect())));
+        // The transactions are requested for each received part of the batch.
+        p2p.expect_get_transactions()
+            .times(6)
+            .returning(|ids| Ok(Some(empty_transactions(ids))));
+        Mocks{
+            p2p,
+            consensus_port: DefaultMocks::times([10]),
+            executor: DefaultMocks::times([10])
+        }
+    }
+    => (State::new(9, None), true) ; "requests the rest of the headers after partial responses"
+)]
+#[test_case(
+    State::new(None, 9),
+    {
+        let mut p2p = MockPeerToPeerPort::default();
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
+            .times(2)
+            .returning(|r| Ok(Some(empty_headers(r))));
+        p2p.expect_get_transactions()
+            .times(4)
+            .returning(|ids| Ok(Some(vec![vec![]; ids.data.len().min(3)])));
+        Mocks{
+            p2p,
+            consensus_port: DefaultMocks::times([10]),
+            executor: DefaultMocks::times([10])
+        }
+    }
+    => (State::new(9, None), true) ; "requests the rest of the transactions after partial responses"
+)]
+#[test_case(
+    State::new(None, 9),
+    {
+        let mut p2p = MockPeerToPeerPort::default();
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height().returning(|_| Ok(vec![PeerId::from(vec![])]));
+        p2p.expect_get_sealed_block_headers()
+            .times(2)
+            .returning(|r| {
+                let start = r.data.start;
+                let mut headers = empty_headers(r);
+                if start == 5 {
+                    headers.swap(1, 2);
+                }
+                Ok(Some(headers))
+            });
+        p2p.expect_get_transactions()
+            .times(1)
+            .returning(|ids| Ok(Some(empty_transactions(ids))));
+        Mocks{
+            p2p,
+            consensus_port: DefaultMocks::times([5]),
+            executor: DefaultMocks::times([5])
+        }
+    }
+    => (State::new(4, None), true) ; "stops on the batch with unexpected heights"
+)]
+#[tokio::test]
+async fn test_import_batches(state: State, mocks: Mocks) -> (State, bool) {
+    let state = SharedMutex::new(state);
+    let params = Config {
+        max_get_header_requests: 10,
+        max_get_txns_requests: 10,
+        header_batch_size: 5,
+        peer_request_timeout: Duration::from_secs(10),
+        headers_only: false,
+        checkpoint: None,
+    };
+    test_import_inner_with_params(state, mocks, None, params).await
+}
+
+#[test_case(
+    State::new(None, 9),
+    {
+        let mut executor = MockBlockImporterPort::default();
+        executor.expect_commit_header()
+            .times(10)
+            .returning(|_| Ok(()));
+        Mocks{
+            consensus_port: DefaultMocks::times([10, 0]),
+            p2p: DefaultMocks::times([2, 0]),
+            executor,
+        }
+    }
+    => (State::new(9, None), true) ; "commits the headers without the transactions"
+)]
+#[test_case(
+    State::new(None, 9),
+    {
+        let mut consensus_port = MockConsensusPort::default();
+        consensus_port.expect_check_sealed_header()
+            .times(8)
+            .returning(|h| Ok(**h.entity.height() != 7));
+        let mut executor = MockBlockImporterPort::default();
+        executor.expect_commit_header()
+            .times(7)
+            .returning(|_| Ok(()));
+        Mocks{
+            consensus_port,
+            p2p: DefaultMocks::times([2, 0]),
+            executor,
+        }
+    }
+    => (State::new(6, None), true) ; "stops on the header with invalid consensus"
+)]
+#[test_case(
+    State::new(None, 9),
+    {
+        let mut executor = MockBlockImporterPort::default();
+        executor.expect_commit_header()
+            .times(4)
+            .returning(|h| {
+                if **h.entity.height() == 3 {
+                    Err(anyhow::anyhow!("Previous root mismatch"))
+                } else {
+                    Ok(())
+                }
+            });
+        // The second batch may be checked before the failure.
+        let mut consensus_port = MockConsensusPort::default();
+        consensus_port.expect_check_sealed_header()
+            .returning(|_| Ok(true));
+        Mocks{
+            consensus_port,
+            p2p: DefaultMocks::times([2, 0]),
+            executor,
+        }
+    }
+    => (State::new(2, None), false) ; "stops on the header that failed to commit"
+)]
+#[tokio::test]
+async fn test_import_headers_only(state: State, mocks: Mocks) -> (State, bool) {
+    let state = SharedMutex::new(state);
+    let params = Config {
+        max_get_header_requests: 10,
+        max_get_txns_requests: 10,
+        header_batch_size: 5,
+        peer_request_timeout: Duration::from_secs(10),
+        headers_only: true,
+        checkpoint: None,
+    };
+    test_import_inner_with_params(state, mocks, None, params).await
+}
+
+#[test_case(
+    State::new(None, 9),
+    checkpoint(4),
+    {
+        let mut executor = MockBlockImporterPort::default();
+        executor.expect_commit_header()
+            .times(5)
+            .returning(|_| Ok(()));
+        executor.expect_execute_and_commit()
+            .times(5)
+            .returning(|_| Ok(()));
+        Mocks{
+            // The checkpoint is checked before the headers.
+            consensus_port: DefaultMocks::times([11, 5]),
+            p2p: DefaultMocks::times([3, 1]),
+            executor,
+        }
+    }
+    => (State::new(9, None), true) ; "commits the headers up to the checkpoint and executes the rest"
+)]
+#[test_case(
+    State::new(5, 9),
+    checkpoint(4),
+    Mocks{
+        consensus_port: DefaultMocks::times([4]),
+        p2p: DefaultMocks::times([1]),
+        executor: DefaultMocks::times([4]),
+    }
+    => (State::new(9, None), true) ; "executes the blocks after the checkpoint"
+)]
+#[test_case(
+    State::new(None, 9),
+    Checkpoint {
+        height: 4u32.into(),
+        block_id: BlockId::default(),
+    },
+    Mocks{
+        consensus_port: DefaultMocks::times([0]),
+        p2p: DefaultMocks::times([1, 0]),
+        executor: DefaultMocks::times([0]),
+    }
+    => (State::new(None, None), true) ; "stops when the peers have another checkpoint block"
+)]
+#[test_case(
+    State::new(None, 9),
+    checkpoint(4),
+    {
+        let mut p2p = MockPeerToPeerPort::default();
+        p2p.expect_get_sealed_block_headers()
+            .times(2)
+            .returning(|r| {
+                let start = r.data.start;
+                let mut headers = empty_headers(r);
+                // Only the batch has the forged block at the checkpoint height.
+                if start == 0 {
+                    headers[4].entity.consensus.time = Tai64(1);
+                    headers[4].entity.recalculate_metadata();
+                }
+                Ok(Some(headers))
+            });
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height()
+            .returning(|_| Ok(vec![PeerId::from(vec![])]));
+        let mut executor = MockBlockImporterPort::default();
+        executor.expect_commit_header()
+            .times(4)
+            .returning(|_| Ok(()));
+        Mocks{
+            consensus_port: DefaultMocks::times([5, 0]),
+            p2p,
+            executor,
+        }
+    }
+    => (State::new(3, None), true) ; "stops on the header at the checkpoint height with another id"
+)]
+#[tokio::test]
+async fn test_import_with_checkpoint(
+    state: State,
+    checkpoint: Checkpoint,
+    mocks: Mocks,
+) -> (State, bool) {
+    let state = SharedMutex::new(state);
+    let params = Config {
+        max_get_header_requests: 10,
+        max_get_txns_requests: 10,
+        header_batch_size: 5,
+        peer_request_timeout: Duration::from_secs(10),
+        headers_only: false,
+        checkpoint: Some(checkpoint),
+    };
+    test_import_inner_with_params(state, mocks, None, params).await
+}
+
+fn checkpoint(height: u32) -> Checkpoint {
+    Checkpoint {
+        height: height.into(),
+        block_id: empty_header(height.into()).entity.id(),
+    }
+}
+
 async fn test_import_inner(
     state: SharedMutex<State>,
     mocks: Mocks,
     count: Option<Count>,
+) -> (State, bool) {
+    let params = Config {
+        max_get_header_requests: 10,
+        max_get_txns_requests: 10,
+        header_batch_size: 1,
+        peer_request_timeout: Duration::from_secs(10),
+        headers_only: false,
+        checkpoint: None,
+    };
+    test_import_inner_with_params(state, mocks, count, params).await
+}
+
+async fn test_import_inner_with_params(
+    state: SharedMutex<State>,
+    mocks: Mocks,
+    count: Option<Count>,
+    params: Config,
 ) -> (State, bool) {
     let notify = Arc::new(Notify::new());
     let Mocks {
@@ -469,23 +866,12 @@ async fn test_import_inner(
         p2p,
         executor,
     } = mocks;
-    let params = Config {
-        max_get_header_requests: 10,
-        max_get_txns_requests: 10,
-    };
     let p2p = Arc::new(p2p);
 
     let executor = Arc::new(executor);
     let consensus = Arc::new(consensus_port);
 
-    let import = Import {
-        state,
-        notify,
-        params,
-        p2p,
-        executor,
-        consensus,
-    };
+    let import = Import::new(state, notify, params, p2p, executor, consensus);
     let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
     let mut watcher = shutdown.into();
     let r = match count {
@@ -577,12 +963,15 @@ impl DefaultMocks for MockPeerToPeerPort {
         let mut p2p = MockPeerToPeerPort::default();
         let mut t = t.into_iter().cycle();
 
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(t.next().unwrap())
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         p2p.expect_get_transactions()
             .times(t.next().unwrap())
-            .returning(|_| Ok(Some(vec![])));
+            .returning(|ids| Ok(Some(empty_transactions(ids))));
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p.expect_get_peers_with_height()
+            .returning(|_| Ok(vec![PeerId::from(vec![])]));
         p2p
     }
 }
@@ -600,7 +989,7 @@ impl DefaultMocks for MockBlockImporterPort {
     }
 }
 
-pub(crate) fn empty_header(h: BlockHeight) -> SourcePeer<SealedBlockHeader> {
+pub(crate) fn empty_header(h: BlockHeight) -> SealedBlockHeader {
     let mut header = BlockHeader::default();
     header.consensus.height = h;
     let transaction_tree =
@@ -608,12 +997,18 @@ pub(crate) fn empty_header(h: BlockHeight) -> SourcePeer<SealedBlockHeader> {
     header.application.generated.transactions_root = transaction_tree.root().into();
 
     let consensus = Consensus::default();
-    let sealed = Sealed {
+    Sealed {
         entity: header,
         consensus,
-    };
-    SourcePeer {
-        peer_id: vec![].into(),
-        data: sealed,
     }
 }
+
+pub(crate) fn empty_headers(range: SourcePeer<Range<u32>>) -> Vec<SealedBlockHeader> {
+    range.data.map(|h| empty_header(h.into())).collect()
+}
+
+pub(crate) fn empty_transactions(
+    block_ids: SourcePeer<Vec<BlockId>>,
+) -> Vec<Vec<Transaction>> {
+    vec![vec![]; block_ids.data.len()]
+}
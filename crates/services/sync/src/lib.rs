@@ -10,7 +10,10 @@ mod state;
 pub mod sync;
 mod tracing_helpers;
 
-pub use import::Config;
+pub use import::{
+    Checkpoint,
+    Config,
+};
 
 #[cfg(test)]
 fuel_core_trace::enable_tracing!();
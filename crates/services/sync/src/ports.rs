@@ -12,7 +12,15 @@ use fuel_core_types::{
         SealedBlockHeader,
     },
     fuel_tx::Transaction,
-    services::p2p::SourcePeer,
+    services::p2p::{
+        PeerId,
+        PeerReportReason,
+        SourcePeer,
+    },
+};
+use std::{
+    fmt,
+    ops::Range,
 };
 
 #[cfg_attr(test, mockall::automock)]
@@ -22,21 +30,33 @@ pub trait PeerToPeerPort {
     /// Stream of newly observed block heights.
     fn height_stream(&self) -> BoxStream<BlockHeight>;
 
-    /// Request sealed block header from the network
-    /// at the given height.
-    ///
-    /// Returns the source peer this header was received from.
-    async fn get_sealed_block_header(
+    /// Returns the connected peers that reported to have
+    /// the block at the given height.
+    async fn get_peers_with_height(
         &self,
         height: BlockHeight,
-    ) -> anyhow::Result<Option<SourcePeer<SealedBlockHeader>>>;
+    ) -> anyhow::Result<Vec<PeerId>>;
 
-    /// Request transactions from the network for the given block
+    /// Request sealed block headers from the given peer
+    /// for the given range of heights.
+    ///
+    /// The peer may return only the headers from the beginning of the range.
+    async fn get_sealed_block_headers(
+        &self,
+        block_height_range: SourcePeer<Range<u32>>,
+    ) -> anyhow::Result<Option<Vec<SealedBlockHeader>>>;
+
+    /// Request transactions from the network for the given blocks
     /// and source peer.
+    ///
+    /// The peer may return only the transactions of the first blocks.
     async fn get_transactions(
         &self,
-        block_id: SourcePeer<BlockId>,
-    ) -> anyhow::Result<Option<Vec<Transaction>>>;
+        block_ids: SourcePeer<Vec<BlockId>>,
+    ) -> anyhow::Result<Option<Vec<Vec<Transaction>>>>;
+
+    /// Report the behaviour of the peer to adjust its reputation.
+    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()>;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -58,5 +78,24 @@ pub trait BlockImporterPort {
 
     /// Execute the given sealed block
     /// and commit it to the database.
+    ///
+    /// The error caused by the invalid block has the [`InvalidBlock`] context.
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+
+    /// Commit the given sealed block header to the database
+    /// without the execution of the block.
+    ///
+    /// The error caused by the invalid header has the [`InvalidBlock`] context.
+    async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()>;
+}
+
+/// The context of the [`BlockImporterPort`] error caused by the block received from
+/// the peer. Only such errors are reported to the peer, unlike the errors of the node.
+#[derive(Debug, Clone, Copy)]
+pub struct InvalidBlock;
+
+impl fmt::Display for InvalidBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The block received from the peer is invalid")
+    }
 }
@@ -2,13 +2,18 @@ use fuel_core_services::{
     stream::IntoBoxStream,
     Service,
 };
+use fuel_core_types::services::p2p::PeerId;
 use futures::{
     stream,
     StreamExt,
 };
+use std::time::Duration;
 
 use crate::{
-    import::empty_header,
+    import::{
+        empty_headers,
+        empty_transactions,
+    },
     ports::{
         MockBlockImporterPort,
         MockConsensusPort,
@@ -33,10 +38,13 @@ async fn test_new_service() {
         })
         .into_boxed()
     });
-    p2p.expect_get_sealed_block_header()
-        .returning(|h| Ok(Some(empty_header(h))));
+    p2p.expect_get_sealed_block_headers()
+        .returning(|r| Ok(Some(empty_headers(r))));
     p2p.expect_get_transactions()
-        .returning(|_| Ok(Some(vec![])));
+        .returning(|ids| Ok(Some(empty_transactions(ids))));
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+    p2p.expect_get_peers_with_height()
+        .returning(|_| Ok(vec![PeerId::from(vec![])]));
     let mut importer = MockBlockImporterPort::default();
     importer
         .expect_committed_height_stream()
@@ -54,6 +62,10 @@ async fn test_new_service() {
     let params = Config {
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        header_batch_size: 10,
+        peer_request_timeout: Duration::from_secs(10),
+        headers_only: false,
+        checkpoint: None,
     };
     let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
 
@@ -1,6 +1,22 @@
 use fuel_core_chain_config::ChainConfig;
+use fuel_core_types::fuel_tx::Address;
 use std::time::Duration;
 
+/// The strategy of selecting transactions from the pool into the produced block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Prefers transactions with the highest gas price.
+    #[default]
+    GasPrice,
+    /// Includes transactions in the order of their arrival into the pool.
+    ByTime,
+    /// Maximizes the total fee of the block.
+    MaxFee,
+    /// Includes transactions from the listed addresses first, the rest are
+    /// selected by gas price.
+    PriorityAddresses(Vec<Address>),
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     /// Maximum number of transactions inside the pool
@@ -17,6 +33,11 @@ pub struct Config {
     pub metrics: bool,
     /// Transaction TTL
     pub transaction_ttl: Duration,
+    /// The strategy of selecting transactions for the block production
+    pub selection_strategy: SelectionStrategy,
+    /// The minimal increase of the gas price in percents required to replace
+    /// the transaction in the pool that spends the same coins or messages
+    pub replacement_price_bump: u64,
 }
 
 impl Default for Config {
@@ -26,12 +47,15 @@ impl Default for Config {
         let metrics = false;
         // 5 minute TTL
         let transaction_ttl = Duration::from_secs(60 * 5);
+        let replacement_price_bump = 10;
         Self::new(
             ChainConfig::default(),
             min_gas_price,
             utxo_validation,
             metrics,
             transaction_ttl,
+            SelectionStrategy::default(),
+            replacement_price_bump,
         )
     }
 }
@@ -43,6 +67,8 @@ impl Config {
         utxo_validation: bool,
         metrics: bool,
         transaction_ttl: Duration,
+        selection_strategy: SelectionStrategy,
+        replacement_price_bump: u64,
     ) -> Self {
         // # Dev-note: If you add a new field, be sure that this field is propagated correctly
         //  in all places where `new` is used.
@@ -54,6 +80,8 @@ impl Config {
             chain_config,
             metrics,
             transaction_ttl,
+            selection_strategy,
+            replacement_price_bump,
         }
     }
 }
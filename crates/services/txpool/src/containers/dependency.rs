@@ -51,6 +51,8 @@ pub struct Dependency {
     max_depth: usize,
     /// utxo-validation feature flag
     utxo_validation: bool,
+    /// The minimal increase of the gas price in percents to replace the transaction.
+    replacement_price_bump: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -96,16 +98,30 @@ pub struct MessageState {
 }
 
 impl Dependency {
-    pub fn new(max_depth: usize, utxo_validation: bool) -> Self {
+    pub fn new(
+        max_depth: usize,
+        utxo_validation: bool,
+        replacement_price_bump: u64,
+    ) -> Self {
         Self {
             coins: HashMap::new(),
             contracts: HashMap::new(),
             messages: HashMap::new(),
             max_depth,
             utxo_validation,
+            replacement_price_bump,
         }
     }
 
+    /// Returns `true` if the transaction with the `new` gas price can replace the
+    /// transaction with the `original` gas price that spends the same input(replace-by-fee).
+    fn can_replace(&self, original: GasPrice, new: GasPrice) -> bool {
+        let original = u128::from(original);
+        let bump = (original * u128::from(self.replacement_price_bump) + 99) / 100;
+        let new = u128::from(new);
+        new > original && new >= original + bump
+    }
+
     /// find all dependent Transactions that are inside txpool.
     /// Does not check db. They can be sorted by gasPrice to get order of dependency
     pub(crate) fn find_dependent(
@@ -375,8 +391,8 @@ impl Dependency {
                             let txpool_tx = txs
                                 .get(spend_by)
                                 .expect("Tx should be always present in txpool");
-                            // compare if tx has better price
-                            if txpool_tx.price() > tx.price() {
+                            // compare if tx has enough better price to replace it
+                            if !self.can_replace(txpool_tx.price(), tx.price()) {
                                 return Err(Error::NotInsertedCollision(
                                     *spend_by, *utxo_id,
                                 )
@@ -456,7 +472,7 @@ impl Dependency {
 
                     if let Some(state) = self.messages.get(nonce) {
                         // some other is already attempting to spend this message, compare gas price
-                        if state.gas_price >= tx.price() {
+                        if !self.can_replace(state.gas_price, tx.price()) {
                             return Err(Error::NotInsertedCollisionMessageId(
                                 state.spent_by,
                                 *nonce,
@@ -10,7 +10,7 @@ pub mod config;
 mod containers;
 pub mod ports;
 pub mod service;
-mod transaction_selector;
+pub mod transaction_selector;
 pub mod txpool;
 pub mod types;
 
@@ -19,7 +19,10 @@ pub mod mock_db;
 #[cfg(any(test, feature = "test-helpers"))]
 pub use mock_db::MockDb;
 
-pub use config::Config;
+pub use config::{
+    Config,
+    SelectionStrategy,
+};
 pub use fuel_core_types::services::txpool::Error;
 pub use service::{
     new_service,
@@ -12,6 +12,7 @@ use fuel_core_types::{
     fuel_tx::{
         Contract,
         ContractId,
+        Transaction,
         UtxoId,
     },
     fuel_types::Nonce,
@@ -33,6 +34,7 @@ pub struct Data {
     pub contracts: HashMap<ContractId, Contract>,
     pub messages: HashMap<Nonce, Message>,
     pub spent_messages: HashSet<Nonce>,
+    pub pending_transactions: Vec<Transaction>,
 }
 
 #[derive(Clone, Default)]
@@ -93,4 +95,18 @@ impl TxPoolDb for MockDb {
     fn current_block_height(&self) -> StorageResult<BlockHeight> {
         Ok(Default::default())
     }
+
+    fn save_pending_transactions(&self, txs: Vec<Transaction>) -> StorageResult<()> {
+        self.data.lock().unwrap().pending_transactions = txs;
+        Ok(())
+    }
+
+    fn pending_transactions(&self) -> StorageResult<Vec<Transaction>> {
+        Ok(self.data.lock().unwrap().pending_transactions.clone())
+    }
+
+    fn clear_pending_transactions(&self) -> StorageResult<()> {
+        self.data.lock().unwrap().pending_transactions.clear();
+        Ok(())
+    }
 }
@@ -57,4 +57,14 @@ pub trait TxPoolDb: Send + Sync {
     fn is_message_spent(&self, message_id: &Nonce) -> StorageResult<bool>;
 
     fn current_block_height(&self) -> StorageResult<BlockHeight>;
+
+    /// Saves the pending transactions of the pool to restore them after the restart.
+    /// The previously saved transactions are replaced.
+    fn save_pending_transactions(&self, txs: Vec<Transaction>) -> StorageResult<()>;
+
+    /// Returns the saved pending transactions in the order of saving.
+    fn pending_transactions(&self) -> StorageResult<Vec<Transaction>>;
+
+    /// Removes the saved pending transactions.
+    fn clear_pending_transactions(&self) -> StorageResult<()>;
 }
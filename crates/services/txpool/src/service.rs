@@ -4,7 +4,7 @@ use crate::{
         PeerToPeer,
         TxPoolDb,
     },
-    transaction_selector::select_transactions,
+    transaction_selector::TransactionSelector,
     Config,
     Error as TxPoolError,
     TxInfo,
@@ -95,6 +95,7 @@ impl TxStatusChange {
 pub struct SharedState<P2P, DB> {
     tx_status_sender: TxStatusChange,
     txpool: Arc<ParkingMutex<TxPool<DB>>>,
+    selector: Arc<dyn TransactionSelector>,
     p2p: Arc<P2P>,
 }
 
@@ -103,6 +104,7 @@ impl<P2P, DB> Clone for SharedState<P2P, DB> {
         Self {
             tx_status_sender: self.tx_status_sender.clone(),
             txpool: self.txpool.clone(),
+            selector: self.selector.clone(),
             p2p: self.p2p.clone(),
         }
     }
@@ -132,6 +134,18 @@ where
 
     async fn into_task(mut self, _: &StateWatcher) -> anyhow::Result<Self::Task> {
         self.ttl_timer.reset();
+        let restored = self
+            .shared
+            .txpool
+            .lock()
+            .restore(&self.shared.tx_status_sender)?;
+        if !restored.is_empty() {
+            let inserted = restored.iter().filter(|result| result.is_ok()).count();
+            tracing::info!(
+                "Restored {inserted} pending transactions, discarded {} invalid ones",
+                restored.len() - inserted
+            );
+        }
         Ok(self)
     }
 }
@@ -208,10 +222,10 @@ where
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
-        // and we don't spawn any sub-tasks that we need to finish or await.
-        // Maybe we will save and load the previous list of transactions in the future to
-        // avoid losing them.
+        // Pending transactions are saved to be restored on the next start,
+        // so they are not lost because of the restart.
+        let saved = self.shared.txpool.lock().persist()?;
+        tracing::info!("Saved {saved} pending transactions");
         Ok(())
     }
 }
@@ -251,7 +265,7 @@ where
     pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
         let mut guard = self.txpool.lock();
         let txs = guard.includable();
-        let sorted_txs = select_transactions(txs, max_gas);
+        let sorted_txs = self.selector.select(txs, max_gas);
 
         for tx in sorted_txs.iter() {
             guard.remove_committed_tx(&tx.id());
@@ -353,6 +367,7 @@ where
     let committed_block_stream = importer.block_events();
     let mut ttl_timer = tokio::time::interval(config.transaction_ttl);
     ttl_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let selector = config.selection_strategy.selector();
     let txpool = Arc::new(ParkingMutex::new(TxPool::new(config, db)));
     let task = Task {
         gossiped_tx_stream,
@@ -360,6 +375,7 @@ where
         shared: SharedState {
             tx_status_sender: TxStatusChange::new(100),
             txpool,
+            selector,
             p2p,
         },
         ttl_timer,
@@ -1,11 +1,22 @@
 use super::*;
-use crate::service::test_helpers::{
-    TestContext,
-    TestContextBuilder,
+use crate::{
+    service::test_helpers::{
+        TestContext,
+        TestContextBuilder,
+    },
+    test_helpers::create_output_and_input,
 };
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
-    fuel_tx::UniqueIdentifier,
+    fuel_crypto::rand::{
+        rngs::StdRng,
+        SeedableRng,
+    },
+    fuel_tx::{
+        TransactionBuilder,
+        UniqueIdentifier,
+        UtxoId,
+    },
     services::txpool::Error as TxpoolError,
 };
 use std::time::Duration;
@@ -223,3 +234,112 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn replaced_tx_and_its_dependents_are_squeezed_out_as_replaced() {
+    let ctx = TestContextBuilder::new().build_and_start().await;
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let (_, gas_coin) = ctx.setup_coin();
+    let (output, unset_input) = create_output_and_input(&mut rng, 10);
+    let tx1 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(1000)
+            .add_input(gas_coin.clone())
+            .add_output(output)
+            .finalize_as_transaction(),
+    );
+    let tx2 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(9)
+            .gas_limit(1000)
+            .add_input(unset_input.into_input(UtxoId::new(tx1.id(), 0)))
+            .finalize_as_transaction(),
+    );
+    let tx3 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(20)
+            .gas_limit(1000)
+            .add_input(gas_coin)
+            .finalize_as_transaction(),
+    );
+    let service = ctx.service();
+
+    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()]);
+    assert!(out.iter().all(Result::is_ok), "Txs should be OK: {out:?}");
+
+    let mut subscribe_update = service.shared.tx_update_subscribe();
+    let out = service.shared.insert(vec![tx3.clone()]);
+    assert!(out[0].is_ok(), "Tx3 should be OK, got err: {out:?}");
+
+    for replaced in [&tx1, &tx2] {
+        let update = subscribe_update.try_recv().unwrap();
+        assert_eq!(*update.tx_id(), replaced.id());
+        assert_eq!(
+            update.into_squeezed_out_reason(),
+            Some(TxpoolError::Replaced(tx3.id()))
+        );
+    }
+    let update = subscribe_update.try_recv().unwrap();
+    assert_eq!(*update.tx_id(), tx3.id());
+    assert!(!update.was_squeezed_out());
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn pending_transactions_are_restored_after_restart() {
+    let mut builder = TestContextBuilder::new();
+    let tx1 = Arc::new(builder.setup_script_tx(10));
+    let (spent_coin, gas_coin) = builder.setup_coin();
+    let tx2 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(20)
+            .gas_limit(1000)
+            .add_input(gas_coin)
+            .finalize_as_transaction(),
+    );
+    let ctx = builder.build_and_start().await;
+    let service = ctx.service();
+
+    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()]);
+    assert!(out.iter().all(Result::is_ok), "Txs should be OK: {out:?}");
+    service.stop_and_await().await.unwrap();
+    assert_eq!(
+        ctx.mock_db()
+            .data
+            .lock()
+            .unwrap()
+            .pending_transactions
+            .len(),
+        2
+    );
+
+    // The coin of the `tx2` is spent while the node is stopped.
+    ctx.mock_db()
+        .data
+        .lock()
+        .unwrap()
+        .coins
+        .remove(&spent_coin.utxo_id);
+
+    let restarted = TestContextBuilder::new()
+        .with_db(ctx.mock_db().clone())
+        .build_and_start()
+        .await;
+    let service = restarted.service();
+
+    let out = service.shared.find(vec![tx1.id(), tx2.id()]);
+    assert!(out[0].is_some(), "Tx1 should be restored");
+    assert!(out[1].is_none(), "Tx2 should be discarded");
+    assert!(ctx
+        .mock_db()
+        .data
+        .lock()
+        .unwrap()
+        .pending_transactions
+        .is_empty());
+
+    service.stop_and_await().await.unwrap();
+}
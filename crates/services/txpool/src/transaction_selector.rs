@@ -1,44 +1,300 @@
+use crate::{
+    config::SelectionStrategy,
+    TxInfo,
+};
 use fuel_core_types::{
+    fuel_tx::{
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            contract::Contract,
+        },
+        Address,
+        Input,
+        Output,
+        TxId,
+    },
     fuel_types::Word,
     services::txpool::ArcPoolTx,
 };
-use std::cmp::Reverse;
+use std::{
+    cmp::Reverse,
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    sync::Arc,
+};
 
-// transaction selection could use a plugin based approach in the
-// future for block producers to customize block building (e.g. alternative priorities besides gas fees)
+/// Selects transactions from the pool for the next block. Block producers can
+/// customize block building by choosing the implementation via [`SelectionStrategy`].
+pub trait TransactionSelector: Send + Sync {
+    /// Returns transactions in the order of their inclusion into the block.
+    /// The total `max_gas` of the selected transactions doesn't exceed `max_gas`.
+    fn select(&self, includable_txs: Vec<TxInfo>, max_gas: Word) -> Vec<ArcPoolTx>;
+}
 
-pub fn select_transactions(
-    mut includable_txs: Vec<ArcPoolTx>,
-    max_gas: u64,
-) -> Vec<ArcPoolTx> {
-    // Select all txs that fit into the block, preferring ones with higher gas price.
-    //
-    // Future improvements to this algorithm may take into account the parallel nature of
-    // transactions to maximize throughput.
-    let mut used_block_space: Word = 0;
+impl SelectionStrategy {
+    pub fn selector(&self) -> Arc<dyn TransactionSelector> {
+        match self {
+            SelectionStrategy::GasPrice => Arc::new(ByGasPrice),
+            SelectionStrategy::ByTime => Arc::new(ByTime),
+            SelectionStrategy::MaxFee => Arc::new(MaxFee),
+            SelectionStrategy::PriorityAddresses(addresses) => {
+                Arc::new(PriorityAddresses::new(addresses.iter().copied()))
+            }
+        }
+    }
+}
+
+/// Selects all txs that fit into the block, preferring ones with higher gas price.
+///
+/// Future improvements to this algorithm may take into account the parallel nature of
+/// transactions to maximize throughput.
+pub struct ByGasPrice;
+
+impl TransactionSelector for ByGasPrice {
+    fn select(&self, includable_txs: Vec<TxInfo>, max_gas: Word) -> Vec<ArcPoolTx> {
+        fill_block(sorted_by_gas_price(includable_txs), max_gas)
+    }
+}
+
+/// Selects txs in the order of their arrival into the pool(FIFO).
+pub struct ByTime;
+
+impl TransactionSelector for ByTime {
+    fn select(&self, mut includable_txs: Vec<TxInfo>, max_gas: Word) -> Vec<ArcPoolTx> {
+        includable_txs.sort_by_key(|info| info.submitted_time());
+        fill_block(
+            includable_txs.into_iter().map(|info| info.tx().clone()),
+            max_gas,
+        )
+    }
+}
+
+/// Selects txs that maximize the total fee of the block.
+///
+/// It is a 0/1 knapsack problem where the weight is the `max_gas` of the transaction.
+/// The gas is rounded up to [`MaxFee::RESOLUTION`] units of the block to keep the
+/// complexity linear to the number of transactions. The space lost to the rounding
+/// is filled greedily, and the result is never worse than [`ByGasPrice`].
+pub struct MaxFee;
+
+impl MaxFee {
+    /// The number of units the block gas limit is split into.
+    pub const RESOLUTION: Word = 1024;
+
+    /// Returns the indexes of the transactions maximizing the total fee.
+    fn knapsack(txs: &[ArcPoolTx], max_gas: Word) -> HashSet<usize> {
+        let unit = max_gas / Self::RESOLUTION + 1;
+        let capacity = (max_gas / unit) as usize;
+
+        let mut best_fee = vec![0u128; capacity + 1];
+        let mut taken = vec![vec![false; capacity + 1]; txs.len()];
+        let mut weights = Vec::with_capacity(txs.len());
+        for (i, tx) in txs.iter().enumerate() {
+            let weight =
+                (tx.max_gas() / unit + Word::from(tx.max_gas() % unit != 0)) as usize;
+            weights.push(weight);
+            if weight > capacity {
+                continue
+            }
+            let fee = fee(tx);
+            for space in (weight..=capacity).rev() {
+                let candidate = best_fee[space - weight] + fee;
+                if candidate > best_fee[space] {
+                    best_fee[space] = candidate;
+                    taken[i][space] = true;
+                }
+            }
+        }
+
+        let mut space = capacity;
+        let mut selected = HashSet::new();
+        for i in (0..txs.len()).rev() {
+            if taken[i][space] {
+                selected.insert(i);
+                space -= weights[i];
+            }
+        }
+        selected
+    }
+}
+
+impl TransactionSelector for MaxFee {
+    fn select(&self, includable_txs: Vec<TxInfo>, max_gas: Word) -> Vec<ArcPoolTx> {
+        let txs: Vec<_> = sorted_by_gas_price(includable_txs).collect();
+        let greedy = fill_block(txs.iter().cloned(), max_gas);
+
+        let selected = Self::knapsack(&txs, max_gas);
+        let (chosen, rest): (Vec<_>, Vec<_>) = txs
+            .into_iter()
+            .enumerate()
+            .partition(|(i, _)| selected.contains(i));
+        let knapsack =
+            fill_block(chosen.into_iter().chain(rest).map(|(_, tx)| tx), max_gas);
+
+        if total_fee(&knapsack) >= total_fee(&greedy) {
+            knapsack
+        } else {
+            greedy
+        }
+    }
+}
+
+/// Selects txs from the priority addresses first. The txs are ordered by gas price
+/// within the priority and the regular groups.
+///
+/// The transaction belongs to the address if any of its coin inputs is owned by the
+/// address or any of its message inputs is sent to the address.
+pub struct PriorityAddresses {
+    addresses: HashSet<Address>,
+}
+
+impl PriorityAddresses {
+    pub fn new(addresses: impl IntoIterator<Item = Address>) -> Self {
+        Self {
+            addresses: addresses.into_iter().collect(),
+        }
+    }
+
+    fn is_prioritized(&self, tx: &ArcPoolTx) -> bool {
+        tx.inputs().iter().any(|input| {
+            input
+                .input_owner()
+                .or_else(|| input.recipient())
+                .map(|owner| self.addresses.contains(owner))
+                .unwrap_or(false)
+        })
+    }
+}
+
+impl TransactionSelector for PriorityAddresses {
+    fn select(&self, includable_txs: Vec<TxInfo>, max_gas: Word) -> Vec<ArcPoolTx> {
+        let (prioritized, regular): (Vec<_>, Vec<_>) =
+            sorted_by_gas_price(includable_txs).partition(|tx| self.is_prioritized(tx));
+        fill_block(prioritized.into_iter().chain(regular), max_gas)
+    }
+}
 
+fn sorted_by_gas_price(
+    mut includable_txs: Vec<TxInfo>,
+) -> impl Iterator<Item = ArcPoolTx> {
     // Sort transactions by gas price, highest first
-    includable_txs.sort_by_key(|a| Reverse(a.price()));
-
-    // Pick as many transactions as we can fit into the block (greedy)
-    includable_txs
-        .into_iter()
-        .filter(|tx| {
-            let tx_block_space = tx.max_gas();
-            if let Some(new_used_space) = used_block_space.checked_add(tx_block_space) {
-                if new_used_space <= max_gas {
-                    used_block_space = new_used_space;
-                    true
-                } else {
-                    false
+    includable_txs.sort_by_key(|info| Reverse(info.price()));
+    includable_txs.into_iter().map(|info| info.tx().clone())
+}
+
+/// Picks as many transactions as we can fit into the block in the given order (greedy).
+///
+/// A transaction spending the outputs of other transactions from the list is deferred
+/// until all of them are picked, so parents always precede their children. If any
+/// parent doesn't fit into the block, its children are skipped too.
+fn fill_block(txs: impl Iterator<Item = ArcPoolTx>, max_gas: Word) -> Vec<ArcPoolTx> {
+    let txs: Vec<_> = txs.collect();
+    let parents = parents(&txs);
+    let mut used_block_space: Word = 0;
+    let mut selected = Vec::new();
+    let mut selected_ids = HashSet::new();
+    let mut rejected_ids = HashSet::new();
+    let mut waiting: HashMap<TxId, Vec<ArcPoolTx>> = HashMap::new();
+
+    for tx in txs {
+        let mut queue = VecDeque::from([tx]);
+        while let Some(tx) = queue.pop_front() {
+            let tx_id = tx.id();
+            let mut missing = parents
+                .get(&tx_id)
+                .into_iter()
+                .flatten()
+                .filter(|parent| !selected_ids.contains(*parent));
+            let fits = match missing.next() {
+                Some(parent) if !rejected_ids.contains(parent) => {
+                    waiting.entry(*parent).or_default().push(tx);
+                    continue
                 }
+                Some(_) => false,
+                None => match used_block_space.checked_add(tx.max_gas()) {
+                    Some(new_used_space) if new_used_space <= max_gas => {
+                        used_block_space = new_used_space;
+                        true
+                    }
+                    _ => false,
+                },
+            };
+
+            if fits {
+                selected_ids.insert(tx_id);
+                selected.push(tx);
             } else {
-                false
+                rejected_ids.insert(tx_id);
             }
+            // The children are processed right after the parent to keep them close.
+            queue.extend(waiting.remove(&tx_id).into_iter().flatten());
+        }
+    }
+    selected
+}
+
+/// Reorders the transactions so parents always precede their children. Otherwise,
+/// the order is kept.
+pub(crate) fn sorted_by_dependency(
+    txs: impl Iterator<Item = ArcPoolTx>,
+) -> Vec<ArcPoolTx> {
+    // Without the gas limit, every transaction is picked once all its parents are.
+    fill_block(txs, Word::MAX)
+}
+
+/// Returns the parents of each transaction among the `txs`: the transactions creating
+/// the spent coins or the used contracts.
+fn parents(txs: &[ArcPoolTx]) -> HashMap<TxId, Vec<TxId>> {
+    let ids: HashSet<_> = txs.iter().map(|tx| tx.id()).collect();
+    let created_contracts: HashMap<_, _> = txs
+        .iter()
+        .flat_map(|tx| {
+            tx.outputs().iter().filter_map(move |output| match output {
+                Output::ContractCreated { contract_id, .. } => {
+                    Some((*contract_id, tx.id()))
+                }
+                _ => None,
+            })
+        })
+        .collect();
+
+    txs.iter()
+        .map(|tx| {
+            let tx_parents = tx
+                .inputs()
+                .iter()
+                .filter_map(|input| match input {
+                    Input::CoinSigned(CoinSigned { utxo_id, .. })
+                    | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                        ids.get(utxo_id.tx_id()).copied()
+                    }
+                    Input::Contract(Contract { contract_id, .. }) => {
+                        created_contracts.get(contract_id).copied()
+                    }
+                    _ => None,
+                })
+                .filter(|parent| *parent != tx.id())
+                .collect();
+            (tx.id(), tx_parents)
         })
         .collect()
 }
 
+/// The maximum fee paid by the transaction in the units of the gas price.
+fn fee(tx: &ArcPoolTx) -> u128 {
+    u128::from(tx.price()) * u128::from(tx.max_gas())
+}
+
+fn total_fee(txs: &[ArcPoolTx]) -> u128 {
+    txs.iter().map(fee).sum()
+}
+
 #[cfg(test)]
 mod tests {
     use fuel_core_txpool as _;
@@ -47,19 +303,25 @@ mod tests {
             op,
             RegId,
         },
-        fuel_crypto::rand::{
-            thread_rng,
-            Rng,
+        fuel_crypto::{
+            rand::{
+                thread_rng,
+                Rng,
+            },
+            SecretKey,
         },
         fuel_tx::{
             ConsensusParameters,
+            Input,
             Output,
             TransactionBuilder,
+            UtxoId,
         },
         fuel_vm::checked_transaction::builder::TransactionBuilderExt,
+        services::txpool::PoolTransaction,
     };
     use itertools::Itertools;
-    use std::sync::Arc;
+    use std::time::Duration;
 
     use super::*;
 
@@ -69,47 +331,62 @@ mod tests {
         pub limit: u64,
     }
 
-    /// A test helper that generates set of txs with given gas prices and limits and runs
-    /// `select_transactions` against that, returning the list of selected gas price, limit pairs
-    fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
-        let mut rng = thread_rng();
+    fn make_tx(tx_gas: &TxGas, secret: SecretKey) -> ArcPoolTx {
+        make_tx_spending(tx_gas, secret, thread_rng().gen())
+    }
 
-        let txs = txs
-            .iter()
-            .map(|tx_gas| {
-                TransactionBuilder::script(
-                    vec![op::ret(RegId::ONE)].into_iter().collect(),
-                    vec![],
-                )
-                .gas_price(tx_gas.price)
-                .gas_limit(tx_gas.limit)
-                .add_unsigned_coin_input(
-                    rng.gen(),
-                    rng.gen(),
-                    1_000_000,
-                    Default::default(),
-                    Default::default(),
-                    0,
-                )
-                .add_output(Output::Change {
-                    to: Default::default(),
-                    amount: 0,
-                    asset_id: Default::default(),
-                })
-                // The block producer assumes transactions are already checked
-                // so it doesn't need to compute valid sigs for tests
-                .finalize_checked_basic(
-                    0,
-                    &ConsensusParameters {
-                        gas_price_factor: 1,
-                        ..ConsensusParameters::default()
-                    },
-                ).into()
+    fn make_tx_spending(tx_gas: &TxGas, secret: SecretKey, utxo_id: UtxoId) -> ArcPoolTx {
+        let tx: PoolTransaction = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_price(tx_gas.price)
+        .gas_limit(tx_gas.limit)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            1_000_000,
+            Default::default(),
+            Default::default(),
+            0,
+        )
+        .add_output(Output::Change {
+            to: Default::default(),
+            amount: 0,
+            asset_id: Default::default(),
+        })
+        // The block producer assumes transactions are already checked
+        // so it doesn't need to compute valid sigs for tests
+        .finalize_checked_basic(
+            0,
+            &ConsensusParameters {
+                gas_price_factor: 1,
+                ..ConsensusParameters::default()
+            },
+        )
+        .into();
+        Arc::new(tx)
+    }
+
+    /// The transactions arrive into the pool in the order of the slice.
+    fn into_infos(txs: Vec<ArcPoolTx>) -> Vec<TxInfo> {
+        txs.into_iter()
+            .enumerate()
+            .map(|(arrival, tx)| TxInfo {
+                tx,
+                submitted_time: Duration::from_secs(arrival as u64),
+                creation_instant: tokio::time::Instant::now(),
             })
-            .map(Arc::new)
-            .collect();
+            .collect()
+    }
 
-        select_transactions(txs, block_gas_limit)
+    fn select(
+        selector: &dyn TransactionSelector,
+        txs: Vec<ArcPoolTx>,
+        block_gas_limit: Word,
+    ) -> Vec<TxGas> {
+        selector
+            .select(into_infos(txs), block_gas_limit)
             .into_iter()
             .map(|tx| TxGas {
                 limit: tx.limit(),
@@ -118,6 +395,17 @@ mod tests {
             .collect()
     }
 
+    /// A test helper that generates set of txs with given gas prices and limits and runs
+    /// `ByGasPrice` selector against that, returning the list of selected gas price, limit pairs
+    fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
+        let txs = txs
+            .iter()
+            .map(|tx_gas| make_tx(tx_gas, thread_rng().gen()))
+            .collect();
+
+        select(&ByGasPrice, txs, block_gas_limit)
+    }
+
     #[test]
     fn selector_works_with_empty_input() {
         let selected = make_txs_and_select(&[], 1_000_000);
@@ -200,4 +488,259 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn selector_by_time_keeps_arrival_order() {
+        #[rustfmt::skip]
+        let original = [
+            TxGas { price: 1, limit: 1000 },
+            TxGas { price: 5, limit: 5000 },
+            TxGas { price: 3, limit: 1000 },
+            TxGas { price: 4, limit: 1000 },
+        ];
+        let txs = original
+            .iter()
+            .map(|tx_gas| make_tx(tx_gas, thread_rng().gen()))
+            .collect();
+
+        let selected = select(&ByTime, txs, 8_000);
+
+        // The second tx doesn't fit, but the following ones still do.
+        assert_eq!(selected, vec![original[0], original[2], original[3]]);
+    }
+
+    #[test]
+    fn selector_by_time_ignores_the_input_order() {
+        #[rustfmt::skip]
+        let original = [
+            TxGas { price: 1, limit: 1000 },
+            TxGas { price: 2, limit: 1000 },
+            TxGas { price: 3, limit: 1000 },
+        ];
+        let txs = original
+            .iter()
+            .map(|tx_gas| make_tx(tx_gas, thread_rng().gen()))
+            .collect();
+        let mut infos = into_infos(txs);
+        infos.reverse();
+
+        let selected: Vec<_> = ByTime
+            .select(infos, Word::MAX)
+            .into_iter()
+            .map(|tx| tx.price())
+            .collect();
+
+        assert_eq!(selected, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn selector_max_fee_beats_greedy() {
+        #[rustfmt::skip]
+        let original = [
+            TxGas { price: 10, limit: 60_000 },
+            TxGas { price: 9, limit: 50_000 },
+            TxGas { price: 9, limit: 50_000 },
+        ];
+        let txs: Vec<_> = original
+            .iter()
+            .map(|tx_gas| make_tx(tx_gas, thread_rng().gen()))
+            .collect();
+
+        let greedy = select(&ByGasPrice, txs.clone(), 104_000);
+        let max_fee = select(&MaxFee, txs, 104_000);
+
+        assert_eq!(greedy, vec![original[0]]);
+        assert_eq!(max_fee, vec![original[1], original[2]]);
+    }
+
+    #[test]
+    fn selector_max_fee_is_not_worse_than_greedy() {
+        #[rustfmt::skip]
+        let original = [
+            TxGas { price: 3, limit: 2000 },
+            TxGas { price: 1, limit: 1000 },
+            TxGas { price: 4, limit: 3000 },
+            TxGas { price: 5, limit: 1000 },
+            TxGas { price: 2, limit: 1000 },
+        ];
+
+        for k in 0..original.len() {
+            for perm in original.into_iter().permutations(k) {
+                for gas_limit in [999, 1000, 2000, 2500, 3000, 5000, 6000, 10_000] {
+                    let txs: Vec<_> = perm
+                        .iter()
+                        .map(|tx_gas| make_tx(tx_gas, thread_rng().gen()))
+                        .collect();
+                    let greedy = ByGasPrice.select(into_infos(txs.clone()), gas_limit);
+                    let max_fee = MaxFee.select(into_infos(txs), gas_limit);
+                    let total_gas: Word = max_fee.iter().map(|tx| tx.max_gas()).sum();
+
+                    assert!(total_gas <= gas_limit);
+                    assert!(total_fee(&max_fee) >= total_fee(&greedy));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn selector_priority_addresses_go_first() {
+        let mut rng = thread_rng();
+        let priority: SecretKey = rng.gen();
+        let regular: SecretKey = rng.gen();
+        #[rustfmt::skip]
+        let original = [
+            (TxGas { price: 5, limit: 1000 }, regular),
+            (TxGas { price: 1, limit: 1000 }, priority),
+            (TxGas { price: 4, limit: 1000 }, regular),
+            (TxGas { price: 2, limit: 1000 }, priority),
+        ];
+        let txs = original
+            .iter()
+            .map(|(tx_gas, owner)| make_tx(tx_gas, *owner))
+            .collect();
+
+        let selected = select(
+            &PriorityAddresses::new([Input::owner(&priority.public_key())]),
+            txs,
+            7_500,
+        );
+
+        assert_eq!(selected, vec![original[3].0, original[1].0, original[0].0]);
+    }
+
+    #[test]
+    fn selectors_put_parents_before_children() {
+        let mut rng = thread_rng();
+        let priority: SecretKey = rng.gen();
+        let regular: SecretKey = rng.gen();
+        let parent = make_tx(
+            &TxGas {
+                price: 1,
+                limit: 1000,
+            },
+            regular,
+        );
+        let child = make_tx_spending(
+            &TxGas {
+                price: 10,
+                limit: 1000,
+            },
+            priority,
+            UtxoId::new(parent.id(), 0),
+        );
+        let other = make_tx(
+            &TxGas {
+                price: 5,
+                limit: 1000,
+            },
+            regular,
+        );
+        let selectors: [Arc<dyn TransactionSelector>; 4] = [
+            Arc::new(ByGasPrice),
+            Arc::new(ByTime),
+            Arc::new(MaxFee),
+            Arc::new(PriorityAddresses::new([Input::owner(
+                &priority.public_key(),
+            )])),
+        ];
+
+        for selector in selectors {
+            for gas_limit in [999, 1000, 2000, 3000] {
+                let txs = vec![parent.clone(), child.clone(), other.clone()];
+                let selected: Vec<_> = selector
+                    .select(into_infos(txs), gas_limit)
+                    .into_iter()
+                    .map(|tx| tx.id())
+                    .collect();
+
+                let parent_position = selected.iter().position(|id| *id == parent.id());
+                if let Some(child_position) =
+                    selected.iter().position(|id| *id == child.id())
+                {
+                    assert!(matches!(parent_position, Some(p) if p < child_position));
+                }
+            }
+            let all = selector.select(
+                into_infos(vec![parent.clone(), child.clone(), other.clone()]),
+                Word::MAX,
+            );
+            assert_eq!(all.len(), 3);
+        }
+    }
+
+    #[test]
+    fn sorted_by_dependency_puts_children_after_parents() {
+        let mut rng = thread_rng();
+        let tx_gas = TxGas {
+            price: 1,
+            limit: 1000,
+        };
+        let parent = make_tx(&tx_gas, rng.gen());
+        let child = make_tx_spending(&tx_gas, rng.gen(), UtxoId::new(parent.id(), 0));
+        let grandchild = make_tx_spending(&tx_gas, rng.gen(), UtxoId::new(child.id(), 0));
+        let other = make_tx(&tx_gas, rng.gen());
+
+        let sorted = sorted_by_dependency(
+            vec![
+                grandchild.clone(),
+                other.clone(),
+                child.clone(),
+                parent.clone(),
+            ]
+            .into_iter(),
+        );
+
+        let ids = sorted.iter().map(|tx| tx.id()).collect_vec();
+        assert_eq!(
+            ids,
+            vec![other.id(), parent.id(), child.id(), grandchild.id()]
+        );
+    }
+
+    #[test]
+    fn selector_skips_children_of_not_selected_parents() {
+        let mut rng = thread_rng();
+        let parent = make_tx(
+            &TxGas {
+                price: 1,
+                limit: 2000,
+            },
+            rng.gen(),
+        );
+        let child = make_tx_spending(
+            &TxGas {
+                price: 10,
+                limit: 1000,
+            },
+            rng.gen(),
+            UtxoId::new(parent.id(), 0),
+        );
+
+        let selected = ByGasPrice.select(into_infos(vec![parent, child]), 1500);
+
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn selection_strategy_creates_the_selector() {
+        let tx = make_tx(
+            &TxGas {
+                price: 1,
+                limit: 1000,
+            },
+            thread_rng().gen(),
+        );
+
+        for strategy in [
+            SelectionStrategy::GasPrice,
+            SelectionStrategy::ByTime,
+            SelectionStrategy::MaxFee,
+            SelectionStrategy::PriorityAddresses(vec![Default::default()]),
+        ] {
+            let selected = strategy
+                .selector()
+                .select(into_infos(vec![tx.clone()]), Word::MAX);
+            assert_eq!(selected, vec![tx.clone()]);
+        }
+    }
 }
@@ -6,6 +6,7 @@ use crate::{
     },
     ports::TxPoolDb,
     service::TxStatusChange,
+    transaction_selector::sorted_by_dependency,
     types::*,
     Config,
     Error,
@@ -51,12 +52,17 @@ where
 {
     pub fn new(config: Config, database: DB) -> Self {
         let max_depth = config.max_depth;
+        let replacement_price_bump = config.replacement_price_bump;
 
         Self {
             by_hash: HashMap::new(),
             by_gas_price: PriceSort::default(),
             by_time: TimeSort::default(),
-            by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            by_dependency: Dependency::new(
+                max_depth,
+                config.utxo_validation,
+                replacement_price_bump,
+            ),
             config,
             database,
         }
@@ -157,6 +163,7 @@ where
         self.by_hash.insert(tx.id(), info);
 
         // if some transaction were removed so we don't need to check limit
+        let replaced = !rem.is_empty();
         let removed = if rem.is_empty() {
             if max_limit_hit {
                 // remove last tx from sort
@@ -178,6 +185,7 @@ where
         Ok(InsertionResult {
             inserted: tx,
             removed,
+            replaced,
         })
     }
 
@@ -263,11 +271,20 @@ where
         // announce to subscribers
         for ret in res.iter() {
             match ret {
-                Ok(InsertionResult { removed, inserted }) => {
+                Ok(InsertionResult {
+                    removed,
+                    inserted,
+                    replaced,
+                }) => {
                     for removed in removed {
-                        // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
-                        // but for now it is okay to just use Error::Removed.
-                        tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+                        // The dependents of the replaced transaction are removed
+                        // with the same reason.
+                        let reason = if *replaced {
+                            Error::Replaced(inserted.id())
+                        } else {
+                            Error::Removed
+                        };
+                        tx_status_sender.send_squeezed_out(removed.id(), reason);
                     }
                     tx_status_sender.send_submitted(inserted.id());
                 }
@@ -323,10 +340,20 @@ where
         self.by_hash.values().map(|tx| tx.limit()).sum()
     }
 
-    /// Return all sorted transactions that are includable in next block.
+    /// Return all transactions that are includable in next block in the order of
+    /// their arrival, so the selection doesn't depend on the order of the hash map.
     /// This is going to be heavy operation, use it only when needed.
-    pub fn includable(&mut self) -> Vec<ArcPoolTx> {
-        self.sorted_includable()
+    pub fn includable(&mut self) -> Vec<TxInfo> {
+        self.by_time
+            .sort
+            .values()
+            .map(|tx| {
+                self.by_hash
+                    .get(&tx.id())
+                    .expect("Every transaction sorted by time is in the pool")
+                    .clone()
+            })
+            .collect()
     }
 
     /// When block is updated we need to receive all spend outputs and remove them from txpool.
@@ -357,6 +384,42 @@ where
         removed
     }
 
+    /// Saves all transactions of the pool to the database to restore them after the restart.
+    /// Returns the number of saved transactions.
+    pub fn persist(&self) -> anyhow::Result<usize> {
+        // The transactions submitted at the same time are sorted by id, so the parents
+        // are moved before their children to insert them back one by one.
+        let txs: Vec<Transaction> =
+            sorted_by_dependency(self.by_time.sort.values().cloned())
+                .iter()
+                .map(|tx| tx.as_ref().into())
+                .collect();
+        let saved = txs.len();
+        self.database.save_pending_transactions(txs)?;
+        Ok(saved)
+    }
+
+    /// Inserts the transactions saved by the [`TxPool::persist`] back into the pool.
+    /// They are verified against the current state of the blockchain, so the
+    /// transactions that became invalid after the restart are discarded.
+    ///
+    /// The saved transactions are removed only after the insertion, so they are
+    /// not lost if the node stops in between.
+    pub fn restore(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+    ) -> anyhow::Result<Vec<anyhow::Result<InsertionResult>>> {
+        let txs: Vec<_> = self
+            .database
+            .pending_transactions()?
+            .into_iter()
+            .map(Arc::new)
+            .collect();
+        let result = self.insert(tx_status_sender, &txs);
+        self.database.clear_pending_transactions()?;
+        Ok(result)
+    }
+
     /// Remove all old transactions from the pool.
     pub fn prune_old_txs(&mut self) -> Vec<ArcPoolTx> {
         let deadline = tokio::time::Instant::now() - self.config.transaction_ttl;
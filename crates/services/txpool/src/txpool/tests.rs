@@ -287,6 +287,88 @@ fn higher_priced_tx_removes_lower_priced_tx() {
     assert_eq!(vec.removed[0].id(), tx1.id(), "Tx1 id should be removed");
 }
 
+#[test]
+fn replacement_requires_gas_price_bump() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        replacement_price_bump: 10,
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config, db);
+
+    let (_, coin_input) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = |gas_price| {
+        Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(gas_price)
+                .gas_limit(GAS_LIMIT)
+                .add_input(coin_input.clone())
+                .finalize_as_transaction(),
+        )
+    };
+    let tx1 = tx(100);
+    let tx2 = tx(109);
+    let tx3 = tx(110);
+
+    txpool
+        .insert_inner(tx1.clone())
+        .expect("Tx1 should be Ok, got Err");
+
+    let err = txpool
+        .insert_inner(tx2)
+        .expect_err("Tx2 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedCollision(id, utxo_id)) if id == &tx1.id() && Some(utxo_id) == coin_input.utxo_id()
+    ));
+
+    let result = txpool.insert_inner(tx3).expect("Tx3 should be Ok, got Err");
+    assert!(result.replaced);
+    assert_eq!(result.removed.len(), 1);
+    assert_eq!(
+        result.removed[0].id(),
+        tx1.id(),
+        "Tx1 id should be replaced"
+    );
+}
+
+#[test]
+fn zero_replacement_bump_requires_higher_gas_price() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        replacement_price_bump: 0,
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config, db);
+
+    let (_, coin_input) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = |gas_price, gas_limit| {
+        Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(gas_price)
+                .gas_limit(gas_limit)
+                .add_input(coin_input.clone())
+                .finalize_as_transaction(),
+        )
+    };
+
+    txpool
+        .insert_inner(tx(10, GAS_LIMIT))
+        .expect("Tx1 should be Ok, got Err");
+    let err = txpool
+        .insert_inner(tx(10, GAS_LIMIT + 1))
+        .expect_err("Tx2 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedCollision(_, _))
+    ));
+    txpool
+        .insert_inner(tx(11, GAS_LIMIT))
+        .expect("Tx3 should be Ok, got Err");
+}
+
 #[test]
 fn underpriced_tx1_not_included_coin_collision() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -498,7 +580,7 @@ fn more_priced_tx2_removes_tx1_and_more_priced_tx3_removes_tx2() {
     );
     let tx3 = Arc::new(
         TransactionBuilder::script(vec![], vec![])
-            .gas_price(12)
+            .gas_price(13)
             .gas_limit(GAS_LIMIT)
             .add_input(gas_coin)
             .finalize_as_transaction(),
@@ -932,6 +1014,42 @@ async fn higher_priced_tx_squeezes_out_lower_priced_tx_with_same_message_id() {
     assert_eq!(squeezed_out_txs.removed[0].id(), tx_low.id());
 }
 
+#[tokio::test]
+async fn message_replacement_requires_gas_price_bump() {
+    let (message, conflicting_message_input) =
+        create_message_predicate_from_message(10_000, 0);
+    let tx = |gas_price| {
+        Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(gas_price)
+                .gas_limit(GAS_LIMIT)
+                .add_input(conflicting_message_input.clone())
+                .finalize_as_transaction(),
+        )
+    };
+    let tx1 = tx(20);
+
+    let db = MockDb::default();
+    db.insert_message(message.clone());
+    let config = Config {
+        replacement_price_bump: 50,
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config, db);
+
+    txpool.insert_inner(tx1.clone()).expect("should succeed");
+
+    let err = txpool.insert_inner(tx(29)).expect_err("expected failure");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedCollisionMessageId(tx_id, msg_id)) if tx_id == &tx1.id() && msg_id == message.id()
+    ));
+
+    let result = txpool.insert_inner(tx(30)).expect("should succeed");
+    assert!(result.replaced);
+    assert_eq!(result.removed[0].id(), tx1.id());
+}
+
 #[tokio::test]
 async fn message_of_squeezed_out_tx_can_be_resubmitted_at_lower_gas_price() {
     // tx1 (message 1, message 2) gas_price 2
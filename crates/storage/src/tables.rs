@@ -6,7 +6,10 @@ use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
         consensus::Consensus,
-        primitives::BlockId,
+        primitives::{
+            BlockHeight,
+            BlockId,
+        },
     },
     entities::{
         coins::coin::CompressedCoin,
@@ -37,10 +40,9 @@ pub use fuel_vm_private::storage::{
 pub struct FuelBlocks;
 
 impl Mappable for FuelBlocks {
-    /// Unique identifier of the fuel block.
+    /// The height of the fuel block.
     type Key = Self::OwnedKey;
-    // TODO: Seems it would be faster to use `BlockHeight` as primary key.
-    type OwnedKey = BlockId;
+    type OwnedKey = BlockHeight;
     type Value = Self::OwnedValue;
     type OwnedValue = CompressedBlock;
 }
@@ -96,6 +96,17 @@ impl Block<Transaction> {
 }
 
 impl<T> Block<T> {
+    /// Create the block without transactions from the header.
+    ///
+    /// The header is not modified, so it still describes the original
+    /// transactions. It is used by the nodes that store only the header chain.
+    pub fn from_header(header: BlockHeader) -> Self {
+        Self {
+            header,
+            transactions: vec![],
+        }
+    }
+
     /// Destructure into the inner types.
     pub fn into_inner(self) -> (BlockHeader, Vec<T>) {
         (self.header, self.transactions)
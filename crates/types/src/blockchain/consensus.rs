@@ -97,4 +97,7 @@ pub struct Genesis {
     pub contracts_root: Bytes32,
     /// The Binary Merkle Tree root of all genesis messages.
     pub messages_root: Bytes32,
+    /// The id of the last block of the previous chain, if the network was started
+    /// from its snapshot(regenesis). It links the history of both chains.
+    pub previous_chain_block_id: Option<BlockId>,
 }
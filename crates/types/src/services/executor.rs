@@ -8,16 +8,20 @@ use crate::{
         },
         primitives::BlockId,
     },
+    entities::coins::coin::Coin,
     fuel_tx::{
         CheckError,
+        Receipt,
         Transaction,
         TxId,
         UtxoId,
     },
     fuel_types::{
+        AssetId,
         Bytes32,
         ContractId,
         Nonce,
+        Word,
     },
     fuel_vm::{
         Backtrace,
@@ -72,6 +76,102 @@ pub enum TransactionExecutionResult {
     },
 }
 
+/// The state placed on top of the database before the dry run of transactions.
+/// It allows simulating transactions that depend on the state absent in the chain.
+#[derive(Debug, Clone, Default)]
+pub struct StateOverrides {
+    /// The coins inserted into the state. An existing coin with the same `UtxoId`
+    /// is replaced.
+    pub coins: Vec<Coin>,
+    /// The values of the contracts storage slots.
+    pub contract_state: Vec<ContractStateSlot>,
+    /// The bytecode of the contracts. The contract is created if it doesn't exist.
+    pub contract_code: Vec<(ContractId, Vec<u8>)>,
+}
+
+/// The value of the contract storage slot. The `None` value means that the slot is
+/// empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractStateSlot {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The key of the storage slot.
+    pub key: Bytes32,
+    /// The value of the storage slot.
+    pub value: Option<Bytes32>,
+}
+
+/// The balance of the contract after the transaction execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractBalance {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The asset of the balance.
+    pub asset_id: AssetId,
+    /// The amount of the asset owned by the contract.
+    pub amount: Word,
+}
+
+/// The changes of the state made by the transaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    /// The coins created by the transaction.
+    pub created_coins: Vec<Coin>,
+    /// The coins spent by the transaction.
+    pub spent_coins: Vec<UtxoId>,
+    /// The contracts deployed by the transaction.
+    pub created_contracts: Vec<ContractId>,
+    /// The new values of the modified storage slots.
+    pub contract_state: Vec<ContractStateSlot>,
+    /// The new balances of the contracts.
+    pub contract_balances: Vec<ContractBalance>,
+}
+
+/// The result of the dry run of one transaction from the batch.
+#[derive(Debug, Clone)]
+pub struct DryRunResult {
+    /// The id of the transaction.
+    pub id: TxId,
+    /// The receipts produced by the transaction.
+    pub receipts: Vec<Receipt>,
+    /// The gas used by the script. It is zero for non-script transactions.
+    pub gas_used: Word,
+    /// The changes of the state made by the transaction.
+    pub state_diff: StateDiff,
+}
+
+/// The call tree of the executed transaction.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionTrace {
+    /// The gas used by the script. It is zero for non-script transactions.
+    pub gas_used: Word,
+    /// The call frames in the order of the calls. The first frame is the script of
+    /// the transaction, it is the root of the tree.
+    pub frames: Vec<CallFrameTrace>,
+}
+
+/// The trace of one call frame of the transaction.
+///
+/// The gas used by the frame isn't tracked: the receipts of the VM contain only
+/// the gas forwarded to the call, not the gas left at its end.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallFrameTrace {
+    /// The index of the calling frame in the [`TransactionTrace::frames`].
+    /// `None` for the script.
+    pub parent: Option<u32>,
+    /// The called contract. `None` for the script.
+    pub contract_id: Option<ContractId>,
+    /// The gas forwarded to the frame. The gas limit of the transaction for the script.
+    pub gas_forwarded: Word,
+    /// The indexes of the receipts produced by the frame in the receipts of the
+    /// transaction. The `Call` receipt of the nested call belongs to the calling frame,
+    /// so the order of the nested frames relative to the receipts of the frame is
+    /// preserved.
+    pub receipts: Vec<u32>,
+}
+
 /// Execution wrapper where the types
 /// depend on the type of execution.
 #[derive(Debug, Clone, Copy)]
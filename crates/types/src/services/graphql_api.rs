@@ -3,6 +3,7 @@
 use crate::fuel_types::{
     Address,
     AssetId,
+    Bytes32,
     ContractId,
 };
 
@@ -21,3 +22,35 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The change of the owner's balance of `asset_id` made by one transaction.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BalanceDelta {
+    /// The identifier of the asset.
+    pub asset_id: AssetId,
+    /// The amount of the coins created for the owner.
+    pub received: u64,
+    /// The amount of the owner's coins spent by the transaction.
+    pub spent: u64,
+}
+
+impl BalanceDelta {
+    /// The net change of the balance, negative if the owner spent more than received.
+    pub fn net(&self) -> i128 {
+        self.received as i128 - self.spent as i128
+    }
+}
+
+/// The transaction from the history of the owner.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedTransaction {
+    /// The id of the transaction.
+    pub tx_id: Bytes32,
+    /// `true` if the execution of the transaction was reverted.
+    pub reverted: bool,
+    /// The changes of the owner's balances sorted by the `asset_id`. Assets
+    /// spent and received back in the same amount have a zero net change.
+    pub deltas: Vec<BalanceDelta>,
+}
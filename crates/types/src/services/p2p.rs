@@ -5,6 +5,7 @@ use crate::{
     fuel_tx::Transaction,
 };
 use std::fmt::Debug;
+use tai64::Tai64;
 
 /// Lightweight representation of gossipped data that only includes IDs
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -30,6 +31,30 @@ pub enum GossipsubMessageAcceptance {
     Ignore,
 }
 
+/// The behaviour of the peer reported by the services to adjust its reputation.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum PeerReportReason {
+    /// The peer served a block that was successfully imported
+    SuccessfulBlockImport,
+    /// The peer served a header with an invalid height or consensus
+    InvalidHeader,
+    /// The peer served transactions that don't match the header,
+    /// or the block built from them failed the execution
+    InvalidBlock,
+    /// The gossiped message from the peer was rejected by the validation
+    RejectedGossip,
+}
+
+/// The peer banned by the reputation system until the specified time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BannedPeer {
+    /// The ID of the banned peer
+    pub peer_id: PeerId,
+    /// The time when the ban expires
+    pub banned_until: Tai64,
+}
+
 /// A gossipped message from the network containing all relevant data.
 #[derive(Debug, Clone)]
 pub struct GossipData<T> {
@@ -147,6 +147,10 @@ pub struct InsertionResult {
     pub inserted: ArcPoolTx,
     /// These were removed during the insertion
     pub removed: Vec<ArcPoolTx>,
+    /// The removed transactions were replaced by the inserted one because it spends
+    /// the same inputs with a higher gas price(replace-by-fee). Otherwise, they were
+    /// squeezed out because the pool is full.
+    pub replaced: bool,
 }
 
 /// The status of the transaction during its life from the tx pool until the block.
@@ -265,6 +269,8 @@ pub enum Error {
     // small todo for now it can pass but in future we should include better messages
     #[error("Transaction removed.")]
     Removed,
+    #[error("Transaction replaced by {0:#x} with a higher gas price.")]
+    Replaced(TxId),
     #[error("Transaction expired because it exceeded the configured time to live `tx-pool-ttl`.")]
     TTLReason,
     #[error("Transaction squeezed out because {0}")]
@@ -0,0 +1,89 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+    },
+    types::secrecy::Secret,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::Transaction,
+};
+
+const ADMIN_TOKEN: &str = "admin-secret";
+
+async fn start_node() -> FuelService {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    config.admin_token = Some(Secret::new(ADMIN_TOKEN.to_string()));
+    FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap()
+}
+
+fn script_tx() -> Transaction {
+    let script = u32::from(op::ret(RegId::ONE)).to_be_bytes().to_vec();
+    Transaction::script(0, 1_000_000, 0, script, vec![], vec![], vec![], vec![]).into()
+}
+
+fn is_unauthorized(error: std::io::Error) -> bool {
+    error.to_string().contains("requires the admin token")
+}
+
+#[tokio::test]
+async fn privileged_operations_require_admin_token() {
+    let srv = start_node().await;
+    let client = FuelClient::from(srv.bound_address);
+    let tx = script_tx();
+
+    assert!(is_unauthorized(
+        client.produce_blocks(1, None).await.unwrap_err()
+    ));
+    assert!(is_unauthorized(client.start_session().await.unwrap_err()));
+    assert!(is_unauthorized(client.dry_run(&tx).await.unwrap_err()));
+    assert!(is_unauthorized(
+        client.estimate_predicates(&tx).await.unwrap_err()
+    ));
+
+    let client = client.with_admin_token("wrong-secret");
+    assert!(is_unauthorized(
+        client.produce_blocks(1, None).await.unwrap_err()
+    ));
+}
+
+#[tokio::test]
+async fn privileged_operations_are_allowed_with_admin_token() {
+    let srv = start_node().await;
+    let client = FuelClient::from(srv.bound_address).with_admin_token(ADMIN_TOKEN);
+    let tx = script_tx();
+
+    assert_eq!(client.produce_blocks(2, None).await.unwrap(), 2);
+    let session = client.start_session().await.unwrap();
+    assert!(client.end_session(&session).await.unwrap());
+    assert!(!client.dry_run(&tx).await.unwrap().is_empty());
+    assert_eq!(client.estimate_predicates(&tx).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn read_api_is_public_with_admin_token() {
+    let srv = start_node().await;
+    let client = FuelClient::from(srv.bound_address);
+
+    assert!(client.health().await.unwrap());
+    assert_eq!(
+        client
+            .chain_info()
+            .await
+            .unwrap()
+            .latest_block
+            .header
+            .height
+            .0,
+        0
+    );
+}
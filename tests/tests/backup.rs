@@ -0,0 +1,63 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use tempfile::TempDir;
+
+#[tokio::test]
+async fn backup_database_fails_without_backup_dir() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client.backup_database("backup").await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn backup_database_rejects_paths() {
+    let backup_dir = TempDir::new().unwrap();
+    let mut config = Config::local_node();
+    config.backup_dir = Some(backup_dir.path().to_path_buf());
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    for name in ["../backup", "nested/backup", "/backup", ""] {
+        let result = client.backup_database(name).await;
+        assert!(result.is_err(), "The name `{name}` should be rejected");
+    }
+}
+
+#[cfg(feature = "default")]
+#[tokio::test]
+async fn backup_database_creates_a_copy_of_the_database() {
+    use fuel_core::service::ServiceTrait;
+    use fuel_core_types::blockchain::primitives::BlockHeight;
+
+    let db_dir = TempDir::new().unwrap();
+    let backup_dir = TempDir::new().unwrap();
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    config.backup_dir = Some(backup_dir.path().to_path_buf());
+    let database = Database::open(db_dir.path()).unwrap();
+    let srv = FuelService::from_database(database, config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    let path = client.backup_database("backup").await.unwrap();
+    assert_eq!(path, backup_dir.path().join("backup").display().to_string());
+
+    // The backup can't be created twice with the same name.
+    assert!(client.backup_database("backup").await.is_err());
+    srv.stop_and_await().await.unwrap();
+
+    let backup = Database::open(&backup_dir.path().join("backup")).unwrap();
+    assert_eq!(backup.latest_height().unwrap(), BlockHeight::from(3u32));
+}
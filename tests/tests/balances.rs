@@ -30,6 +30,8 @@ async fn balance() {
     let mut config = Config::local_node();
     config.chain_conf.initial_state = Some(StateConfig {
         height: None,
+        last_block_id: None,
+        spent_messages: None,
         contracts: None,
         coins: Some(
             vec![
@@ -209,6 +211,8 @@ async fn first_5_balances() {
     let mut config = Config::local_node();
     config.chain_conf.initial_state = Some(StateConfig {
         height: None,
+        last_block_id: None,
+        spent_messages: None,
         contracts: None,
         coins: Some(coins),
         messages: Some(messages),
@@ -1,4 +1,10 @@
 use fuel_core::{
+    chain_config::{
+        write_snapshot,
+        CheckpointConfig,
+        SnapshotEncoding,
+        SnapshotReader,
+    },
     database::Database,
     schema::scalars::BlockId,
     service::{
@@ -7,7 +13,10 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
-    types::TransactionStatus,
+    types::{
+        TransactionResponse,
+        TransactionStatus,
+    },
     FuelClient,
     PageDirection,
     PaginationRequest,
@@ -29,6 +38,7 @@ use fuel_core_types::{
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
+use futures::StreamExt;
 use itertools::{
     rev,
     Itertools,
@@ -42,7 +52,10 @@ use std::{
 #[tokio::test]
 async fn block() {
     // setup test data in the node
-    let block = CompressedBlock::default();
+    let mut block = CompressedBlock::default();
+    let height = 1u32.into();
+    block.header_mut().consensus.height = height;
+    block.header_mut().recalculate_metadata();
     let id = block.id();
     let mut db = Database::default();
     // setup server & client
@@ -51,7 +64,7 @@ async fn block() {
         .unwrap();
     let client = FuelClient::from(srv.bound_address);
 
-    db.storage::<FuelBlocks>().insert(&id, &block).unwrap();
+    db.storage::<FuelBlocks>().insert(&height, &block).unwrap();
     db.storage::<SealedBlockConsensus>()
         .insert(&id, &Consensus::PoA(Default::default()))
         .unwrap();
@@ -340,3 +353,238 @@ async fn block_connection_5(
         }
     };
 }
+
+#[tokio::test]
+async fn subscribe_new_blocks_from_start_height() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::default();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    let blocks = client.subscribe_new_blocks(Some(1)).await.unwrap();
+    let headers = client.subscribe_new_headers(Some(2)).await.unwrap();
+    let blocks = tokio::spawn(blocks.take(3).collect::<Vec<_>>());
+    let headers = tokio::spawn(headers.take(2).collect::<Vec<_>>());
+    // Wait until the subscriptions receive existing blocks from the database.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    client.produce_blocks(1, None).await.unwrap();
+
+    let blocks = tokio::time::timeout(Duration::from_secs(10), blocks)
+        .await
+        .unwrap()
+        .unwrap()
+        .into_iter()
+        .collect::<std::io::Result<Vec<_>>>()
+        .unwrap();
+    let heights = blocks
+        .iter()
+        .map(|block| block.header.height.0)
+        .collect_vec();
+    assert_eq!(heights, vec![1, 2, 3]);
+    let transaction = blocks
+        .into_iter()
+        .next()
+        .unwrap()
+        .transactions
+        .into_iter()
+        .map(|tx| TransactionResponse::try_from(tx).unwrap())
+        .find(|response| response.transaction.id() == tx.id())
+        .expect("The block contains the submitted transaction");
+    assert!(matches!(
+        transaction.status,
+        TransactionStatus::Success { .. }
+    ));
+
+    let headers = tokio::time::timeout(Duration::from_secs(10), headers)
+        .await
+        .unwrap()
+        .unwrap()
+        .into_iter()
+        .collect::<std::io::Result<Vec<_>>>()
+        .unwrap();
+    let heights = headers.iter().map(|header| header.height.0).collect_vec();
+    assert_eq!(heights, vec![2, 3]);
+}
+
+#[tokio::test]
+async fn commit_block_headers_without_execution() {
+    // The producer executes the blocks and the light node imports only their headers.
+    let producer_db = Database::default();
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let producer = FuelService::from_database(producer_db.clone(), config)
+        .await
+        .unwrap();
+    let producer_client = FuelClient::from(producer.bound_address);
+    let tx = Transaction::default();
+    producer_client.submit_and_await_commit(&tx).await.unwrap();
+    producer_client.produce_blocks(2, None).await.unwrap();
+
+    let light_db = Database::default();
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let light_node = FuelService::from_database(light_db.clone(), config)
+        .await
+        .unwrap();
+    let light_client = FuelClient::from(light_node.bound_address);
+    let importer = &light_node.shared.block_importer;
+    let header = |height: u32| {
+        producer_db
+            .get_sealed_block_header_by_height(&height.into())
+            .unwrap()
+            .unwrap()
+    };
+
+    importer.commit_header(header(1)).await.unwrap();
+    importer
+        .commit_header(header(3))
+        .await
+        .expect_err("Should skip the height 2");
+    importer.commit_header(header(2)).await.unwrap();
+    let mut unlinked_header = header(3);
+    unlinked_header.entity.consensus.prev_root = Default::default();
+    unlinked_header.entity.recalculate_metadata();
+    importer
+        .commit_header(unlinked_header)
+        .await
+        .expect_err("Should not match the previous root");
+    importer.commit_header(header(3)).await.unwrap();
+
+    let light_query = |query: String| {
+        let address = light_node.bound_address;
+        async move {
+            reqwest::Client::new()
+                .post(format!("http://{address}/graphql"))
+                .json(&serde_json::json!({ "query": query }))
+                .send()
+                .await
+                .unwrap()
+                .json::<serde_json::Value>()
+                .await
+                .unwrap()
+        }
+    };
+    for height in 1..=3 {
+        let expected = producer_client
+            .block_by_height(height)
+            .await
+            .unwrap()
+            .unwrap();
+        let actual = light_query(format!(
+            "{{ block(height: \"{height}\") {{ id header {{ prevRoot }} }} }}"
+        ))
+        .await;
+        assert_eq!(actual["data"]["block"]["id"], expected.id.to_string());
+        assert_eq!(
+            actual["data"]["block"]["header"]["prevRoot"],
+            expected.header.prev_root.to_string()
+        );
+        assert_eq!(
+            light_db
+                .get_sealed_block_header_by_height(&height.into())
+                .unwrap()
+                .unwrap()
+                .consensus,
+            header(height as u32).consensus
+        );
+
+        // The transactions of the block are unknown.
+        let response = light_query(format!(
+            "{{ block(height: \"{height}\") {{ transactions {{ id }} }} }}"
+        ))
+        .await;
+        let err = response["errors"][0]["message"].as_str().unwrap();
+        assert!(err.contains("headers-only"), "{err}");
+        // The peers can't request the transactions of the block from the light node.
+        let block_id = header(height as u32).entity.id();
+        assert!(light_db
+            .get_transactions_on_block(&block_id)
+            .unwrap()
+            .is_none());
+    }
+    let chain =
+        light_query("{ chain { latestBlock { header { height } } } }".to_string()).await;
+    assert_eq!(
+        chain["data"]["chain"]["latestBlock"]["header"]["height"],
+        "3"
+    );
+
+    let err = light_client
+        .message_proof(
+            &format!("{:#x}", tx.id()),
+            &format!("{:#x}", MessageId::default()),
+        )
+        .await
+        .expect_err("The light node doesn't have the receipts");
+    assert!(err.to_string().contains("headers-only"), "{err}");
+}
+
+#[tokio::test]
+async fn checkpoint_node_executes_blocks_after_the_checkpoint() {
+    let producer_db = Database::default();
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let chain_conf = config.chain_conf.clone();
+    let producer = FuelService::from_database(producer_db.clone(), config)
+        .await
+        .unwrap();
+    let producer_client = FuelClient::from(producer.bound_address);
+    producer_client.produce_blocks(2, None).await.unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    write_snapshot(
+        &producer_db,
+        chain_conf,
+        dir.path(),
+        10,
+        SnapshotEncoding::Json,
+    )
+    .unwrap();
+    let tx = Transaction::default();
+    producer_client.submit_and_await_commit(&tx).await.unwrap();
+
+    // The new node starts from the state at the height 2.
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.chain_conf.checkpoint = Some(CheckpointConfig {
+        height: 2u32.into(),
+        block_id: producer_db
+            .get_block_id(&2u32.into())
+            .unwrap()
+            .unwrap()
+            .into(),
+    });
+    config.checkpoint_snapshot = Some(SnapshotReader::open(dir.path()).unwrap());
+    let node = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(node.bound_address);
+    let importer = &node.shared.block_importer;
+
+    for height in 1..=2u32 {
+        let header = producer_db
+            .get_sealed_block_header_by_height(&height.into())
+            .unwrap()
+            .unwrap();
+        importer.commit_header(header).await.unwrap();
+    }
+    let block = producer_db
+        .get_sealed_block_by_height(&3u32.into())
+        .unwrap()
+        .unwrap();
+    importer.execute_and_commit(block).await.unwrap();
+
+    let expected = producer_client.block_by_height(3).await.unwrap().unwrap();
+    let actual = client.block_by_height(3).await.unwrap().unwrap();
+    assert_eq!(actual.id.to_string(), expected.id.to_string());
+    let status = client
+        .transaction_status(&tx.id().to_string())
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
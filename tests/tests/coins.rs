@@ -35,6 +35,8 @@ mod coin {
         let mut config = Config::local_node();
         config.chain_conf.initial_state = Some(StateConfig {
             height: None,
+            last_block_id: None,
+            spent_messages: None,
             contracts: None,
             coins: Some(
                 vec![
@@ -325,6 +327,8 @@ mod message_coin {
         let mut config = Config::local_node();
         config.chain_conf.initial_state = Some(StateConfig {
             height: None,
+            last_block_id: None,
+            spent_messages: None,
             contracts: None,
             coins: None,
             messages: Some(
@@ -554,6 +558,8 @@ mod all_coins {
         let mut config = Config::local_node();
         config.chain_conf.initial_state = Some(StateConfig {
             height: None,
+            last_block_id: None,
+            spent_messages: None,
             contracts: None,
             coins: Some(
                 vec![
@@ -786,6 +792,8 @@ async fn empty_setup() -> TestContext {
     let mut config = Config::local_node();
     config.chain_conf.initial_state = Some(StateConfig {
         height: None,
+        last_block_id: None,
+        spent_messages: None,
         contracts: None,
         coins: None,
         messages: None,
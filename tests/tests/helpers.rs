@@ -8,6 +8,7 @@ use fuel_core::{
     service::{
         Config,
         FuelService,
+        VMConfig,
     },
 };
 use fuel_core_client::client::FuelClient;
@@ -54,6 +55,7 @@ pub struct TestSetupBuilder {
     pub min_gas_price: u64,
     pub starting_block: Option<BlockHeight>,
     pub utxo_validation: bool,
+    pub vm_trace: bool,
 }
 
 impl TestSetupBuilder {
@@ -163,6 +165,10 @@ impl TestSetupBuilder {
                 ..fuel_core_txpool::Config::default()
             },
             chain_conf: chain_config,
+            vm: VMConfig {
+                trace: self.vm_trace,
+                ..Default::default()
+            },
             ..Config::local_node()
         };
 
@@ -186,6 +192,7 @@ impl Default for TestSetupBuilder {
             min_gas_price: 0,
             starting_block: None,
             utxo_validation: true,
+            vm_trace: false,
         }
     }
 }
@@ -1,5 +1,7 @@
 #![deny(unused_must_use)]
 
+mod admin_auth;
+mod backup;
 mod balances;
 mod blocks;
 mod chain;
@@ -13,6 +15,7 @@ mod helpers;
 mod messages;
 mod node_info;
 mod poa;
+mod query_limits;
 #[cfg(feature = "relayer")]
 mod relayer;
 mod snapshot;
@@ -22,5 +25,6 @@ mod trigger_integration;
 mod tx;
 #[cfg(feature = "p2p")]
 mod tx_gossip;
+mod websocket;
 
 fuel_core_trace::enable_tracing!();
@@ -0,0 +1,132 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+        QueryLimits,
+        RateLimitConfig,
+    },
+};
+use reqwest::StatusCode;
+use serde_json::{
+    json,
+    Value,
+};
+
+async fn start_node(query_limits: QueryLimits) -> FuelService {
+    let mut config = Config::local_node();
+    config.query_limits = query_limits;
+    FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap()
+}
+
+async fn post(srv: &FuelService, query: &str) -> (StatusCode, Value) {
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .json(&json!({ "query": query }))
+        .send()
+        .await
+        .unwrap();
+    let status = response.status();
+    (status, response.json().await.unwrap())
+}
+
+fn first_error(response: &Value) -> &Value {
+    &response["errors"][0]
+}
+
+#[tokio::test]
+async fn too_deep_query_is_rejected() {
+    let srv = start_node(QueryLimits {
+        max_depth: 3,
+        ..Default::default()
+    })
+    .await;
+
+    let (_, response) =
+        post(&srv, "{ chain { latestBlock { header { height } } } }").await;
+    assert!(first_error(&response)["message"]
+        .as_str()
+        .unwrap()
+        .contains("nested too deep"));
+
+    let (_, response) = post(&srv, "{ chain { latestBlock { id } } }").await;
+    assert!(response["errors"].is_null());
+}
+
+fn blocks_with_receipts(first: usize) -> String {
+    format!(
+        "{{ blocks(first: {first}) {{ nodes {{ transactions {{ receipts {{ \
+            amount assetId gas param1 param2 pc is ra rb rc rd len digest data \
+        }} }} }} }} }}"
+    )
+}
+
+#[tokio::test]
+async fn too_complex_query_is_rejected() {
+    let srv = start_node(Default::default()).await;
+
+    let (_, response) = post(&srv, &blocks_with_receipts(10000)).await;
+    assert!(first_error(&response)["message"]
+        .as_str()
+        .unwrap()
+        .contains("too complex"));
+
+    let (_, response) = post(&srv, &blocks_with_receipts(10)).await;
+    assert!(response["errors"].is_null());
+}
+
+#[tokio::test]
+async fn big_page_of_nested_lists_is_rejected_by_default() {
+    let srv = start_node(Default::default()).await;
+
+    // The lists of transactions and receipts are charged per element, so even the
+    // cheapest selection is too complex for the biggest page.
+    let (_, response) = post(
+        &srv,
+        "{ blocks(first: 10000) { nodes { transactions { receipts { amount } } } } }",
+    )
+    .await;
+    assert!(first_error(&response)["message"]
+        .as_str()
+        .unwrap()
+        .contains("too complex"));
+}
+
+#[tokio::test]
+async fn too_big_page_is_rejected() {
+    let srv = start_node(QueryLimits {
+        max_page_size: 10,
+        ..Default::default()
+    })
+    .await;
+
+    let (_, response) = post(&srv, "{ blocks(last: 11) { nodes { id } } }").await;
+    let error = first_error(&response);
+    assert_eq!(error["extensions"]["code"], "PAGE_SIZE_LIMIT");
+    assert_eq!(error["extensions"]["maxPageSize"], 10);
+
+    let (_, response) = post(&srv, "{ blocks(last: 10) { nodes { id } } }").await;
+    assert!(response["errors"].is_null());
+}
+
+#[tokio::test]
+async fn requests_above_rate_limit_are_throttled() {
+    let srv = start_node(QueryLimits {
+        rate_limit: Some(RateLimitConfig {
+            requests_per_second: 1,
+            burst: 2,
+        }),
+        ..Default::default()
+    })
+    .await;
+
+    for _ in 0..2 {
+        let (status, _) = post(&srv, "{ health }").await;
+        assert_eq!(status, StatusCode::OK);
+    }
+    let (status, response) = post(&srv, "{ health }").await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(first_error(&response)["extensions"]["code"], "RATE_LIMITED");
+}
@@ -38,6 +38,8 @@ async fn snapshot_state_config() {
     let mut config = Config::local_node();
     let starting_state = StateConfig {
         height: Some(BlockHeight::from(10u64)),
+        last_block_id: None,
+        spent_messages: None,
         contracts: Some(vec![ContractConfig {
             code: vec![8; 32],
             salt: Salt::new([9; 32]),
@@ -6,12 +6,22 @@ use fuel_core::{
         adapters::MaybeRelayerAdapter,
         Config,
         FuelService,
+        PruningConfig,
     },
 };
 use fuel_core_client::client::{
-    types::TransactionStatus,
+    schema::tx::{
+        TransactionHistoryFilterInput,
+        TransactionHistoryStatus,
+        TransferDirection,
+    },
+    types::{
+        OwnerTransactionResponse,
+        TransactionStatus,
+    },
     FuelClient,
     PageDirection,
+    PaginatedResult,
     PaginationRequest,
 };
 use fuel_core_types::{
@@ -24,7 +34,10 @@ use fuel_core_types::{
     },
     fuel_asm::*,
     fuel_tx::*,
-    services::executor::ExecutionBlock,
+    services::{
+        executor::ExecutionBlock,
+        graphql_api::BalanceDelta,
+    },
     tai64::Tai64,
 };
 use itertools::Itertools;
@@ -38,6 +51,9 @@ use std::{
     io::ErrorKind::NotFound,
 };
 
+mod dry_run_batch;
+mod execution_trace;
+mod gas_estimation;
 mod predicates;
 mod tx_pointer;
 mod txn_status_subscription;
@@ -193,6 +209,41 @@ async fn submit() {
     assert_eq!(tx.id(), ret_tx.id());
 }
 
+#[cfg(feature = "default")]
+#[tokio::test]
+async fn pending_transaction_is_restored_after_restart() {
+    use fuel_core::service::{
+        config::Trigger,
+        ServiceTrait,
+    };
+
+    let db_dir = tempfile::TempDir::new().unwrap();
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let tx = create_mock_tx(1);
+
+    let database = Database::open(db_dir.path()).unwrap();
+    let srv = FuelService::from_database(database, config.clone())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit(&tx).await.unwrap();
+    srv.stop_and_await().await.unwrap();
+    drop(srv);
+
+    let database = Database::open(db_dir.path()).unwrap();
+    let srv = FuelService::from_database(database, config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let status = client
+        .transaction_status(&tx.id().to_string())
+        .await
+        .unwrap();
+    assert!(
+        matches!(status, TransactionStatus::Submitted { .. }),
+        "{status:?}"
+    );
+}
+
 #[ignore]
 #[tokio::test]
 async fn transaction_status_submitted() {
@@ -244,6 +295,61 @@ async fn get_transaction_by_id() {
     }
 }
 
+#[tokio::test]
+async fn get_pruned_transaction_by_id_returns_error() {
+    let transaction = Transaction::default();
+    let id = transaction.id();
+
+    // setup server & client
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    config.pruning = Some(PruningConfig { retained_blocks: 1 });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    // submit tx to api and move it out of the retention window
+    client.submit_and_await_commit(&transaction).await.unwrap();
+    client.produce_blocks(2, None).await.unwrap();
+
+    // the pruning happens in the background
+    let mut result = client.transaction(&format!("{id:#x}")).await;
+    for _ in 0..50 {
+        if result.is_err() {
+            break
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        result = client.transaction(&format!("{id:#x}")).await;
+    }
+
+    let err = result.expect_err("the transaction should be pruned");
+    assert!(err.to_string().contains("pruned"), "{err}");
+    let err = client
+        .receipts(&format!("{id:#x}"))
+        .await
+        .expect_err("the receipts should be pruned");
+    assert!(err.to_string().contains("pruned"), "{err}");
+    let err = client
+        .transaction_status(&format!("{id:#x}"))
+        .await
+        .expect_err("the status should be pruned");
+    assert!(err.to_string().contains("pruned"), "{err}");
+
+    // the block of the transaction reports that its transactions are pruned
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .json(&serde_json::json!({
+            "query": "{ block(height: \"1\") { transactions { id } } }"
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let err = response["errors"][0]["message"].as_str().unwrap();
+    assert!(err.contains("pruned"), "{err}");
+    assert!(client.block_by_height(3).await.unwrap().is_some());
+}
+
 #[tokio::test]
 async fn get_transparent_transaction_by_id() {
     let transaction = Transaction::default();
@@ -647,6 +753,235 @@ async fn get_owned_transactions() {
     assert_eq!(&charlie_txs, &[tx1, tx2, tx3]);
 }
 
+#[tokio::test]
+async fn get_transaction_history_with_filters() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    let tx1 = context.transfer(alice, charlie, 1).await.unwrap();
+    let tx2 = context.transfer(charlie, bob, 2).await.unwrap();
+    let tx3 = context.transfer(bob, charlie, 3).await.unwrap();
+
+    let client = context.client;
+    let filter = TransactionHistoryFilterInput {
+        owner: charlie.into(),
+        asset_id: None,
+        direction: None,
+        status: None,
+        from_height: None,
+        to_height: None,
+    };
+    let history = |filter: TransactionHistoryFilterInput, direction| {
+        let client = &client;
+        async move {
+            let page_request = PaginationRequest {
+                cursor: None,
+                results: 5,
+                direction,
+            };
+            client
+                .transaction_history(filter, page_request)
+                .await
+                .unwrap()
+                .results
+        }
+    };
+    let ids = |txs: Vec<OwnerTransactionResponse>| {
+        txs.iter()
+            .map(|tx| tx.transaction.transaction.id())
+            .collect_vec()
+    };
+
+    let all = history(filter.clone(), PageDirection::Forward).await;
+    assert_eq!(ids(all.clone()), vec![tx1, tx2, tx3]);
+    assert!(all.iter().all(|tx| !tx.reverted));
+    assert_eq!(
+        all[1].deltas,
+        vec![BalanceDelta {
+            asset_id: AssetId::BASE,
+            received: 0,
+            spent: 2,
+        }]
+    );
+
+    let received = TransactionHistoryFilterInput {
+        direction: Some(TransferDirection::In),
+        ..filter.clone()
+    };
+    assert_eq!(
+        ids(history(received, PageDirection::Forward).await),
+        vec![tx1, tx3]
+    );
+
+    let spent = TransactionHistoryFilterInput {
+        direction: Some(TransferDirection::Out),
+        asset_id: Some(AssetId::BASE.into()),
+        ..filter.clone()
+    };
+    assert_eq!(ids(history(spent, PageDirection::Forward).await), vec![tx2]);
+
+    let other_asset = TransactionHistoryFilterInput {
+        asset_id: Some(AssetId::from([1; 32]).into()),
+        ..filter.clone()
+    };
+    assert!(history(other_asset, PageDirection::Forward)
+        .await
+        .is_empty());
+
+    let reverted = TransactionHistoryFilterInput {
+        status: Some(TransactionHistoryStatus::Reverted),
+        ..filter.clone()
+    };
+    assert!(history(reverted, PageDirection::Forward).await.is_empty());
+
+    let height_of_tx2 = match client.transaction_status(&tx2.to_string()).await {
+        Ok(TransactionStatus::Success { block_id, .. }) => {
+            client
+                .block(&block_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .header
+                .height
+        }
+        status => panic!("Unexpected status {status:?}"),
+    };
+    let range = TransactionHistoryFilterInput {
+        from_height: Some(height_of_tx2.clone()),
+        to_height: Some(height_of_tx2.clone()),
+        ..filter.clone()
+    };
+    assert_eq!(ids(history(range, PageDirection::Forward).await), vec![tx2]);
+
+    let since_tx2 = TransactionHistoryFilterInput {
+        from_height: Some(height_of_tx2),
+        ..filter
+    };
+    assert_eq!(
+        ids(history(since_tx2, PageDirection::Backward).await),
+        vec![tx3, tx2]
+    );
+}
+
+#[tokio::test]
+async fn transaction_history_page_stops_at_scanned_entries_limit() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut config = Config::local_node();
+    config.query_limits.max_scanned_entries = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut context = TestContext {
+        srv,
+        rng: StdRng::seed_from_u64(100),
+        client,
+    };
+    context.transfer(alice, charlie, 1).await.unwrap();
+    context.transfer(bob, charlie, 2).await.unwrap();
+    let spent = context.transfer(charlie, alice, 3).await.unwrap();
+
+    let filter = TransactionHistoryFilterInput {
+        owner: charlie.into(),
+        asset_id: None,
+        direction: Some(TransferDirection::Out),
+        status: None,
+        from_height: None,
+        to_height: None,
+    };
+    let page_request = |cursor| PaginationRequest {
+        cursor,
+        results: 5,
+        direction: PageDirection::Forward,
+    };
+
+    // The first two transactions of charlie are received, so the first page is empty.
+    let first_page = context
+        .client
+        .transaction_history(filter.clone(), page_request(None))
+        .await
+        .unwrap();
+    assert!(first_page.results.is_empty());
+    assert!(first_page.has_next_page);
+    assert!(first_page.cursor.is_some());
+
+    let second_page = context
+        .client
+        .transaction_history(filter, page_request(first_page.cursor))
+        .await
+        .unwrap();
+    let ids = second_page
+        .results
+        .iter()
+        .map(|tx| tx.transaction.transaction.id())
+        .collect_vec();
+    assert_eq!(ids, vec![spent]);
+    assert!(!second_page.has_next_page);
+}
+
+#[tokio::test]
+async fn transaction_history_backward_page_stops_at_scanned_entries_limit() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut config = Config::local_node();
+    config.query_limits.max_scanned_entries = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut context = TestContext {
+        srv,
+        rng: StdRng::seed_from_u64(100),
+        client,
+    };
+    let first_received = context.transfer(alice, charlie, 1).await.unwrap();
+    let second_received = context.transfer(bob, charlie, 2).await.unwrap();
+    context.transfer(charlie, alice, 3).await.unwrap();
+
+    let filter = TransactionHistoryFilterInput {
+        owner: charlie.into(),
+        asset_id: None,
+        direction: Some(TransferDirection::In),
+        status: None,
+        from_height: None,
+        to_height: None,
+    };
+    let page_request = |cursor| PaginationRequest {
+        cursor,
+        results: 5,
+        direction: PageDirection::Backward,
+    };
+    let ids = |page: &PaginatedResult<OwnerTransactionResponse, String>| {
+        page.results
+            .iter()
+            .map(|tx| tx.transaction.transaction.id())
+            .collect_vec()
+    };
+
+    // The latest transaction of charlie is spent, so the limit is reached after the
+    // second one.
+    let first_page = context
+        .client
+        .transaction_history(filter.clone(), page_request(None))
+        .await
+        .unwrap();
+    assert_eq!(ids(&first_page), vec![second_received]);
+    assert!(first_page.has_previous_page);
+    assert!(!first_page.has_next_page);
+    assert!(first_page.cursor.is_some());
+
+    let second_page = context
+        .client
+        .transaction_history(filter, page_request(first_page.cursor))
+        .await
+        .unwrap();
+    assert_eq!(ids(&second_page), vec![first_received]);
+    assert!(!second_page.has_next_page);
+}
+
 impl TestContext {
     async fn transfer(
         &mut self,
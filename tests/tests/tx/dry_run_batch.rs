@@ -0,0 +1,322 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+    QueryLimits,
+};
+use fuel_core_client::client::{
+    schema::{
+        primitives::HexFormatted,
+        tx::{
+            CoinOverrideInput,
+            ContractCodeOverrideInput,
+            ContractStateOverrideInput,
+            StateOverridesInput,
+        },
+        Bytes,
+        HexString,
+    },
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_asm::*,
+    fuel_crypto::SecretKey,
+    fuel_tx::*,
+    fuel_types::bytes::SerializableVec,
+    fuel_vm::Call,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+fn word_value(word: Word) -> Bytes32 {
+    let mut value = Bytes32::zeroed();
+    value[..8].copy_from_slice(&word.to_be_bytes());
+    value
+}
+
+#[tokio::test]
+async fn dry_run_batch_spends_overridden_coin_in_dependent_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let first_owner_key = SecretKey::random(&mut rng);
+    let second_owner_key = SecretKey::random(&mut rng);
+    let second_owner = Input::owner(&second_owner_key.public_key());
+    let recipient: Address = rng.gen();
+    let fake_coin: UtxoId = rng.gen();
+    let amount = 1000;
+
+    let script = op::ret(RegId::ONE).to_bytes().to_vec();
+    let first = TransactionBuilder::script(script.clone(), vec![])
+        .gas_limit(10000)
+        .add_unsigned_coin_input(
+            first_owner_key,
+            fake_coin,
+            amount,
+            AssetId::BASE,
+            Default::default(),
+            0,
+        )
+        .add_output(Output::coin(second_owner, amount, AssetId::BASE))
+        .finalize_as_transaction();
+    // The second transaction spends the output of the first one.
+    let first_output = UtxoId::new(first.id(), 0);
+    let second = TransactionBuilder::script(script, vec![])
+        .gas_limit(10000)
+        .add_unsigned_coin_input(
+            second_owner_key,
+            first_output,
+            amount,
+            AssetId::BASE,
+            Default::default(),
+            0,
+        )
+        .add_output(Output::coin(recipient, amount, AssetId::BASE))
+        .finalize_as_transaction();
+
+    let overrides = StateOverridesInput {
+        coins: vec![CoinOverrideInput {
+            utxo_id: fuel_core_client::client::schema::UtxoId(HexFormatted(fake_coin)),
+            owner: Input::owner(&first_owner_key.public_key()).into(),
+            amount: amount.into(),
+            asset_id: AssetId::BASE.into(),
+            maturity: None,
+        }],
+        ..Default::default()
+    };
+
+    let results = client
+        .dry_run_batch(
+            &[first.clone(), second.clone()],
+            None,
+            Some(overrides),
+            Some(true),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 2);
+    let first_result = &results[0];
+    assert_eq!(
+        Bytes32::from(first_result.transaction_id.clone()),
+        first.id()
+    );
+    assert!(first_result.gas_used.0 > 0);
+    let spent: Vec<UtxoId> = first_result
+        .state_diff
+        .spent_coins
+        .iter()
+        .cloned()
+        .map(Into::into)
+        .collect();
+    assert_eq!(spent, vec![fake_coin]);
+    let created = &first_result.state_diff.created_coins;
+    assert_eq!(created.len(), 1);
+    assert_eq!(UtxoId::from(created[0].utxo_id.clone()), first_output);
+    assert_eq!(Address::from(created[0].owner.clone()), second_owner);
+    assert_eq!(created[0].amount.0, amount);
+
+    let second_result = &results[1];
+    assert_eq!(
+        Bytes32::from(second_result.transaction_id.clone()),
+        second.id()
+    );
+    let spent: Vec<UtxoId> = second_result
+        .state_diff
+        .spent_coins
+        .iter()
+        .cloned()
+        .map(Into::into)
+        .collect();
+    assert_eq!(spent, vec![first_output]);
+    let created = &second_result.state_diff.created_coins;
+    assert_eq!(created.len(), 1);
+    assert_eq!(Address::from(created[0].owner.clone()), recipient);
+
+    // The coin doesn't exist without the override.
+    let result = client
+        .dry_run_batch(&[first.clone()], None, None, Some(true))
+        .await;
+    assert!(result.is_err());
+
+    // Nothing is committed.
+    assert!(client
+        .transaction_status(&format!("{:#x}", first.id()))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn dry_run_batch_with_contract_code_and_storage_overrides() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let contract_id: ContractId = rng.gen();
+    // The slot with the contract id as the key works as a counter. The contract logs
+    // the current value and increments it.
+    let contract_code: Vec<u8> = vec![
+        op::srw(0x10, 0x11, RegId::FP),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::addi(0x10, 0x10, 1),
+        op::sww(RegId::FP, 0x11, 0x10),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let slot = Bytes32::new(*contract_id);
+
+    let script: Vec<u8> = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let call = |gas_limit| -> Transaction {
+        Transaction::script(
+            0,
+            gas_limit,
+            0,
+            script.clone(),
+            Call::new(contract_id, 0, 0).to_bytes(),
+            vec![Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                contract_id,
+            )],
+            vec![Output::contract(0, Default::default(), Default::default())],
+            vec![],
+        )
+        .into()
+    };
+    // Different gas limits to have different ids of transactions.
+    let txs = [call(1_000_000), call(1_000_001)];
+
+    let overrides = StateOverridesInput {
+        contract_code: vec![ContractCodeOverrideInput {
+            contract: contract_id.into(),
+            bytecode: HexString(Bytes(contract_code)),
+        }],
+        contract_state: vec![ContractStateOverrideInput {
+            contract: contract_id.into(),
+            key: slot.into(),
+            value: Some(word_value(41).into()),
+        }],
+        ..Default::default()
+    };
+
+    let results = client
+        .dry_run_batch(&txs, None, Some(overrides), Some(false))
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 2);
+    for (result, expected) in results.into_iter().zip([41, 42]) {
+        let receipts: Vec<Receipt> = result
+            .receipts
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert!(receipts.iter().any(|receipt| matches!(
+            receipt,
+            Receipt::Log { ra, .. } if *ra == expected
+        )));
+
+        let state = &result.state_diff.contract_state;
+        assert_eq!(state.len(), 1);
+        assert_eq!(ContractId::from(state[0].contract.clone()), contract_id);
+        assert_eq!(Bytes32::from(state[0].key.clone()), slot);
+        assert_eq!(
+            state[0].value.clone().map(Bytes32::from),
+            Some(word_value(expected + 1))
+        );
+    }
+
+    // The overridden contract isn't committed.
+    let contract = client
+        .contract(format!("{contract_id:#x}").as_str())
+        .await
+        .unwrap();
+    assert!(contract.is_none());
+}
+
+#[tokio::test]
+async fn dry_run_batch_uses_block_context_of_height() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+    let script = vec![op::bhei(0x10), op::ret(0x10)]
+        .into_iter()
+        .collect::<Vec<u8>>();
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(10000)
+        .finalize_as_transaction();
+
+    for (height, expected) in [(Some(1), 2), (None, 3)] {
+        let results = client
+            .dry_run_batch(&[tx.clone()], height, None, Some(false))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        let result = results.into_iter().next().unwrap();
+        let receipts: Vec<Receipt> = result
+            .receipts
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert!(receipts.iter().any(|receipt| matches!(
+            receipt,
+            Receipt::Return { val, .. } if *val == expected
+        )));
+    }
+}
+
+#[tokio::test]
+async fn dry_run_batch_above_the_limits_is_rejected() {
+    let mut config = Config::local_node();
+    config.query_limits = QueryLimits {
+        max_dry_run_txs: 2,
+        max_dry_run_gas: 25_000,
+        ..Default::default()
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let script = |gas_limit| {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(gas_limit)
+            .finalize_as_transaction()
+    };
+
+    let err = client
+        .dry_run_batch(
+            &[script(10_000), script(10_001), script(10_002)],
+            None,
+            None,
+            Some(false),
+        )
+        .await
+        .expect_err("Too many transactions");
+    assert!(err.to_string().contains("number of transactions"), "{err}");
+
+    let err = client
+        .dry_run_batch(&[script(20_000), script(20_001)], None, None, Some(false))
+        .await
+        .expect_err("Too much gas");
+    assert!(err.to_string().contains("total gas"), "{err}");
+
+    let results = client
+        .dry_run_batch(&[script(10_000), script(10_001)], None, None, Some(false))
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+}
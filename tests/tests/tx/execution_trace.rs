@@ -0,0 +1,121 @@
+use crate::helpers::TestSetupBuilder;
+use fuel_core_types::{
+    fuel_asm::*,
+    fuel_tx::*,
+    fuel_types::bytes::SerializableVec,
+    fuel_vm::Call,
+};
+
+fn contract_call(contract_id: ContractId) -> Transaction {
+    let script: Vec<u8> = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    Transaction::script(
+        0,
+        1_000_000,
+        0,
+        script,
+        Call::new(contract_id, 0, 0).to_bytes(),
+        vec![Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        )],
+        vec![Output::contract(0, Default::default(), Default::default())],
+        vec![],
+    )
+    .into()
+}
+
+#[tokio::test]
+async fn transaction_trace_contains_call_tree() {
+    let contract_code: Vec<u8> = vec![
+        op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    test_builder.vm_trace = true;
+    let (_, contract_id) = test_builder.setup_contract(contract_code, None, None, None);
+    let context = test_builder.finalize().await;
+
+    let tx = contract_call(contract_id);
+    context.client.submit_and_await_commit(&tx).await.unwrap();
+
+    let mut trace = context
+        .client
+        .transaction_trace(&format!("{:#x}", tx.id()))
+        .await
+        .unwrap()
+        .expect("The trace is recorded");
+    assert!(trace.gas_used.0 > 0);
+    assert_eq!(trace.frames.len(), 2);
+
+    let receipts: Vec<Vec<Receipt>> = trace
+        .frames
+        .iter_mut()
+        .map(|frame| {
+            std::mem::take(&mut frame.receipts)
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()
+                .unwrap()
+        })
+        .collect();
+
+    let script = &trace.frames[0];
+    assert_eq!(script.parent, None);
+    assert!(script.contract.is_none());
+    assert_eq!(script.gas_forwarded.0, 1_000_000);
+    assert!(matches!(
+        receipts[0].as_slice(),
+        [
+            Receipt::Call { to, .. },
+            Receipt::Return { .. },
+            Receipt::ScriptResult { .. }
+        ] if *to == contract_id
+    ));
+
+    let call = &trace.frames[1];
+    assert_eq!(call.parent, Some(0));
+    assert_eq!(
+        call.contract.clone().map(ContractId::from),
+        Some(contract_id)
+    );
+    assert!(call.gas_forwarded.0 > 0);
+    assert!(matches!(
+        receipts[1].as_slice(),
+        [Receipt::Log { ra: 1, .. }, Receipt::Return { id, .. }] if *id == contract_id
+    ));
+}
+
+#[tokio::test]
+async fn transaction_trace_is_not_recorded_by_default() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    let (_, contract_id) = test_builder.setup_contract(
+        op::ret(RegId::ONE).to_bytes().to_vec(),
+        None,
+        None,
+        None,
+    );
+    let context = test_builder.finalize().await;
+
+    let tx = contract_call(contract_id);
+    context.client.submit_and_await_commit(&tx).await.unwrap();
+
+    let trace = context
+        .client
+        .transaction_trace(&format!("{:#x}", tx.id()))
+        .await
+        .unwrap();
+    assert!(trace.is_none());
+}
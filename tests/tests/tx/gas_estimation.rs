@@ -0,0 +1,125 @@
+use crate::helpers::TestSetupBuilder;
+use fuel_core_client::client::types::TransactionStatus;
+use fuel_core_types::{
+    fuel_asm::*,
+    fuel_tx::*,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+fn predicate_tx(rng: &mut StdRng, predicate: Vec<u8>, gas_limit: Word) -> Script {
+    let amount = 500;
+    let asset_id = AssetId::BASE;
+    let owner = Input::predicate_owner(&predicate);
+    let script: Vec<u8> = [op::noop(); 100]
+        .into_iter()
+        .chain([op::ret(RegId::ONE)])
+        .collect();
+    TransactionBuilder::script(script, vec![])
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            amount,
+            asset_id,
+            Default::default(),
+            0,
+            predicate,
+            vec![],
+        ))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .gas_price(1)
+        .gas_limit(gas_limit)
+        .finalize()
+}
+
+#[tokio::test]
+async fn estimated_gas_limit_is_enough_to_execute_transaction() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    // The gas limit is too low to execute the script.
+    let tx = predicate_tx(&mut rng, predicate, 1);
+
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.min_gas_price = 2;
+    test_builder.config_coin_inputs_from_transactions(&[&tx]);
+    let context = test_builder.finalize().await;
+
+    let estimation = context
+        .client
+        .estimate_gas(&tx.clone().into())
+        .await
+        .unwrap();
+    let predicate_gas_used = context
+        .client
+        .estimate_predicates(&tx.clone().into())
+        .await
+        .unwrap();
+
+    assert!(estimation.script_gas_used.0 > 100);
+    assert!(estimation.predicate_gas_used.0 > 0);
+    assert_eq!(estimation.predicate_gas_used.0, predicate_gas_used);
+    assert!(
+        estimation.gas_limit.0
+            >= estimation.script_gas_used.0 + estimation.predicate_gas_used.0
+    );
+    // The price of the transaction is below the minimal gas price of the node.
+    assert_eq!(estimation.gas_price.0, 2);
+    assert!(estimation.total_fee.0 > 0);
+
+    let mut tx = tx;
+    tx.set_gas_limit(estimation.gas_limit.0);
+    tx.set_gas_price(estimation.gas_price.0);
+    let status = context
+        .client
+        .submit_and_await_commit(&tx.into())
+        .await
+        .unwrap();
+    assert!(
+        matches!(status, TransactionStatus::Success { .. }),
+        "{status:?}"
+    );
+}
+
+#[tokio::test]
+async fn estimate_predicates_fails_for_invalid_predicate() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let predicate = op::ret(RegId::ZERO).to_bytes().to_vec();
+    let tx = predicate_tx(&mut rng, predicate, 1000);
+
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.config_coin_inputs_from_transactions(&[&tx]);
+    let context = test_builder.finalize().await;
+
+    let tx = tx.into();
+    assert!(context.client.estimate_predicates(&tx).await.is_err());
+    assert!(context.client.estimate_gas(&tx).await.is_err());
+}
+
+#[tokio::test]
+async fn estimate_gas_reports_the_error_of_invalid_transaction() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let contract_id: ContractId = rng.gen();
+    // The contract doesn't exist, so the transaction can't be executed at all.
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .gas_limit(1000)
+        .finalize_as_transaction();
+
+    let context = TestSetupBuilder::new(2322).finalize().await;
+
+    let err = context.client.estimate_gas(&tx).await.unwrap_err();
+    assert!(
+        !err.to_string().contains("fails with the maximum gas limit"),
+        "{err}"
+    );
+}
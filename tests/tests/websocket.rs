@@ -0,0 +1,198 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+        QueryLimits,
+        RateLimitConfig,
+        WebSocketConfig,
+    },
+};
+use futures::{
+    SinkExt,
+    StreamExt,
+};
+use serde_json::{
+    json,
+    Value,
+};
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{
+        client::IntoClientRequest,
+        http::HeaderValue,
+        protocol::frame::coding::CloseCode,
+        Message,
+    },
+    MaybeTlsStream,
+    WebSocketStream,
+};
+
+type Socket = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn start_node(websocket: WebSocketConfig) -> FuelService {
+    let mut config = Config::local_node();
+    config.websocket = websocket;
+    FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap()
+}
+
+async fn connect(address: SocketAddr) -> Socket {
+    let mut request = format!("ws://{address}/graphql-ws")
+        .into_client_request()
+        .unwrap();
+    request.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        HeaderValue::from_static("graphql-transport-ws"),
+    );
+    let (socket, _) = connect_async(request).await.unwrap();
+    socket
+}
+
+async fn send(socket: &mut Socket, message: Value) {
+    socket
+        .send(Message::Text(message.to_string()))
+        .await
+        .unwrap();
+}
+
+async fn receive(socket: &mut Socket) -> Value {
+    loop {
+        let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+            .await
+            .expect("Timed out waiting for a message")
+            .unwrap()
+            .unwrap();
+        if let Message::Text(text) = message {
+            return serde_json::from_str(&text).unwrap()
+        }
+    }
+}
+
+async fn initialize(socket: &mut Socket) {
+    send(socket, json!({ "type": "connection_init" })).await;
+    assert_eq!(receive(socket).await["type"], "connection_ack");
+}
+
+fn subscribe_new_headers(id: &str) -> Value {
+    json!({
+        "id": id,
+        "type": "subscribe",
+        "payload": { "query": "subscription { newHeaders { height } }" },
+    })
+}
+
+#[tokio::test]
+async fn websocket_sends_keep_alive_pings() {
+    let srv = start_node(WebSocketConfig {
+        keep_alive_interval: Duration::from_millis(100),
+        ..Default::default()
+    })
+    .await;
+    let mut socket = connect(srv.bound_address).await;
+    initialize(&mut socket).await;
+
+    assert_eq!(receive(&mut socket).await["type"], "ping");
+    assert_eq!(receive(&mut socket).await["type"], "ping");
+}
+
+#[tokio::test]
+async fn websocket_closes_uninitialized_connection() {
+    let srv = start_node(WebSocketConfig {
+        connection_init_timeout: Duration::from_millis(100),
+        ..Default::default()
+    })
+    .await;
+    let mut socket = connect(srv.bound_address).await;
+
+    let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+        .await
+        .expect("The connection should be closed")
+        .unwrap()
+        .unwrap();
+    match message {
+        Message::Close(Some(frame)) => {
+            assert_eq!(frame.code, CloseCode::from(4408));
+        }
+        message => panic!("Expected the close frame, got {message:?}"),
+    }
+}
+
+#[tokio::test]
+async fn websocket_limits_subscriptions_per_connection() {
+    let srv = start_node(WebSocketConfig {
+        max_subscriptions_per_connection: 1,
+        ..Default::default()
+    })
+    .await;
+    let mut socket = connect(srv.bound_address).await;
+    initialize(&mut socket).await;
+
+    send(&mut socket, subscribe_new_headers("1")).await;
+    send(&mut socket, subscribe_new_headers("2")).await;
+    let rejection = receive(&mut socket).await;
+    assert_eq!(rejection["type"], "error");
+    assert_eq!(rejection["id"], "2");
+
+    // The slot is released after the first subscription is stopped.
+    send(&mut socket, json!({ "id": "1", "type": "complete" })).await;
+    let completed = receive(&mut socket).await;
+    assert_eq!(completed["type"], "complete");
+    assert_eq!(completed["id"], "1");
+    send(&mut socket, subscribe_new_headers("3")).await;
+    send(&mut socket, json!({ "type": "ping" })).await;
+    assert_eq!(receive(&mut socket).await["type"], "pong");
+}
+
+#[tokio::test]
+async fn websocket_rejects_unknown_protocol() {
+    let srv = start_node(Default::default()).await;
+    let request = format!("ws://{}/graphql-ws", srv.bound_address)
+        .into_client_request()
+        .unwrap();
+
+    assert!(connect_async(request).await.is_err());
+}
+
+#[tokio::test]
+async fn websocket_subscriptions_are_rate_limited() {
+    let mut config = Config::local_node();
+    config.query_limits = QueryLimits {
+        rate_limit: Some(RateLimitConfig {
+            requests_per_second: 1,
+            burst: 2,
+        }),
+        ..Default::default()
+    };
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    // The upgrade takes the first token.
+    let mut socket = connect(srv.bound_address).await;
+    initialize(&mut socket).await;
+
+    send(&mut socket, subscribe_new_headers("1")).await;
+    send(&mut socket, subscribe_new_headers("2")).await;
+    let rejection = receive(&mut socket).await;
+    assert_eq!(rejection["type"], "error");
+    assert_eq!(rejection["id"], "2");
+    assert_eq!(
+        rejection["payload"][0]["extensions"]["code"],
+        "RATE_LIMITED"
+    );
+
+    // The new connections are rejected until the bucket is refilled.
+    let mut request = format!("ws://{}/graphql-ws", srv.bound_address)
+        .into_client_request()
+        .unwrap();
+    request.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        HeaderValue::from_static("graphql-transport-ws"),
+    );
+    assert!(connect_async(request).await.is_err());
+}